@@ -4,6 +4,7 @@ use super::*;
 
 use crate::Pallet;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
 use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 use pallet_community::types::{
@@ -11,6 +12,7 @@ use pallet_community::types::{
 	Traditions, Values,
 };
 use pallet_passport::types::BadgesType;
+use sp_runtime::Saturating;
 use sp_std::vec;
 use sp_std::vec::Vec;
 const SEED: u32 = 0;
@@ -82,7 +84,7 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 			"Jur is the core community of the Jur ecosystem, which includes all the contributors."
 				.into(),
 		),
-		Some(members),
+		Some(members.clone()),
 		Some(get_community_metadata::<T>()),
 		Category::Public,
 		Some("tag".into()),
@@ -91,11 +93,24 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in members {
+		pallet_community::Pallet::<T>::accept_invitation(
+			RawOrigin::Signed(member).into(),
+			community_id,
+		)
+		.unwrap();
+	}
 
 	community_id
 }
 
 pub fn add_founder<T: Config>(caller: T::AccountId) {
+	// Enough balance to reserve the metadata deposit of the community.
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		&caller,
+		<T as pallet_community::Config>::MetadataDepositPerByte::get()
+			.saturating_mul(100_000u32.into()),
+	);
 	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller).unwrap();
 }
 
@@ -261,5 +276,61 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::CompletedBounty(community_id, <T as pallet::Config>::Helper::bounty(1)).into());
 	}
 
+	service_expiry_block {
+		let now = frame_system::Pallet::<T>::block_number();
+	}: {
+		BountyExpiryQueue::<T>::service(now, 1, |_| {});
+	}
+	verify {
+		assert_eq!(BountyExpiryCursor::<T>::get(), Some(now.saturating_add(1u32.into())));
+	}
+
+	close_bounty {
+		let caller: T::AccountId = whitelisted_caller();
+		let first_member: T::AccountId = account("sub", 2, SEED);
+		let second_member: T::AccountId = account("sub", 3, SEED);
+		let members = vec![account("sub", 2, SEED), account("sub", 3, SEED)];
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+		pallet_passport::Pallet::<T>::mint(RawOrigin::Signed(first_member).into(), community_id.clone()).unwrap();
+		pallet_passport::Pallet::<T>::mint(RawOrigin::Signed(second_member).into(), community_id.clone()).unwrap();
+		add_badge::<T>(caller.clone(), community_id.clone());
+		let bounty_id = <T as pallet::Config>::Helper::bounty(1);
+
+		let bounded_accounts: BoundedVec<<T as frame_system::Config>::AccountId, <T as pallet::Config>::AccountLimit> = members
+			.try_into().unwrap();
+
+		let bounty_name: Vec<u8> = "Bounty to help in dev work".into();
+		let bounded_bounty_name: BoundedVec<u8, <T as pallet::Config>::NameLimit> = bounty_name.try_into().unwrap();
+
+		let bounty_description: Vec<u8> = "Development bounty for the jur community members".into();
+		let bounded_bounty_description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit> = bounty_description.try_into().unwrap();
+
+		let badge_name: Vec<u8> = "DEVBOUNTY".into();
+		let bounded_badge_name: BoundedVec<u8, <T as pallet_passport::Config>::BadgeNameLimit> = badge_name.try_into().unwrap();
+
+		Pallet::<T>::create_bounty(
+			RawOrigin::Signed(caller.clone()).into(),
+			community_id,
+			bounded_bounty_name,
+			vec![],
+			bounded_badge_name,
+			bounded_bounty_description,
+			2
+		).unwrap();
+
+		Pallet::<T>::update_bounty(
+			RawOrigin::Signed(caller.clone()).into(),
+			community_id,
+			bounty_id,
+			bounded_accounts
+		).unwrap();
+	}: {
+		Pallet::<T>::do_close_bounty(community_id, bounty_id)?;
+	}
+	verify {
+		assert_last_event::<T>(Event::<T>::ClosedBounty(community_id, bounty_id).into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
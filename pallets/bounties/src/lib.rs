@@ -18,9 +18,19 @@
 pub use pallet::*;
 mod types;
 use crate::types::{Bounty, BountyStatus};
-use frame_support::{dispatch::DispatchResultWithPostInfo, BoundedVec};
+use frame_support::{
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	ensure,
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_community::{
+	types::Permissions, CommunityPermissions, OnCommunityDissolved, OnMemberLeft,
+};
 use pallet_passport::Passports;
-use primitives::{Incrementable, BLOCKS_PER_DAY, BOUNTY_DURATION_LIMIT};
+use primitives::{expiry::ExpiryQueue, Incrementable, BLOCKS_PER_DAY, BOUNTY_DURATION_LIMIT};
+use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 
 #[cfg(test)]
@@ -31,15 +41,21 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
+const LOG_TARGET: &str = "runtime::bounties";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[cfg(feature = "runtime-benchmarks")]
 	pub trait BenchmarkHelper<BountyId> {
 		fn bounty(i: u32) -> BountyId;
@@ -81,6 +97,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type AccountLimit: Get<u32>;
 
+		/// The maximum number of open bounties an account can participate in at once.
+		#[pallet::constant]
+		type MaxBountiesPerParticipant: Get<u32>;
+
+		/// The maximum number of bounties which can expire in the same block.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// The maximum number of expired bounties closed in `on_initialize`, the remaining ones
+		/// are closed in `on_idle` or in the next blocks.
+		#[pallet::constant]
+		type MaxClosedPerBlock: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type Helper: BenchmarkHelper<Self::BountyId>;
@@ -90,6 +119,7 @@ pub mod pallet {
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -120,10 +150,33 @@ pub mod pallet {
 	pub type NextBountyId<T: Config> =
 		StorageMap<_, Twox64Concat, T::CommunityId, T::BountyId, OptionQuery>;
 
+	/// Store the open bounties an account participates in
+	#[pallet::storage]
+	pub type ParticipatedBounties<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::BountyId, T::MaxBountiesPerParticipant>,
+		ValueQuery,
+	>;
+
+	/// Store the bounties which expire at a given block
 	#[pallet::storage]
 	#[pallet::getter(fn bounty_expire)]
-	pub type BountyExpireTime<T: Config> =
-		StorageMap<_, Identity, BlockNumberFor<T>, (T::CommunityId, T::BountyId), OptionQuery>;
+	pub type BountyExpireTime<T: Config> = StorageMap<
+		_,
+		Identity,
+		BlockNumberFor<T>,
+		BoundedVec<(T::CommunityId, T::BountyId), T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Store the first block whose expired bounties have not all been closed yet
+	#[pallet::storage]
+	#[pallet::getter(fn bounty_expiry_cursor)]
+	pub type BountyExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -138,6 +191,8 @@ pub mod pallet {
 		ClosedBounty(T::CommunityId, T::BountyId),
 		/// Issued Badge
 		IssuedBadge(Vec<u8>),
+		/// Expired bounty could not be closed [Community Id, Bounty Id, Error]
+		BountyCloseFailed(T::CommunityId, T::BountyId, DispatchError),
 	}
 
 	#[pallet::error]
@@ -162,39 +217,52 @@ pub mod pallet {
 		BadgeNotExist,
 		/// Bounty is closed as its deadline is exceed.
 		BountyClosed,
+		/// Too many bounties expire in the same block.
+		TooManyExpiries,
+		/// The participant already takes part in too many open bounties.
+		TooManyBounties,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
-			let option_bounty_expire = BountyExpireTime::<T>::get(block_number);
+			let (visited, closed) = BountyExpiryQueue::<T>::service(
+				block_number,
+				T::MaxClosedPerBlock::get(),
+				|(community_id, bounty_id)| Self::expire_bounty(community_id, bounty_id),
+			);
 
-			if let Some((community_id, bounty_id)) = option_bounty_expire {
-				Bounties::<T>::try_mutate(
-					community_id,
-					&bounty_id,
-					|bounty_details| -> DispatchResult {
-						let bounty = bounty_details
-							.as_mut()
-							.ok_or(Error::<T>::BountyNotAvailable)?;
+			Self::expiry_weight(visited, closed)
+		}
 
-						ensure!(
-							(bounty.status == BountyStatus::Ongoing)
-								|| (bounty.status == BountyStatus::WorkInProgress),
-							Error::<T>::BountyClosed
-						);
+		fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let base_weight = Self::expiry_weight(0, 0);
+			let item_weight = Self::expiry_weight(1, 1).saturating_sub(base_weight);
 
-						bounty.status = BountyStatus::Completed;
+			if remaining_weight.any_lt(base_weight.saturating_add(item_weight)) {
+				return Weight::zero();
+			}
 
-						Self::deposit_event(Event::ClosedBounty(community_id, bounty_id));
+			// Close the bounties carried over from the previous blocks.
+			let limit = remaining_weight
+				.saturating_sub(base_weight)
+				.ref_time()
+				.checked_div(item_weight.ref_time())
+				.unwrap_or(u64::MAX)
+				.min(u32::MAX as u64) as u32;
+
+			let (visited, closed) = BountyExpiryQueue::<T>::service(
+				block_number,
+				limit,
+				|(community_id, bounty_id)| Self::expire_bounty(community_id, bounty_id),
+			);
 
-						Ok(())
-					},
-				)
-				.expect("Bounty not found");
-			};
+			Self::expiry_weight(visited, closed)
+		}
 
-			Weight::zero()
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
 		}
 	}
 
@@ -202,7 +270,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Create a new bounty for a particular community from a origin.
 		///
-		/// The origin must be Signed and the community founder.
+		/// The origin must be Signed and hold the `MANAGE_BOUNTIES` permission in the community.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community.
@@ -225,11 +293,20 @@ pub mod pallet {
 			duration: u32,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
-			let community = pallet_community::Communities::<T>::get(community_id)
-				.ok_or(Error::<T>::CommunityDoesNotExist)?;
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
 
-			// Ensuring the bounty creator should be founder.
-			ensure!(origin == community.founder, Error::<T>::NotAllowed);
+			// Ensuring the bounty creator is allowed to manage bounties.
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::MANAGE_BOUNTIES
+				),
+				Error::<T>::NotAllowed
+			);
 
 			// Ensuring the bounty badge should exist in the community badge directory.
 			let is_badge_exist = pallet_passport::Badges::<T>::get(community_id, &badge);
@@ -264,8 +341,15 @@ pub mod pallet {
 			let community = pallet_community::Communities::<T>::get(community_id)
 				.ok_or(Error::<T>::CommunityDoesNotExist)?;
 
-			// Ensuring the origin should be founder.
-			ensure!(origin == community.founder, Error::<T>::NotAllowed);
+			// Ensuring the origin is allowed to manage bounties.
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::MANAGE_BOUNTIES
+				),
+				Error::<T>::NotAllowed
+			);
 
 			// Ensuring the members should not be a founder.
 			ensure!(!participants.contains(&community.founder), Error::<T>::NotAllowed);
@@ -280,23 +364,45 @@ pub mod pallet {
 				Error::<T>::PassportNotAvailable
 			);
 
-			Bounties::<T>::try_mutate(community_id, &bounty_id, |bounty_details| {
-				let bounty = bounty_details
-					.as_mut()
-					.ok_or(Error::<T>::BountyNotAvailable)?;
+			Bounties::<T>::try_mutate(
+				community_id,
+				&bounty_id,
+				|bounty_details| -> DispatchResult {
+					let bounty = bounty_details
+						.as_mut()
+						.ok_or(Error::<T>::BountyNotAvailable)?;
+
+					ensure!(
+						(bounty.status == BountyStatus::Ongoing)
+							|| (bounty.status == BountyStatus::WorkInProgress),
+						Error::<T>::BountyClosed
+					);
+
+					// Keeping the participation index in sync with the new participants.
+					for participant in &bounty.participants {
+						if !participants.contains(participant) {
+							Self::remove_participation(community_id, participant, bounty_id);
+						}
+					}
 
-				ensure!(
-					(bounty.status == BountyStatus::Ongoing)
-						|| (bounty.status == BountyStatus::WorkInProgress),
-					Error::<T>::BountyClosed
-				);
+					for participant in &participants {
+						if !bounty.participants.contains(participant) {
+							ParticipatedBounties::<T>::try_mutate(
+								community_id,
+								participant,
+								|bounty_ids| bounty_ids.try_push(bounty_id),
+							)
+							.map_err(|_| Error::<T>::TooManyBounties)?;
+						}
+					}
 
-				bounty.participants = participants;
-				bounty.status = BountyStatus::WorkInProgress;
+					bounty.participants = participants;
+					bounty.status = BountyStatus::WorkInProgress;
 
-				Self::deposit_event(Event::UpdatedBounty(community_id, bounty_id));
-				Ok(())
-			})
+					Self::deposit_event(Event::UpdatedBounty(community_id, bounty_id));
+					Ok(())
+				},
+			)
 		}
 
 		#[pallet::call_index(2)]
@@ -311,7 +417,14 @@ pub mod pallet {
 			let community = pallet_community::Communities::<T>::get(community_id)
 				.ok_or(Error::<T>::CommunityDoesNotExist)?;
 
-			ensure!(origin == community.founder, Error::<T>::NotAllowed);
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::MANAGE_BOUNTIES
+				),
+				Error::<T>::NotAllowed
+			);
 
 			// Ensuring the members should not be a founder.
 			ensure!(!contributors.contains(&community.founder), Error::<T>::NotAllowed);
@@ -332,25 +445,9 @@ pub mod pallet {
 
 			// Issuing the badge to the members
 			for member in &contributors {
-				Passports::<T>::try_mutate(
-					community_id,
-					member,
-					|passport_details| -> DispatchResult {
-						let passport = passport_details
-							.as_mut()
-							.ok_or(Error::<T>::PassportNotAvailable)?;
-
-						let mut badges = passport.badges.clone();
+				pallet_passport::Pallet::<T>::do_issue_badge(community_id, member, &bounty_reward)?;
 
-						if !badges.contains(&bounty_reward) {
-							badges.push(bounty_reward.clone());
-							passport.badges = badges;
-						}
-
-						Self::deposit_event(Event::IssuedBadge(bounty_reward.to_vec()));
-						Ok(())
-					},
-				)?;
+				Self::deposit_event(Event::IssuedBadge(bounty_reward.to_vec()));
 			}
 
 			Bounties::<T>::try_mutate(community_id, &bounty_id, |bounty_details| {
@@ -384,7 +481,109 @@ pub mod pallet {
 	}
 }
 
+/// Queue of the bounties expiring at a given block.
+pub type BountyExpiryQueue<T> = ExpiryQueue<
+	BlockNumberFor<T>,
+	(<T as pallet_community::Config>::CommunityId, <T as Config>::BountyId),
+	<T as Config>::MaxExpiriesPerBlock,
+	BountyExpireTime<T>,
+	BountyExpiryCursor<T>,
+>;
+
 impl<T: Config> Pallet<T> {
+	/// Close an expired bounty, reporting a failure instead of halting the block.
+	fn expire_bounty(community_id: T::CommunityId, bounty_id: T::BountyId) {
+		if let Err(error) = Self::do_close_bounty(community_id, bounty_id) {
+			log::error!(
+				target: LOG_TARGET,
+				"Failed to close the expired bounty {:?} of community {:?}: {:?}",
+				bounty_id,
+				community_id,
+				error
+			);
+			Self::deposit_event(Event::<T>::BountyCloseFailed(community_id, bounty_id, error));
+		}
+	}
+
+	/// Close an expired bounty which is not completed yet.
+	pub fn do_close_bounty(community_id: T::CommunityId, bounty_id: T::BountyId) -> DispatchResult {
+		Bounties::<T>::try_mutate(community_id, &bounty_id, |bounty_details| -> DispatchResult {
+			let bounty = bounty_details
+				.as_mut()
+				.ok_or(Error::<T>::BountyNotAvailable)?;
+
+			ensure!(
+				(bounty.status == BountyStatus::Ongoing)
+					|| (bounty.status == BountyStatus::WorkInProgress),
+				Error::<T>::BountyClosed
+			);
+
+			bounty.status = BountyStatus::Completed;
+
+			// The participants are kept in the bounty, only the index of open bounties changes.
+			for participant in &bounty.participants {
+				Self::remove_participation(community_id, participant, bounty_id);
+			}
+
+			Self::deposit_event(Event::ClosedBounty(community_id, bounty_id));
+
+			Ok(())
+		})
+	}
+
+	/// Remove the bounty from the open bounties the account participates in.
+	fn remove_participation(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+		bounty_id: T::BountyId,
+	) {
+		ParticipatedBounties::<T>::mutate_exists(community_id, who, |maybe_bounty_ids| {
+			if let Some(bounty_ids) = maybe_bounty_ids {
+				bounty_ids.retain(|id| *id != bounty_id);
+				if bounty_ids.is_empty() {
+					*maybe_bounty_ids = None;
+				}
+			}
+		});
+	}
+
+	/// Weight of visiting `visited` blocks of the expiry queue and closing `closed` bounties.
+	fn expiry_weight(visited: u32, closed: u32) -> Weight {
+		let block_weight = <T as Config>::WeightInfo::service_expiry_block();
+
+		block_weight
+			.saturating_add(block_weight.saturating_mul(visited as u64))
+			.saturating_add(<T as Config>::WeightInfo::close_bounty().saturating_mul(closed as u64))
+	}
+
+	/// Ensure every queued expiry and participation refers to an open bounty.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		for (_, expiries) in BountyExpireTime::<T>::iter() {
+			for (community_id, bounty_id) in expiries {
+				let bounty = Bounties::<T>::get(community_id, bounty_id)
+					.ok_or("Queued expiry refers to a missing bounty")?;
+				ensure!(
+					bounty.status != BountyStatus::Completed,
+					"Queued expiry refers to a completed bounty"
+				);
+			}
+		}
+
+		for (community_id, who, bounty_ids) in ParticipatedBounties::<T>::iter() {
+			for bounty_id in bounty_ids {
+				let bounty = Bounties::<T>::get(community_id, bounty_id)
+					.ok_or("Participation refers to a missing bounty")?;
+				ensure!(
+					bounty.status != BountyStatus::Completed && bounty.participants.contains(&who),
+					"Participation refers to a bounty the account does not take part in"
+				);
+			}
+		}
+
+		Ok(())
+	}
+
 	pub fn do_create_bounty(
 		creator: T::AccountId,
 		community_id: T::CommunityId,
@@ -407,7 +606,8 @@ impl<T: Config> Pallet<T> {
 
 		// Storing the Bounty expire time
 		let expire_block = frame_system::Pallet::<T>::block_number() + deadline_block.into();
-		<BountyExpireTime<T>>::insert(&expire_block, (community_id, bounty_id));
+		BountyExpiryQueue::<T>::schedule(expire_block, (community_id, bounty_id))
+			.map_err(|_| Error::<T>::TooManyExpiries)?;
 
 		let new_bounty = Bounty {
 			creator,
@@ -433,3 +633,40 @@ impl<T: Config> Pallet<T> {
 		Ok(().into())
 	}
 }
+
+impl<T: Config> OnCommunityDissolved<T::CommunityId> for Pallet<T> {
+	fn on_community_dissolved(community_id: T::CommunityId, limit: u32) -> u32 {
+		let mut removed = 0_u32;
+
+		for (bounty_id, bounty) in Bounties::<T>::drain_prefix(community_id).take(limit as usize) {
+			// Make sure the expiry hook does not look up the removed bounty.
+			BountyExpiryQueue::<T>::cancel(bounty.deadline_block, &(community_id, bounty_id));
+			removed += 1;
+		}
+
+		if removed < limit {
+			removed += ParticipatedBounties::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			NextBountyId::<T>::remove(community_id);
+		}
+
+		removed
+	}
+}
+
+impl<T: Config> OnMemberLeft<T::CommunityId, T::AccountId> for Pallet<T> {
+	fn on_member_left(community_id: T::CommunityId, who: &T::AccountId) {
+		// Only the open bounties are indexed, the completed ones keep their participants.
+		for bounty_id in ParticipatedBounties::<T>::take(community_id, who) {
+			Bounties::<T>::mutate(community_id, bounty_id, |maybe_bounty| {
+				if let Some(bounty) = maybe_bounty {
+					bounty.participants.retain(|participant| participant != who);
+				}
+			});
+		}
+	}
+}
@@ -0,0 +1,226 @@
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use log;
+use sp_runtime::Saturating;
+use sp_std::vec;
+
+pub mod v1 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 0 {
+				let now = frame_system::Pallet::<T>::block_number();
+				let mut translated = 0u64;
+
+				BountyExpireTime::<T>::translate::<(T::CommunityId, T::BountyId), _>(
+					|expire_block, old_value| {
+						translated.saturating_inc();
+
+						// The bounties expired before the upgrade are already closed.
+						(expire_block >= now).then(|| BoundedVec::truncate_from(vec![old_value]))
+					},
+				);
+				BountyExpiryCursor::<T>::put(now);
+
+				StorageVersion::new(1).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Bounties Upgraded {} expiries, storage to version 1",
+					translated
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 2)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Bounties Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"must upgrade linearly"
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			let pending_count = BountyExpireTime::<T>::iter_keys()
+				.filter(|expire_block| *expire_block >= now)
+				.count();
+			Ok((pending_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pending_count: Vec<u8>) -> Result<(), &'static str> {
+			let pending_count: u32 = Decode::decode(&mut pending_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = BountyExpireTime::<T>::iter_values()
+				.map(|expiries| expiries.len() as u32)
+				.sum::<u32>();
+			assert_eq!(
+				pending_count, post_count,
+				"the pending expiries before and after the migration should be the same"
+			);
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Index the participants of the open bounties in `ParticipatedBounties`.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 1 {
+				let mut reads = 0u64;
+				let mut indexed = 0u64;
+				let mut skipped = 0u64;
+
+				for (community_id, bounty_id, bounty) in Bounties::<T>::iter() {
+					reads.saturating_inc();
+
+					// Completed bounties keep their participants as a record of the work done.
+					if bounty.status == BountyStatus::Completed {
+						continue;
+					}
+
+					for participant in bounty.participants.iter() {
+						let pushed = ParticipatedBounties::<T>::try_mutate(
+							community_id,
+							participant,
+							|bounty_ids| bounty_ids.try_push(bounty_id),
+						);
+
+						if pushed.is_ok() {
+							indexed.saturating_inc();
+						} else {
+							log::error!(
+								target: LOG_TARGET,
+								"Too many open bounties for a participant of bounty {:?} of community {:?}",
+								bounty_id,
+								community_id
+							);
+							skipped.saturating_inc();
+						}
+					}
+				}
+
+				StorageVersion::new(2).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Bounties indexed {} participations, skipped {}, storage to version 2",
+					indexed,
+					skipped
+				);
+				T::DbWeight::get().reads_writes(reads + indexed + skipped + 1, indexed + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Bounties Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"must upgrade linearly"
+			);
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 2, "must_upgrade");
+
+			for (community_id, bounty_id, bounty) in Bounties::<T>::iter() {
+				if bounty.status == BountyStatus::Completed {
+					continue;
+				}
+
+				for participant in bounty.participants.iter() {
+					let bounty_ids = ParticipatedBounties::<T>::get(community_id, participant);
+					if !bounty_ids.contains(&bounty_id) && !bounty_ids.is_full() {
+						return Err("participation not indexed");
+					}
+				}
+			}
+
+			Pallet::<T>::do_try_state().map_err(|_| "inconsistent participations")
+		}
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "try-runtime")]
+mod test {
+	use super::*;
+	use crate::mock::{Test as T, *};
+	use frame_support::{assert_ok, pallet_prelude::StorageVersion, storage::unhashed, BoundedVec};
+
+	#[test]
+	fn migration_works() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0);
+			run_to_block(10);
+
+			unhashed::put(&BountyExpireTime::<T>::hashed_key_for(5), &(1u32, 1u32));
+			unhashed::put(&BountyExpireTime::<T>::hashed_key_for(20), &(2u32, 1u32));
+
+			let state = v1::MigrateToV1::<T>::pre_upgrade().unwrap();
+			let _w = v1::MigrateToV1::<T>::on_runtime_upgrade();
+			v1::MigrateToV1::<T>::post_upgrade(state).unwrap();
+
+			assert!(!BountyExpireTime::<T>::contains_key(5));
+			assert_eq!(BountyExpireTime::<T>::get(20).into_inner(), vec![(2, 1)]);
+			assert_eq!(BountyExpiryCursor::<T>::get(), Some(10));
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
+		});
+	}
+
+	#[test]
+	fn migration_to_v2_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			crate::tests::update_bounty();
+			assert_ok!(crate::tests::create_another_bounty());
+			let _ = ParticipatedBounties::<T>::clear(u32::MAX, None);
+
+			// Closing bounty 2 before the upgrade: its participants are not indexed.
+			let participants: BoundedVec<u64, <T as Config>::AccountLimit> =
+				vec![3].try_into().unwrap();
+			Bounties::<T>::mutate(1, 2, |bounty| {
+				let bounty = bounty.as_mut().unwrap();
+				bounty.participants = participants;
+				bounty.status = BountyStatus::Completed;
+			});
+
+			let state = v2::MigrateToV2::<T>::pre_upgrade().unwrap();
+			let _w = v2::MigrateToV2::<T>::on_runtime_upgrade();
+			v2::MigrateToV2::<T>::post_upgrade(state).unwrap();
+
+			assert_eq!(ParticipatedBounties::<T>::get(1, 3).into_inner(), vec![1]);
+			assert_eq!(ParticipatedBounties::<T>::get(1, 4).into_inner(), vec![1]);
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+		});
+	}
+}
@@ -1,7 +1,7 @@
-use crate::{mock::*, Bounties, Error};
-use frame_support::assert_noop;
+use crate::{mock::*, types::BountyStatus, Bounties, Error, Event, ParticipatedBounties};
 use frame_support::pallet_prelude::ConstU32;
 use frame_support::BoundedVec;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
 use pallet_community::types::{
 	Category, CommunityMetaData, CommunityType, Customs, Languages, Religions, Territories,
 	Traditions, Values,
@@ -61,6 +61,7 @@ pub fn add_founder() {
 }
 
 fn create_community() {
+	let community_id = pallet_community::NextCommunityId::<Test>::get().unwrap_or(1);
 	Community::create_community(
 		RuntimeOrigin::signed(1),
 		// hash of IPFS path of dummy logo
@@ -79,6 +80,9 @@ fn create_community() {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in [2, 3, 4, 5] {
+		Community::accept_invitation(RuntimeOrigin::signed(member), community_id).unwrap();
+	}
 }
 
 fn add_badge() {
@@ -143,7 +147,29 @@ pub fn create_bounty() {
 	.unwrap();
 }
 
-fn update_bounty() {
+pub fn create_another_bounty() -> DispatchResultWithPostInfo {
+	let bounty_name: Vec<u8> = "Bounty to help in design work".into();
+	let bounded_bounty_name: BoundedVec<u8, ConstU32<512>> = bounty_name.try_into().unwrap();
+
+	let bounty_description: Vec<u8> = "Design bounty for the jur community members".into();
+	let bounded_bounty_description: BoundedVec<u8, ConstU32<8192>> =
+		bounty_description.try_into().unwrap();
+
+	let badge_name: Vec<u8> = "DEVBOUNTY".into();
+	let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
+
+	BountyPallet::create_bounty(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_bounty_name,
+		vec![],
+		bounded_badge_name,
+		bounded_bounty_description,
+		2,
+	)
+}
+
+pub fn update_bounty() {
 	create_bounty();
 	Passport::mint(RuntimeOrigin::signed(3), 1).unwrap();
 	Passport::mint(RuntimeOrigin::signed(4), 1).unwrap();
@@ -489,3 +515,100 @@ fn complete_bounty_not_works_founder_as_contributor() {
 		);
 	});
 }
+
+#[test]
+fn leaving_community_removes_bounty_participant() {
+	new_test_ext().execute_with(|| {
+		update_bounty();
+
+		Community::leave_community(RuntimeOrigin::signed(3), 1).unwrap();
+
+		let bounty = Bounties::<Test>::get(1, 1).unwrap();
+		assert_eq!(bounty.participants.to_vec(), vec![4]);
+		assert!(!ParticipatedBounties::<Test>::contains_key(1, 3));
+		assert_ok!(BountyPallet::do_try_state());
+	});
+}
+
+#[test]
+fn update_bounty_keeps_participations_in_sync() {
+	new_test_ext().execute_with(|| {
+		update_bounty();
+		assert_eq!(ParticipatedBounties::<Test>::get(1, 3).into_inner(), vec![1]);
+		assert_eq!(ParticipatedBounties::<Test>::get(1, 4).into_inner(), vec![1]);
+
+		let bounded_accounts: BoundedVec<<Test as frame_system::Config>::AccountId, ConstU32<500>> =
+			vec![4].try_into().unwrap();
+		assert_ok!(BountyPallet::update_bounty(RuntimeOrigin::signed(1), 1, 1, bounded_accounts));
+
+		assert!(!ParticipatedBounties::<Test>::contains_key(1, 3));
+		assert_eq!(ParticipatedBounties::<Test>::get(1, 4).into_inner(), vec![1]);
+		assert_ok!(BountyPallet::do_try_state());
+
+		// Closing the bounty releases its participants.
+		run_to_block(28_801);
+		assert!(!ParticipatedBounties::<Test>::contains_key(1, 4));
+		assert_ok!(BountyPallet::do_try_state());
+	});
+}
+
+#[test]
+fn update_bounty_not_works_for_too_many_participations() {
+	new_test_ext().execute_with(|| {
+		update_bounty();
+		assert_ok!(create_another_bounty());
+		assert_ok!(create_another_bounty());
+
+		let bounded_accounts: BoundedVec<<Test as frame_system::Config>::AccountId, ConstU32<500>> =
+			vec![3].try_into().unwrap();
+		assert_ok!(BountyPallet::update_bounty(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			bounded_accounts.clone()
+		));
+		assert_noop!(
+			BountyPallet::update_bounty(RuntimeOrigin::signed(1), 1, 3, bounded_accounts),
+			Error::<Test>::TooManyBounties
+		);
+	});
+}
+
+#[test]
+fn bounties_expiring_in_the_same_block_are_all_closed() {
+	new_test_ext().execute_with(|| {
+		create_bounty();
+		assert_ok!(create_another_bounty());
+		assert_ok!(create_another_bounty());
+		assert_noop!(create_another_bounty(), Error::<Test>::TooManyExpiries);
+		assert_ok!(BountyPallet::do_try_state());
+
+		// Only two bounties are closed in the block of the deadline.
+		run_to_block(28_801);
+		assert_eq!(Bounties::<Test>::get(1, 1).unwrap().status, BountyStatus::Completed);
+		assert_eq!(Bounties::<Test>::get(1, 2).unwrap().status, BountyStatus::Completed);
+		assert_eq!(Bounties::<Test>::get(1, 3).unwrap().status, BountyStatus::Ongoing);
+
+		// The remaining one is carried over to the next block.
+		run_to_block(28_802);
+		assert_eq!(Bounties::<Test>::get(1, 3).unwrap().status, BountyStatus::Completed);
+	});
+}
+
+#[test]
+fn expired_bounty_close_failure_is_reported() {
+	new_test_ext().execute_with(|| {
+		create_bounty();
+		assert_ok!(BountyPallet::do_try_state());
+
+		Bounties::<Test>::remove(1, 1);
+		assert!(BountyPallet::do_try_state().is_err());
+
+		run_to_block(28_801);
+
+		System::assert_has_event(
+			Event::<Test>::BountyCloseFailed(1, 1, Error::<Test>::BountyNotAvailable.into()).into(),
+		);
+		assert_ok!(BountyPallet::do_try_state());
+	});
+}
@@ -32,6 +32,8 @@ pub trait WeightInfo {
 	fn create_bounty() -> Weight;
 	fn update_bounty() -> Weight;
 	fn complete_bounty() -> Weight;
+	fn service_expiry_block() -> Weight;
+	fn close_bounty() -> Weight;
 }
 
 /// Weights for pallet_bounties using the Substrate node and recommended hardware.
@@ -43,7 +45,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::NextBountyId` (r:1 w:1)
 	/// Proof: `Bounties::NextBountyId` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Bounties::BountyExpireTime` (r:0 w:1)
+	/// Storage: `Bounties::BountyExpireTime` (r:1 w:1)
 	/// Proof: `Bounties::BountyExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::Bounties` (r:0 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -53,7 +55,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4633`
 		// Minimum execution time: 29_000_000 picoseconds.
 		Weight::from_parts(30_000_000, 4633)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
@@ -62,14 +64,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:2 w:2)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1422`
 		//  Estimated: `7362`
 		// Minimum execution time: 32_000_000 picoseconds.
 		Weight::from_parts(32_000_000, 7362)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -77,13 +81,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:1 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn complete_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1486`
 		//  Estimated: `7426`
 		// Minimum execution time: 40_000_000 picoseconds.
 		Weight::from_parts(41_000_000, 7426)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::BountyExpiryCursor` (r:1 w:1)
+	/// Proof: `Bounties::BountyExpiryCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::BountyExpireTime` (r:1 w:1)
+	/// Proof: `Bounties::BountyExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_expiry_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3604`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 3604)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:2 w:2)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn close_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
@@ -96,7 +130,7 @@ impl WeightInfo for () {
 	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::NextBountyId` (r:1 w:1)
 	/// Proof: `Bounties::NextBountyId` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Bounties::BountyExpireTime` (r:0 w:1)
+	/// Storage: `Bounties::BountyExpireTime` (r:1 w:1)
 	/// Proof: `Bounties::BountyExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::Bounties` (r:0 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -106,7 +140,7 @@ impl WeightInfo for () {
 		//  Estimated: `4633`
 		// Minimum execution time: 29_000_000 picoseconds.
 		Weight::from_parts(30_000_000, 4633)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
@@ -115,14 +149,16 @@ impl WeightInfo for () {
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:2 w:2)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1422`
 		//  Estimated: `7362`
 		// Minimum execution time: 32_000_000 picoseconds.
 		Weight::from_parts(32_000_000, 7362)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -130,13 +166,43 @@ impl WeightInfo for () {
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:1 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn complete_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1486`
 		//  Estimated: `7426`
 		// Minimum execution time: 40_000_000 picoseconds.
 		Weight::from_parts(41_000_000, 7426)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::BountyExpiryCursor` (r:1 w:1)
+	/// Proof: `Bounties::BountyExpiryCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::BountyExpireTime` (r:1 w:1)
+	/// Proof: `Bounties::BountyExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_expiry_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3604`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 3604)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:2 w:2)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn close_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+//! Runtime API definition for the Jur Community Pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Read access to the treasuries, the hierarchy, the reference ids, the names and the
+	/// membership dues of the communities.
+	pub trait CommunityApi<AccountId, CommunityId, Balance, AssetId, BlockNumber>
+	where
+		AccountId: Codec,
+		CommunityId: Codec,
+		Balance: Codec,
+		AssetId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The keyless account holding the funds of the community.
+		fn treasury_account(community_id: CommunityId) -> AccountId;
+
+		/// The JUR balance of the community treasury.
+		fn treasury_balance(community_id: CommunityId) -> Balance;
+
+		/// The balance of an asset held by the community treasury.
+		fn treasury_asset_balance(community_id: CommunityId, asset_id: AssetId) -> Balance;
+
+		/// All the direct and indirect sub-communities of the community.
+		fn descendants(community_id: CommunityId) -> Vec<CommunityId>;
+
+		/// The community shared under the given reference id.
+		fn community_by_reference_id(reference_id: [u8; 16]) -> Option<CommunityId>;
+
+		/// The community registered under the given name, ignoring whitespace and letter case.
+		fn community_by_name(name: Vec<u8>) -> Option<CommunityId>;
+
+		/// The block until which the member paid the recurring due of the community,
+		/// `None` if the member is exempt or the community charges no recurring due.
+		fn dues_paid_until(community_id: CommunityId, who: AccountId) -> Option<BlockNumber>;
+
+		/// Whether the member stopped paying the recurring due of the community.
+		fn is_lapsed(community_id: CommunityId, who: AccountId) -> bool;
+	}
+}
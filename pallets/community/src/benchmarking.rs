@@ -5,6 +5,7 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Community;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{fungibles, Currency};
 use frame_system::RawOrigin;
 use sp_std::{prelude::*, vec};
 
@@ -59,11 +60,106 @@ fn get_metadata<T: Config>() -> CommunityMetaData<T::StringLimit> {
 		]),
 	}
 }
+/// Give the founder enough balance to reserve the metadata deposit of a community.
+fn fund_founder<T: Config>(founder: &T::AccountId) {
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		founder,
+		T::MetadataDepositPerByte::get().saturating_mul(100_000u32.into()),
+	);
+}
+
+fn create_nft_gated_community<T: Config>(founder: T::AccountId, members: Vec<T::AccountId>) {
+	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), founder.clone()).unwrap();
+	fund_founder::<T>(&founder);
+
+	let community_id = NextCommunityId::<T>::get().unwrap_or(T::CommunityId::initial_value());
+
+	Community::<T>::create_community(
+		RawOrigin::Signed(founder).into(),
+		// hash of IPFS path of dummy logo
+		Some("bafkreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into()),
+		"Jur".into(),
+		Some(
+			"Jur is the core community of the Jur ecosystem, which includes all the contributors."
+				.into(),
+		),
+		Some(members.clone()),
+		Some(get_metadata::<T>()),
+		Category::NFTGated,
+		Some("tag".into()),
+		Some("#222307".into()),
+		Some("#E76080".into()),
+		Some(CommunityType::Nation),
+	)
+	.unwrap();
+	for member in members {
+		Community::<T>::accept_invitation(RawOrigin::Signed(member).into(), community_id).unwrap();
+	}
+}
+
+fn create_state<T: Config>(founder: T::AccountId, members: Vec<T::AccountId>) {
+	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), founder.clone()).unwrap();
+	fund_founder::<T>(&founder);
+
+	let community_id = NextCommunityId::<T>::get().unwrap_or(T::CommunityId::initial_value());
+
+	Community::<T>::create_community(
+		RawOrigin::Signed(founder).into(),
+		// hash of IPFS path of dummy logo
+		Some("bafkreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into()),
+		"Jur".into(),
+		Some(
+			"Jur is the core community of the Jur ecosystem, which includes all the contributors."
+				.into(),
+		),
+		Some(members.clone()),
+		Some(get_metadata::<T>()),
+		Category::Public,
+		Some("tag".into()),
+		Some("#222307".into()),
+		Some("#E76080".into()),
+		Some(CommunityType::State(State {
+			constitution: vec![b"All members are equal".to_vec().try_into().unwrap()]
+				.try_into()
+				.unwrap(),
+			government: vec![],
+			citizens: vec![],
+		})),
+	)
+	.unwrap();
+	for member in members {
+		Community::<T>::accept_invitation(RawOrigin::Signed(member).into(), community_id).unwrap();
+	}
+}
+
+fn create_sub_community<T: Config>(founder: T::AccountId) {
+	Community::<T>::create_community(
+		RawOrigin::Signed(founder).into(),
+		None,
+		"Jur Sub".into(),
+		None,
+		None,
+		None,
+		Category::Public,
+		None,
+		None,
+		None,
+		Some(CommunityType::Community),
+	)
+	.unwrap();
+}
+
 benchmarks! {
+	where_clause { where T::Assets: fungibles::Create<T::AccountId> }
+
 	create_community {
+		// Initial citizens are added right away, which costs more than an invitation.
+		let m in 0 .. T::MaxInvitationsPerBlock::get();
+
 		let caller: T::AccountId = whitelisted_caller();
-		let members = vec![account("sub", 1, SEED), account("sub", 2, SEED)];
+		let citizens: Vec<T::AccountId> = (0 .. m).map(|i| account("citizen", i, SEED)).collect();
 		pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
+		fund_founder::<T>(&caller);
 
 	}: _(
 		RawOrigin::Signed(caller.clone()),
@@ -71,16 +167,23 @@ benchmarks! {
 		Some("bafkreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into()),
 		"Jur".into(),
 		Some("Jur is the core community of the Jur ecosystem, which includes all the contributors.".into()),
-		Some(members),
+		Some(citizens.clone()),
 		Some(get_metadata::<T>()),
 		Category::Public,
 		Some("tag".into()),
 		Some("#222307".into()),
 		Some("#E76080".into()),
-		Some(CommunityType::Nation)
+		Some(CommunityType::State(State {
+			constitution: vec![b"All members are equal".to_vec().try_into().unwrap()]
+				.try_into()
+				.unwrap(),
+			government: vec![],
+			citizens,
+		}))
 	)
 	verify {
 		assert!(Communities::<T>::get(T::Helper::community(1)).is_some());
+		assert_eq!(MemberCount::<T>::get(T::Helper::community(1)), m);
 	}
 
 	update_community {
@@ -89,6 +192,8 @@ benchmarks! {
 
 		pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
 
+		fund_founder::<T>(&caller);
+
 		Community::<T>::create_community(
 			RawOrigin::Signed(caller.clone()).into(),
 			// hash of IPFS path of dummy logo
@@ -120,6 +225,8 @@ benchmarks! {
 
 		pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
 
+		fund_founder::<T>(&caller);
+
 		Community::<T>::create_community(
 			RawOrigin::Signed(caller.clone()).into(),
 			// hash of IPFS path of dummy logo
@@ -186,42 +293,14 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::UpdatedMetadata(T::Helper::community(1)).into());
 	}
 
-	accept_members {
-	let caller: T::AccountId = whitelisted_caller();
-	let members = vec![account("sub", 1, SEED)];
-
-	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
-
-	Community::<T>::create_community(
-		RawOrigin::Signed(caller.clone()).into(),
-		// hash of IPFS path of dummy logo
-		Some("bafkreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into()),
-		"Jur".into(),
-		Some("Jur is the core community of the Jur ecosystem, which includes all the contributors.".into()),
-		Some(members),
-		Some(get_metadata::<T>()),
-		Category::Public,
-		Some("tag".into()),
-		Some("#222307".into()),
-		Some("#E76080".into()),
-		Some(CommunityType::Nation)
-	).unwrap();
-
-	let members = vec![account("sub", 2, SEED), account("sub", 3, SEED)];
-
-	}: _(
-		RawOrigin::Signed(caller), T::Helper::community(1), members
-	)
-	verify {
-		assert_last_event::<T>(Event::<T>::AddedMembers(T::Helper::community(1)).into());
-	}
-
 	join_community {
 	let caller: T::AccountId = whitelisted_caller();
 	let members = vec![account("sub", 1, SEED)];
 
 	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
 
+	fund_founder::<T>(&caller);
+
 	Community::<T>::create_community(
 		RawOrigin::Signed(caller.clone()).into(),
 		// hash of IPFS path of dummy logo
@@ -252,6 +331,8 @@ benchmarks! {
 
 	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
 
+	fund_founder::<T>(&caller);
+
 	Community::<T>::create_community(
 		RawOrigin::Signed(caller.clone()).into(),
 		// hash of IPFS path of dummy logo
@@ -280,6 +361,8 @@ benchmarks! {
 
 	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
 
+	fund_founder::<T>(&caller);
+
 	Community::<T>::create_community(
 		RawOrigin::Signed(caller.clone()).into(),
 		// hash of IPFS path of dummy logo
@@ -309,6 +392,8 @@ benchmarks! {
 
 		pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller.clone()).unwrap();
 
+		fund_founder::<T>(&caller);
+
 		Community::<T>::create_community(
 			RawOrigin::Signed(caller.clone()).into(),
 			// hash of IPFS path of dummy logo
@@ -345,5 +430,599 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::UpdatedRequiredFounderBalance(required_founder_balance).into());
 	}
 
+	request_to_join {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller, vec![]);
+
+	}: _(
+		RawOrigin::Signed(member.clone()), T::Helper::community(1), Some(Default::default())
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::JoinRequested(T::Helper::community(1), member).into());
+	}
+
+	approve_join_request {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+		Community::<T>::request_to_join(
+			RawOrigin::Signed(member.clone()).into(),
+			T::Helper::community(1),
+			Some(Default::default())
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), member.clone()
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::JoinRequestApproved(T::Helper::community(1), member).into());
+	}
+
+	reject_join_request {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+		Community::<T>::request_to_join(
+			RawOrigin::Signed(member.clone()).into(),
+			T::Helper::community(1),
+			Some(Default::default())
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), member.clone()
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::JoinRequestRejected(T::Helper::community(1), member).into());
+	}
+
+	set_membership_gate {
+		let caller: T::AccountId = whitelisted_caller();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), Some(T::Helper::gate_requirement())
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::UpdatedMembershipGate(T::Helper::community(1)).into());
+	}
+
+	revalidate_membership {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![member.clone()]);
+
+		Community::<T>::set_membership_gate(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1),
+			Some(T::Helper::gate_requirement())
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), member.clone()
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::RevokedMembership(T::Helper::community(1), member).into());
+	}
+
+	grant_role {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![member.clone()]);
+
+	}: _(
+		RawOrigin::Signed(caller),
+		T::Helper::community(1),
+		member.clone(),
+		"Moderator".into(),
+		Permissions::ACCEPT_MEMBERS
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::GrantedRole(T::Helper::community(1), member).into());
+	}
+
+	revoke_role {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![member.clone()]);
+
+		Community::<T>::grant_role(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1),
+			member.clone(),
+			"Moderator".into(),
+			Permissions::ACCEPT_MEMBERS
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), member.clone()
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::RevokedRole(T::Helper::community(1), member).into());
+	}
+
+	propose_founder_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_founder: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), new_founder.clone()
+	)
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::FounderTransferProposed(T::Helper::community(1), new_founder).into()
+		);
+	}
+
+	accept_founder_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_founder: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+		Community::<T>::propose_founder_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1),
+			new_founder.clone()
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(new_founder.clone()), T::Helper::community(1)
+	)
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::FounderTransferred(T::Helper::community(1), caller, new_founder).into()
+		);
+	}
+
+	dissolve_community {
+		let caller: T::AccountId = whitelisted_caller();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1)
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::DissolvedCommunity(T::Helper::community(1)).into());
+	}
+
+	slash_founder_bond {
+		let caller: T::AccountId = whitelisted_caller();
+		let bond: T::Balance = 1_000_000_000u32.into();
+
+		Community::<T>::update_required_founder_balance(RawOrigin::Root.into(), bond).unwrap();
+		pallet_balances::Pallet::<T>::make_free_balance_be(&caller, bond.saturating_mul(10u32.into()));
+		create_nft_gated_community::<T>(caller, vec![]);
+
+	}: _(
+		RawOrigin::Root, T::Helper::community(1)
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::SlashedFounderBond(T::Helper::community(1), bond).into());
+	}
+
+	spend {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let amount: T::Balance = 1_000_000_000u32.into();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		pallet_balances::Pallet::<T>::make_free_balance_be(
+			&Community::<T>::treasury_account(T::Helper::community(1)),
+			amount.saturating_mul(10u32.into()),
+		);
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), beneficiary.clone(), amount
+	)
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::TreasurySpent(T::Helper::community(1), beneficiary, amount).into()
+		);
+	}
+
+	spend_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let asset = T::Helper::asset(1);
+		let amount: AssetBalanceOf<T> = 1_000_000_000u32.into();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		<T::Assets as fungibles::Create<T::AccountId>>::create(
+			asset.clone(),
+			caller.clone(),
+			true,
+			1u32.into(),
+		)?;
+		<T::Assets as fungibles::Mutate<T::AccountId>>::mint_into(
+			asset.clone(),
+			&Community::<T>::treasury_account(T::Helper::community(1)),
+			amount.saturating_mul(10u32.into()),
+		)?;
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), asset.clone(), beneficiary.clone(), amount
+	)
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::TreasuryAssetSpent(T::Helper::community(1), asset, beneficiary, amount).into()
+		);
+	}
+
+	invite_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), member.clone()
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::InvitedMember(T::Helper::community(1), member).into());
+	}
+
+	create_invite_code {
+		let caller: T::AccountId = whitelisted_caller();
+		let code_hash = T::Hashing::hash(b"invite");
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), code_hash
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::CreatedInviteCode(T::Helper::community(1), code_hash).into());
+	}
+
+	accept_invitation {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		Community::<T>::invite_member(
+			RawOrigin::Signed(caller).into(),
+			T::Helper::community(1),
+			member.clone()
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(member.clone()), T::Helper::community(1)
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::AcceptedInvitation(T::Helper::community(1), member).into());
+	}
+
+	decline_invitation {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		Community::<T>::invite_member(
+			RawOrigin::Signed(caller).into(),
+			T::Helper::community(1),
+			member.clone()
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(member.clone()), T::Helper::community(1)
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::DeclinedInvitation(T::Helper::community(1), member).into());
+	}
+
+	redeem_invite_code {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+		let code: Vec<u8> = b"invite".to_vec();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		Community::<T>::create_invite_code(
+			RawOrigin::Signed(caller).into(),
+			T::Helper::community(1),
+			T::Hashing::hash(&code)
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(member.clone()), T::Helper::community(1), code
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::RedeemedInviteCode(T::Helper::community(1), member).into());
+	}
+
+	revoke_invitation {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		Community::<T>::invite_member(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1),
+			member.clone()
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), Invitee::Account(member.clone())
+	)
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::RevokedInvitation(T::Helper::community(1), Invitee::Account(member)).into()
+		);
+	}
+
+	ban_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![member.clone()]);
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), member.clone(), Some(Default::default()), None
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::BannedMember(T::Helper::community(1), member).into());
+	}
+
+	unban_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_nft_gated_community::<T>(caller.clone(), vec![member.clone()]);
+		Community::<T>::ban_member(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1),
+			member.clone(),
+			None,
+			None
+		).unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller), T::Helper::community(1), member.clone()
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::UnbannedMember(T::Helper::community(1), member).into());
+	}
+
+	apply_for_citizenship {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_state::<T>(caller, vec![member.clone()]);
+
+	}: _(RawOrigin::Signed(member.clone()), T::Helper::community(1))
+	verify {
+		assert_last_event::<T>(Event::<T>::CitizenshipApplied(T::Helper::community(1), member).into());
+	}
+
+	approve_citizenship {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_state::<T>(caller.clone(), vec![member.clone()]);
+		Community::<T>::apply_for_citizenship(
+			RawOrigin::Signed(member.clone()).into(),
+			T::Helper::community(1)
+		).unwrap();
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(1), member.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::CitizenshipGranted(T::Helper::community(1), member).into());
+	}
+
+	reject_citizenship {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_state::<T>(caller.clone(), vec![member.clone()]);
+		Community::<T>::apply_for_citizenship(
+			RawOrigin::Signed(member.clone()).into(),
+			T::Helper::community(1)
+		).unwrap();
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(1), member.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::CitizenshipRejected(T::Helper::community(1), member).into());
+	}
+
+	revoke_citizenship {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+
+		create_state::<T>(caller.clone(), vec![member.clone()]);
+		Community::<T>::apply_for_citizenship(
+			RawOrigin::Signed(member.clone()).into(),
+			T::Helper::community(1)
+		).unwrap();
+		Community::<T>::approve_citizenship(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1),
+			member.clone()
+		).unwrap();
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(1), member.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::CitizenshipRevoked(T::Helper::community(1), member).into());
+	}
+
+	request_parent {
+		let caller: T::AccountId = whitelisted_caller();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		create_sub_community::<T>(caller.clone());
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(2), T::Helper::community(1), true, true)
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::ParentRequested(T::Helper::community(2), T::Helper::community(1)).into()
+		);
+	}
+
+	approve_sub_community {
+		let caller: T::AccountId = whitelisted_caller();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		create_sub_community::<T>(caller.clone());
+		Community::<T>::request_parent(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(2),
+			T::Helper::community(1),
+			true,
+			true
+		).unwrap();
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(1), T::Helper::community(2))
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::SubCommunityAdded(T::Helper::community(1), T::Helper::community(2)).into()
+		);
+	}
+
+	reject_sub_community {
+		let caller: T::AccountId = whitelisted_caller();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		create_sub_community::<T>(caller.clone());
+		Community::<T>::request_parent(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(2),
+			T::Helper::community(1),
+			true,
+			true
+		).unwrap();
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(1), T::Helper::community(2))
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::SubCommunityRejected(T::Helper::community(1), T::Helper::community(2)).into()
+		);
+	}
+
+	detach_sub_community {
+		let caller: T::AccountId = whitelisted_caller();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		create_sub_community::<T>(caller.clone());
+		Community::<T>::request_parent(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(2),
+			T::Helper::community(1),
+			true,
+			true
+		).unwrap();
+		Community::<T>::approve_sub_community(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1),
+			T::Helper::community(2)
+		).unwrap();
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(2))
+	verify {
+		assert_last_event::<T>(
+			Event::<T>::SubCommunityDetached(T::Helper::community(1), T::Helper::community(2)).into()
+		);
+	}
+
+	set_membership_dues {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: T::Balance = 1_000_000_000u32.into();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+
+	}: _(
+		RawOrigin::Signed(caller),
+		T::Helper::community(1),
+		Some(DuesAmount::Native(amount)),
+		Some(RecurringDue { amount: DuesAmount::Native(amount), period: 100u32.into() })
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::UpdatedMembershipDues(T::Helper::community(1)).into());
+	}
+
+	pay_dues {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+		let amount: T::Balance = 1_000_000_000u32.into();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![member.clone()]);
+		Community::<T>::set_membership_dues(
+			RawOrigin::Signed(caller).into(),
+			T::Helper::community(1),
+			None,
+			Some(RecurringDue { amount: DuesAmount::Native(amount), period: 100u32.into() })
+		).unwrap();
+		pallet_balances::Pallet::<T>::make_free_balance_be(
+			&member,
+			amount.saturating_mul(10u32.into()),
+		);
+
+	}: _(RawOrigin::Signed(member.clone()), T::Helper::community(1), 2)
+	verify {
+		assert!(DuesPaidUntil::<T>::get(T::Helper::community(1), &member).is_some());
+	}
+
+	claim_treasury_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = T::Helper::asset(1);
+		let amount: AssetBalanceOf<T> = 1_000_000_000u32.into();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		<T::Assets as fungibles::Create<T::AccountId>>::create(
+			asset.clone(),
+			caller.clone(),
+			true,
+			1u32.into(),
+		)?;
+		<T::Assets as fungibles::Mutate<T::AccountId>>::mint_into(
+			asset.clone(),
+			&Community::<T>::treasury_account(T::Helper::community(1)),
+			amount,
+		)?;
+		Community::<T>::dissolve_community(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Helper::community(1)
+		).unwrap();
+
+	}: _(RawOrigin::Signed(caller), T::Helper::community(1), asset.clone())
+	verify {
+		assert!(Community::<T>::treasury_asset_balance(T::Helper::community(1), asset).is_zero());
+	}
+
+	clean_up_community {
+		let n in 1 .. T::MaxInvitationsPerBlock::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		create_nft_gated_community::<T>(caller.clone(), vec![]);
+		for i in 0 .. n {
+			Community::<T>::invite_member(
+				RawOrigin::Signed(caller.clone()).into(),
+				T::Helper::community(1),
+				account("invitee", i, SEED)
+			).unwrap();
+		}
+		Community::<T>::dissolve_community(
+			RawOrigin::Signed(caller).into(),
+			T::Helper::community(1)
+		).unwrap();
+
+	}: {
+		Community::<T>::do_clean_up_community(T::Helper::community(1), n);
+	}
+	verify {
+		assert_eq!(Invitations::<T>::iter_prefix(T::Helper::community(1)).count(), 0);
+	}
+
 	impl_benchmark_test_suite!(Community, crate::mock::new_test_ext(), crate::mock::Test);
 }
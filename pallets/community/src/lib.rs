@@ -17,22 +17,78 @@
 //! * `update_community`
 //! * `update_metadata`
 //! * `delete_community`
-//! * `accept_members`
 //! * `join_community`
+//! * `request_to_join`
+//! * `approve_join_request`
+//! * `reject_join_request`
+//! * `set_membership_gate`
+//! * `revalidate_membership`
+//! * `grant_role`
+//! * `revoke_role`
+//! * `propose_founder_transfer`
+//! * `accept_founder_transfer`
+//! * `dissolve_community`
+//! * `slash_founder_bond`
+//! * `spend`
+//! * `spend_asset`
+//! * `invite_member`
+//! * `create_invite_code`
+//! * `accept_invitation`
+//! * `decline_invitation`
+//! * `redeem_invite_code`
+//! * `revoke_invitation`
+//! * `ban_member`
+//! * `unban_member`
+//! * `apply_for_citizenship`
+//! * `approve_citizenship`
+//! * `reject_citizenship`
+//! * `revoke_citizenship`
+//! * `request_parent`
+//! * `approve_sub_community`
+//! * `reject_sub_community`
+//! * `detach_sub_community`
+//! * `set_membership_dues`
+//! * `pay_dues`
+//! * `claim_treasury_asset`
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use crate::types::*;
-use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Randomness, BoundedVec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{
+		fungibles,
+		tokens::{Fortitude, Preservation},
+		Currency, EnsureOrigin, ExistenceRequirement, OnUnbalanced, ReservableCurrency,
+	},
+	weights::Weight,
+	Blake2_128, BoundedVec, PalletId, StorageHasher,
+};
+use frame_system::pallet_prelude::OriginFor;
 pub use pallet::*;
 use primitives::Incrementable;
 use scale_info::prelude::string::String;
-use sp_runtime::RuntimeDebug;
-use sp_std::vec::Vec;
+use sp_runtime::{
+	traits::{AccountIdConversion, Hash, Zero},
+	DispatchError, RuntimeDebug, Saturating,
+};
+use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, vec::Vec};
+pub use traits::{
+	CommunityPermissions, MembershipGate, OnCitizenshipRevoked, OnCommunityDissolved,
+	OnMemberJoined, OnMemberLeft,
+};
 pub use weights::WeightInfo;
 
+/// Domain separator for the derivation of the community reference ids.
+const REFERENCE_ID_CONTEXT: &[u8; 8] = b"jur/cref";
+
+/// The number of salts tried before giving up on deriving a unique reference id.
+const MAX_REFERENCE_ID_ATTEMPTS: u32 = 8;
+
+pub mod traits;
 pub mod types;
 
 #[cfg(test)]
@@ -56,17 +112,27 @@ pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
 
 	#[cfg(feature = "runtime-benchmarks")]
-	pub trait BenchmarkHelper<CommunityId> {
+	pub trait BenchmarkHelper<CommunityId, GateRequirement, AssetId> {
 		fn community(i: u32) -> CommunityId;
+		fn gate_requirement() -> GateRequirement;
+		fn asset(i: u32) -> AssetId;
 	}
 	#[cfg(feature = "runtime-benchmarks")]
-	impl<CommunityId: From<u32>> BenchmarkHelper<CommunityId> for () {
+	impl<CommunityId: From<u32>, GateRequirement: Default, AssetId: From<u32>>
+		BenchmarkHelper<CommunityId, GateRequirement, AssetId> for ()
+	{
 		fn community(i: u32) -> CommunityId {
 			i.into()
 		}
+		fn gate_requirement() -> GateRequirement {
+			Default::default()
+		}
+		fn asset(i: u32) -> AssetId {
+			i.into()
+		}
 	}
 
 	/// Configure the pallet by specifying the parameters and types on which it
@@ -99,13 +165,11 @@ pub mod pallet {
 
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
-		type Helper: BenchmarkHelper<Self::CommunityId>;
+		type Helper: BenchmarkHelper<Self::CommunityId, GateRequirementOf<Self>, AssetIdOf<Self>>;
 
 		/// Weight information
 		type WeightInfo: WeightInfo;
 
-		type MyRandomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
-
 		/// The maximum length of tag.
 		#[pallet::constant]
 		type TagLimit: Get<u32>;
@@ -125,6 +189,77 @@ pub mod pallet {
 		/// The maximum length of logo.
 		#[pallet::constant]
 		type LogoLimit: Get<u32>;
+
+		/// The maximum number of articles in the constitution of a State.
+		#[pallet::constant]
+		type MaxArticles: Get<u32>;
+
+		/// The maximum length of a constitution article.
+		#[pallet::constant]
+		type ArticleLimit: Get<u32>;
+
+		/// The number of blocks after which a pending join request expires.
+		#[pallet::constant]
+		type JoinRequestTtl: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of join requests which can expire in the same block.
+		#[pallet::constant]
+		type MaxJoinRequestsPerBlock: Get<u32>;
+
+		/// The number of blocks after which a pending invitation expires.
+		#[pallet::constant]
+		type InvitationTtl: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of invitations which can expire in the same block.
+		#[pallet::constant]
+		type MaxInvitationsPerBlock: Get<u32>;
+
+		/// Token requirement checked for the members of `NFTGated` communities.
+		type MembershipGate: MembershipGate<Self::AccountId>;
+
+		/// Handler removing the state other pallets keep for a dissolved community.
+		type OnCommunityDissolved: OnCommunityDissolved<Self::CommunityId>;
+
+		/// The maximum number of storage items removed per block for dissolved communities.
+		#[pallet::constant]
+		type DissolveCleanupLimit: Get<u32>;
+
+		/// Origin allowed to slash the founder bond of a community.
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Handler for the slashed founder bonds.
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The pallet id from which the treasury accounts of the communities are derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The fungible assets the community treasuries can hold.
+		type Assets: fungibles::Inspect<Self::AccountId> + fungibles::Mutate<Self::AccountId>;
+
+		/// Handler for the accounts losing the citizenship of a State.
+		type OnCitizenshipRevoked: OnCitizenshipRevoked<Self::CommunityId, Self::AccountId>;
+
+		/// The maximum number of levels in a hierarchy of communities.
+		#[pallet::constant]
+		type MaxHierarchyDepth: Get<u32>;
+
+		/// The maximum number of direct and indirect sub-communities of a community.
+		#[pallet::constant]
+		type MaxDescendants: Get<u32>;
+
+		/// Handler for the accounts joining a community.
+		type OnMemberJoined: OnMemberJoined<Self::CommunityId, Self::AccountId>;
+
+		/// Handler for the accounts leaving or being removed from a community.
+		type OnMemberLeft: OnMemberLeft<Self::CommunityId, Self::AccountId>;
+
+		/// The deposit reserved from the founder per byte of logo, description and metadata.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<Self::Balance>;
+
+		/// Origin of a community governing itself through its accepted proposals.
+		type CommunityOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::CommunityId>;
 	}
 
 	#[pallet::pallet]
@@ -132,9 +267,9 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	/// To be used in generating refernce number
-	#[pallet::storage]
-	pub(crate) type Nonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+	/// The origin of a community, dispatching the call of one of its accepted proposals.
+	#[pallet::origin]
+	pub type Origin<T> = CommunityOrigin<<T as Config>::CommunityId>;
 
 	/// Store the community with community id
 	#[pallet::storage]
@@ -154,6 +289,193 @@ pub mod pallet {
 		>,
 	>;
 
+	/// Store the community id for a normalized community name
+	#[pallet::storage]
+	#[pallet::getter(fn community_name_index)]
+	pub type CommunityNameIndex<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::NameLimit>, T::CommunityId, OptionQuery>;
+
+	/// Store the token requirement of a `NFTGated` community
+	#[pallet::storage]
+	#[pallet::getter(fn gate_requirement)]
+	pub type GateRequirements<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, GateRequirementOf<T>, OptionQuery>;
+
+	/// Store the members of a community along with their membership details
+	#[pallet::storage]
+	#[pallet::getter(fn community_members)]
+	pub type CommunityMembers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		MemberInfo<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Store the role a member holds within a community
+	#[pallet::storage]
+	#[pallet::getter(fn community_roles)]
+	pub type CommunityRoles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		Role<T::NameLimit>,
+		OptionQuery,
+	>;
+
+	/// Store the number of members of a community
+	#[pallet::storage]
+	#[pallet::getter(fn member_count)]
+	pub type MemberCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, u32, ValueQuery>;
+
+	/// Store the pending join requests of a community
+	#[pallet::storage]
+	#[pallet::getter(fn join_requests)]
+	pub type JoinRequests<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		JoinRequestOf<T>,
+		OptionQuery,
+	>;
+
+	/// Store the join requests which expire at a given block
+	#[pallet::storage]
+	#[pallet::getter(fn join_request_expiry)]
+	pub type JoinRequestExpiry<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::CommunityId, T::AccountId), T::MaxJoinRequestsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Store the pending invitations of a community
+	#[pallet::storage]
+	#[pallet::getter(fn invitations)]
+	pub type Invitations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		InviteeOf<T>,
+		Invitation<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Store the invitations which expire at a given block
+	#[pallet::storage]
+	#[pallet::getter(fn invitation_expiry)]
+	pub type InvitationExpiry<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::CommunityId, InviteeOf<T>), T::MaxInvitationsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Store the accounts banned from a community
+	#[pallet::storage]
+	#[pallet::getter(fn bans)]
+	pub type Bans<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		Ban<BlockNumberFor<T>, T::Hash>,
+		OptionQuery,
+	>;
+
+	/// Store the citizens of a State and the block they were granted the citizenship
+	#[pallet::storage]
+	#[pallet::getter(fn citizens)]
+	pub type Citizens<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Store the pending citizenship applications and the block they were submitted
+	#[pallet::storage]
+	#[pallet::getter(fn citizenship_applications)]
+	pub type CitizenshipApplications<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Resolve the public reference id of a community
+	#[pallet::storage]
+	#[pallet::getter(fn community_by_reference_id)]
+	pub type ReferenceIdToCommunity<T: Config> =
+		StorageMap<_, Blake2_128Concat, [u8; 16], T::CommunityId, OptionQuery>;
+
+	/// Store the parent of a sub-community
+	#[pallet::storage]
+	#[pallet::getter(fn parent_of)]
+	pub type Parents<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, ParentLink<T::CommunityId>, OptionQuery>;
+
+	/// Store the direct sub-communities of a community
+	#[pallet::storage]
+	#[pallet::getter(fn sub_communities)]
+	pub type SubCommunities<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::CommunityId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Store the number of direct and indirect sub-communities of a community
+	#[pallet::storage]
+	#[pallet::getter(fn descendant_count)]
+	pub type DescendantCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, u32, ValueQuery>;
+
+	/// Store the pending requests of communities to be linked to a parent
+	#[pallet::storage]
+	#[pallet::getter(fn parent_requests)]
+	pub type ParentRequests<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, ParentLink<T::CommunityId>, OptionQuery>;
+
+	/// Store the membership dues charged by a community
+	#[pallet::storage]
+	#[pallet::getter(fn membership_dues)]
+	pub type CommunityDues<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, MembershipDuesOf<T>, OptionQuery>;
+
+	/// Store the block until which a member paid the recurring due of a community
+	#[pallet::storage]
+	#[pallet::getter(fn dues_paid_until)]
+	pub type DuesPaidUntil<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
 	/// The communities owned by a given account
 	#[pallet::storage]
 	#[pallet::getter(fn community_account)]
@@ -165,6 +487,44 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Store the account a community is about to be transferred to
+	#[pallet::storage]
+	#[pallet::getter(fn pending_founder_transfer)]
+	pub type PendingFounderTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, T::AccountId, OptionQuery>;
+
+	/// Store the balance reserved from the founder of a community
+	#[pallet::storage]
+	#[pallet::getter(fn founder_bond)]
+	pub type FounderBonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, T::Balance, OptionQuery>;
+
+	/// Store the balance reserved from the founder, or the treasury, for the metadata of a
+	/// community
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_deposit)]
+	pub type MetadataDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, T::Balance, OptionQuery>;
+
+	/// Store the communities whose metadata deposit is reserved from their treasury instead of
+	/// the founder, after the metadata got updated by the community itself
+	#[pallet::storage]
+	pub type TreasuryMetadataDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, (), OptionQuery>;
+
+	/// Store the dissolved communities whose state is still being cleaned up
+	#[pallet::storage]
+	#[pallet::getter(fn dissolved_communities)]
+	pub type DissolvedCommunities<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Store the founder of a dissolved community, who can claim the assets left in its
+	/// treasury
+	#[pallet::storage]
+	#[pallet::getter(fn treasury_claimant)]
+	pub type TreasuryClaimants<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, T::AccountId, OptionQuery>;
+
 	/// Stores the `CommunityId` that is going to be used for the next
 	/// community. This gets incremented whenever a new community is created.
 	#[pallet::storage]
@@ -182,8 +542,6 @@ pub mod pallet {
 		CreatedCommunity(T::CommunityId, [u8; 16], T::AccountId),
 		/// Updated Community [community]
 		UpdatedCommunity(T::CommunityId),
-		/// Updated Community [community]
-		AddedMembers(T::CommunityId),
 		/// Updated Community Metadata [community]
 		UpdatedMetadata(T::CommunityId),
 		/// Joined Community [community]
@@ -196,6 +554,78 @@ pub mod pallet {
 		UpdatedTagAndColors(T::CommunityId),
 		/// Updated Required Founder Balance [balance]
 		UpdatedRequiredFounderBalance(T::Balance),
+		/// Requested to join a community [community, account]
+		JoinRequested(T::CommunityId, T::AccountId),
+		/// Approved a join request [community, account]
+		JoinRequestApproved(T::CommunityId, T::AccountId),
+		/// Rejected a join request [community, account]
+		JoinRequestRejected(T::CommunityId, T::AccountId),
+		/// Join request expired without being handled [community, account]
+		JoinRequestExpired(T::CommunityId, T::AccountId),
+		/// Updated the membership gate [community]
+		UpdatedMembershipGate(T::CommunityId),
+		/// Revoked the membership of an account which no longer holds the token [community, account]
+		RevokedMembership(T::CommunityId, T::AccountId),
+		/// Granted a role to a member [community, account]
+		GrantedRole(T::CommunityId, T::AccountId),
+		/// Revoked the role of a member [community, account]
+		RevokedRole(T::CommunityId, T::AccountId),
+		/// Proposed a new founder for the community [community, new founder]
+		FounderTransferProposed(T::CommunityId, T::AccountId),
+		/// Transferred the community to a new founder [community, old founder, new founder]
+		FounderTransferred(T::CommunityId, T::AccountId, T::AccountId),
+		/// Dissolved Community [community]
+		DissolvedCommunity(T::CommunityId),
+		/// Removed all the state of a dissolved community [community]
+		CleanedUpCommunity(T::CommunityId),
+		/// Slashed the founder bond of a community [community, amount]
+		SlashedFounderBond(T::CommunityId, T::Balance),
+		/// Spent funds from the community treasury [community, beneficiary, amount]
+		TreasurySpent(T::CommunityId, T::AccountId, T::Balance),
+		/// Spent assets from the community treasury [community, asset, beneficiary, amount]
+		TreasuryAssetSpent(T::CommunityId, AssetIdOf<T>, T::AccountId, AssetBalanceOf<T>),
+		/// Invited an account to join the community [community, account]
+		InvitedMember(T::CommunityId, T::AccountId),
+		/// Created an invite code for the community [community, code hash]
+		CreatedInviteCode(T::CommunityId, T::Hash),
+		/// Accepted an invitation [community, account]
+		AcceptedInvitation(T::CommunityId, T::AccountId),
+		/// Declined an invitation [community, account]
+		DeclinedInvitation(T::CommunityId, T::AccountId),
+		/// Redeemed an invite code [community, account]
+		RedeemedInviteCode(T::CommunityId, T::AccountId),
+		/// Revoked an invitation [community, invitee]
+		RevokedInvitation(T::CommunityId, InviteeOf<T>),
+		/// Invitation expired without being accepted [community, invitee]
+		InvitationExpired(T::CommunityId, InviteeOf<T>),
+		/// Banned an account from the community [community, account]
+		BannedMember(T::CommunityId, T::AccountId),
+		/// Lifted the ban of an account [community, account]
+		UnbannedMember(T::CommunityId, T::AccountId),
+		/// Applied for the citizenship of a State [community, account]
+		CitizenshipApplied(T::CommunityId, T::AccountId),
+		/// Granted the citizenship of a State [community, account]
+		CitizenshipGranted(T::CommunityId, T::AccountId),
+		/// Rejected a citizenship application [community, account]
+		CitizenshipRejected(T::CommunityId, T::AccountId),
+		/// Revoked the citizenship of an account [community, account]
+		CitizenshipRevoked(T::CommunityId, T::AccountId),
+		/// Amended the constitution of a State [community]
+		AmendedConstitution(T::CommunityId),
+		/// Requested to become a sub-community [sub-community, parent]
+		ParentRequested(T::CommunityId, T::CommunityId),
+		/// Added a sub-community [parent, sub-community]
+		SubCommunityAdded(T::CommunityId, T::CommunityId),
+		/// Rejected the request of a sub-community [parent, sub-community]
+		SubCommunityRejected(T::CommunityId, T::CommunityId),
+		/// Detached a sub-community from its parent [parent, sub-community]
+		SubCommunityDetached(T::CommunityId, T::CommunityId),
+		/// Updated the membership dues [community]
+		UpdatedMembershipDues(T::CommunityId),
+		/// Paid the joining fee of a community [community, account]
+		JoiningFeePaid(T::CommunityId, T::AccountId),
+		/// Paid the recurring due of a community [community, account, paid until]
+		DuesPaid(T::CommunityId, T::AccountId, BlockNumberFor<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -230,10 +660,138 @@ pub mod pallet {
 		CommunityAlreadyExist,
 		/// Insufficient balance to become a founder
 		InsufficientBalanceToBecomeFounder,
+		/// Community can only be joined through a join request
+		JoinRequestRequired,
+		/// Join request already exist
+		JoinRequestAlreadyExist,
+		/// Join request does not exist
+		JoinRequestNotExist,
+		/// Too many join requests expire in the same block
+		TooManyJoinRequests,
+		/// The joining fee exceeds the one the join request agreed to
+		JoiningFeeIncreased,
+		/// Account does not hold the token required by the community
+		NotEligible,
+		/// Community is not a `NFTGated` community
+		NotGatedCommunity,
+		/// Member still holds the token required by the community
+		StillEligible,
+		/// Member does not hold a role
+		RoleNotExist,
+		/// Account is already the founder of the community
+		AlreadyFounder,
+		/// Founder transfer does not exist
+		FounderTransferNotExist,
+		/// Founder bond does not exist
+		FounderBondNotExist,
+		/// Invitation already exist
+		InvitationAlreadyExist,
+		/// Invitation does not exist
+		InvitationNotExist,
+		/// Too many invitations expire in the same block
+		TooManyInvitations,
+		/// Too many members or initial citizens are listed
+		TooManyMembers,
+		/// Account is banned from the community
+		Banned,
+		/// Account is not banned from the community
+		NotBanned,
+		/// The ban would already be expired
+		InvalidBanExpiry,
+		/// Community is not a State
+		NotStateCommunity,
+		/// Account is already a citizen of the State
+		AlreadyCitizen,
+		/// Account is not a citizen of the State
+		NotCitizen,
+		/// Citizenship application already exist
+		CitizenshipApplicationAlreadyExist,
+		/// Citizenship application does not exist
+		CitizenshipApplicationNotExist,
+		/// Constitution article does not exist
+		ArticleNotExist,
+		/// The constitution holds the maximum number of articles
+		TooManyArticles,
+		/// Community is already linked to a parent
+		AlreadySubCommunity,
+		/// Community is not a sub-community
+		NotSubCommunity,
+		/// Request to be linked to the parent does not exist
+		ParentRequestNotExist,
+		/// The parent would create a cycle in the hierarchy
+		InvalidParent,
+		/// The hierarchy would exceed `MaxHierarchyDepth`
+		HierarchyTooDeep,
+		/// The hierarchy would exceed `MaxDescendants`
+		TooManySubCommunities,
+		/// No unique reference id could be derived for the community
+		ReferenceIdCollision,
+		/// Community does not charge a recurring due
+		DuesNotExist,
+		/// The dues period or the number of paid periods is zero
+		InvalidDuesPeriod,
+		/// Insufficient balance to reserve the metadata deposit
+		InsufficientBalanceForDeposit,
+		/// The treasury holds none of the asset
+		TreasuryEmpty,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+			let expired_requests = JoinRequestExpiry::<T>::take(block_number);
+			let expired_count = expired_requests.len() as u64;
+
+			for (community_id, who) in expired_requests {
+				JoinRequests::<T>::remove(community_id, &who);
+				Self::deposit_event(Event::JoinRequestExpired(community_id, who));
+			}
+
+			let expired_invitations = InvitationExpiry::<T>::take(block_number);
+			let expired_count = expired_count.saturating_add(expired_invitations.len() as u64);
+
+			for (community_id, invitee) in expired_invitations {
+				Invitations::<T>::remove(community_id, &invitee);
+				Self::deposit_event(Event::InvitationExpired(community_id, invitee));
+			}
+
+			T::DbWeight::get().reads_writes(2, expired_count + 2)
+		}
+
+		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let base_weight = <T as pallet::Config>::WeightInfo::clean_up_community(0)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			let item_weight = <T as pallet::Config>::WeightInfo::clean_up_community(1)
+				.saturating_sub(<T as pallet::Config>::WeightInfo::clean_up_community(0));
+
+			if remaining_weight.any_lt(base_weight.saturating_add(item_weight)) {
+				return Weight::zero();
+			}
+
+			let community_id = match DissolvedCommunities::<T>::iter_keys().next() {
+				Some(community_id) => community_id,
+				None => return T::DbWeight::get().reads(1),
+			};
+
+			let limit = remaining_weight
+				.saturating_sub(base_weight)
+				.ref_time()
+				.checked_div(item_weight.ref_time())
+				.unwrap_or(u64::MAX)
+				.min(T::DissolveCleanupLimit::get() as u64) as u32;
+
+			let removed = Self::do_clean_up_community(community_id, limit);
+
+			if removed < limit {
+				DissolvedCommunities::<T>::remove(community_id);
+				Self::deposit_event(Event::CleanedUpCommunity(community_id));
+			}
+
+			base_weight
+				.saturating_add(item_weight.saturating_mul(removed.min(limit) as u64))
+				.min(remaining_weight)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -245,15 +803,19 @@ pub mod pallet {
 		/// - `logo`: This is an image file (also a GIF is valid) that is uploaded on IPFS.
 		/// - `name`: Name of the community
 		/// - `description`: Information about community
-		/// - `members`: as a Founder I should be able to add members to the society by adding
-		/// 				their wallet addresses. Tt’s not required to add members immediately
-		/// 			at society’s creation
+		/// - `members`: Accounts invited to join the community, they become members once they
+		/// 			accept the invitation. It’s not required to invite members immediately
+		/// 			at society’s creation. At most `MaxInvitationsPerBlock` accounts can be listed
 		/// - `metadata`: Other customizable fields like community_type, custom, language, norms etc.
+		/// - `community_type`: The initial citizens of a State are limited the same way as
+		/// 			`members`.
 		///
 		/// Emits `CreatedCommunity` event when successful.
 		///
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_community())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_community(
+			Pallet::<T>::initial_member_count(members, community_type)
+		))]
 		pub fn create_community(
 			origin: OriginFor<T>,
 			logo: Option<Vec<u8>>,
@@ -265,18 +827,18 @@ pub mod pallet {
 			tagline: Option<Vec<u8>>,
 			primary_color: Option<Vec<u8>>,
 			secondary_color: Option<Vec<u8>>,
-			community_type: Option<CommunityType<T::AccountId>>,
+			community_type: Option<CommunityTypeOf<T>>,
 		) -> DispatchResult {
 			let community_id =
 				NextCommunityId::<T>::get().unwrap_or(T::CommunityId::initial_value());
 
 			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
 
-			let balance = pallet_balances::Pallet::<T>::free_balance(&founder);
-
-			let required_balance = RequiredFounderBalance::<T>::get();
-
-			ensure!(balance >= required_balance, Error::<T>::InsufficientBalanceToBecomeFounder);
+			Self::do_reserve_founder_bond(
+				community_id,
+				&founder,
+				RequiredFounderBalance::<T>::get(),
+			)?;
 
 			Self::do_create_community(
 				community_id,
@@ -296,7 +858,8 @@ pub mod pallet {
 
 		/// Update a particular community from a privileged origin.
 		///
-		/// The origin must conform to `CreateOrigin`.
+		/// The origin must conform to `CreateOrigin` or be the community itself, in which case
+		/// the metadata deposit is reserved from the treasury of the community.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community to be updated.
@@ -313,7 +876,8 @@ pub mod pallet {
 			logo: Option<Vec<u8>>,
 			description: Option<Vec<u8>>,
 		) -> DispatchResult {
-			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+			let depositor = Self::metadata_depositor(&origin, community_id);
+			let founder = Self::ensure_community_origin(origin, community_id)?;
 
 			let bounded_description: BoundedVec<u8, T::DescriptionLimit> =
 				if let Some(desc) = description {
@@ -338,6 +902,12 @@ pub mod pallet {
 				community.logo = bounded_logo;
 				community.description = bounded_description;
 
+				Self::do_update_metadata_deposit(
+					community_id,
+					depositor.as_ref().unwrap_or(&founder),
+					community,
+				)?;
+
 				Self::deposit_event(Event::UpdatedCommunity(community_id));
 
 				Ok(())
@@ -346,7 +916,8 @@ pub mod pallet {
 
 		/// Update a particular community metadata from a privileged origin.
 		///
-		/// The origin must conform to `CreateOrigin`.
+		/// The origin must conform to `CreateOrigin` or be the community itself, in which case
+		/// the metadata deposit is reserved from the treasury of the community.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community to be updated.
@@ -361,7 +932,8 @@ pub mod pallet {
 			community_id: T::CommunityId,
 			metadata: CommunityMetaDataFor<T>,
 		) -> DispatchResult {
-			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+			let depositor = Self::metadata_depositor(&origin, community_id);
+			let founder = Self::ensure_community_origin(origin, community_id)?;
 
 			Communities::<T>::try_mutate(community_id, |maybe_community| {
 				let community = maybe_community
@@ -372,47 +944,13 @@ pub mod pallet {
 
 				community.metadata = Option::from(metadata);
 
-				Self::deposit_event(Event::UpdatedMetadata(community_id));
-
-				Ok(())
-			})
-		}
-
-		/// Update members of a particular community from a privileged origin.
-		///
-		/// The origin must conform to `CreateOrigin`.
-		///
-		/// Parameters:
-		/// - `community_id`: Id of the community to be updated
-		/// - `members`: Members of teh community
-		///
-		/// Emits `UpdatedCommunity` event when successful.
-		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_members())]
-		pub fn accept_members(
-			origin: OriginFor<T>,
-			community_id: T::CommunityId,
-			members: Vec<T::AccountId>,
-		) -> DispatchResult {
-			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
-
-			Communities::<T>::try_mutate(community_id, |maybe_community| {
-				let community = maybe_community
-					.as_mut()
-					.ok_or(Error::<T>::CommunityNotExist)?;
-				ensure!(founder == community.founder, Error::<T>::NoPermission);
-
-				let mut community_members = community.members.clone();
-
-				for new_members in members.clone() {
-					// checking if member is already present in the community.
-					if !community_members.contains(&new_members) {
-						community_members.push(new_members.clone());
-					}
-				}
-				community.members = community_members;
+				Self::do_update_metadata_deposit(
+					community_id,
+					depositor.as_ref().unwrap_or(&founder),
+					community,
+				)?;
 
-				Self::deposit_event(Event::AddedMembers(community_id));
+				Self::deposit_event(Event::UpdatedMetadata(community_id));
 
 				Ok(())
 			})
@@ -434,25 +972,35 @@ pub mod pallet {
 		) -> DispatchResult {
 			let member = T::CreateOrigin::ensure_origin(origin, &community_id)?;
 
-			Communities::<T>::try_mutate(community_id, |maybe_community| {
-				let community = maybe_community
-					.as_mut()
-					.ok_or(Error::<T>::CommunityNotExist)?;
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
 
-				let mut community_members = community.members.clone();
+			ensure!(!Self::is_banned(community_id, &member), Error::<T>::Banned);
 
-				ensure!(community.founder != member, Error::<T>::AlreadyMember);
+			if community.category != Category::Public {
+				// Gated communities without a requirement are only joined by a request.
+				let requirement = GateRequirements::<T>::get(community_id)
+					.ok_or(Error::<T>::JoinRequestRequired)?;
 
-				ensure!(!community_members.contains(&member), Error::<T>::AlreadyMember);
+				ensure!(
+					T::MembershipGate::is_eligible(&requirement, &member),
+					Error::<T>::NotEligible
+				);
+			}
 
-				community_members.push(member.clone());
+			ensure!(community.founder != member, Error::<T>::AlreadyMember);
 
-				community.members = community_members;
+			ensure!(
+				!CommunityMembers::<T>::contains_key(community_id, &member),
+				Error::<T>::AlreadyMember
+			);
 
-				Self::deposit_event(Event::JoinedCommunity(community_id));
+			Self::do_pay_joining_fee(community_id, &member)?;
+			Self::do_add_member(community_id, &member);
 
-				Ok(())
-			})
+			Self::deposit_event(Event::JoinedCommunity(community_id));
+
+			Ok(())
 		}
 
 		/// Leave any particular private/public community.
@@ -471,28 +1019,18 @@ pub mod pallet {
 		) -> DispatchResult {
 			let member = T::CreateOrigin::ensure_origin(origin, &community_id)?;
 
-			Communities::<T>::try_mutate(community_id, |maybe_community| {
-				let community = maybe_community
-					.as_mut()
-					.ok_or(Error::<T>::CommunityNotExist)?;
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
 
-				let mut community_members = community.members.clone();
+			ensure!(
+				CommunityMembers::<T>::contains_key(community_id, &member),
+				Error::<T>::NotMember
+			);
 
-				ensure!(community_members.contains(&member), Error::<T>::NotMember);
+			Self::do_remove_member(community_id, &member);
 
-				let index = community_members
-					.iter()
-					.position(|value| *value == member.clone())
-					.expect("Member not found.");
+			Self::deposit_event(Event::LeavedCommunity(community_id));
 
-				community_members.remove(index);
-
-				community.members = community_members;
-
-				Self::deposit_event(Event::LeavedCommunity(community_id));
-
-				Ok(())
-			})
+			Ok(())
 		}
 
 		/// Remove member from private community.
@@ -511,40 +1049,33 @@ pub mod pallet {
 			member: T::AccountId,
 			community_id: T::CommunityId,
 		) -> DispatchResult {
-			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
-
-			Communities::<T>::try_mutate(community_id, |maybe_community| {
-				let community = maybe_community
-					.as_mut()
-					.ok_or(Error::<T>::CommunityNotExist)?;
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
 
-				// TODO update below check to restrict this extrinsic for private communities
-				// ensure!(community.type == "Private", Error::<T>::NoPermission);
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
 
-				ensure!(founder == community.founder, Error::<T>::NoPermission);
-
-				let mut community_members = community.members.clone();
+			// TODO update below check to restrict this extrinsic for private communities
+			// ensure!(community.type == "Private", Error::<T>::NoPermission);
 
-				ensure!(community_members.contains(&member), Error::<T>::NotMember);
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
 
-				let index = community_members
-					.iter()
-					.position(|value| *value == member.clone())
-					.expect("Member not found.");
+			ensure!(
+				CommunityMembers::<T>::contains_key(community_id, &member),
+				Error::<T>::NotMember
+			);
 
-				community_members.remove(index);
+			Self::do_remove_member(community_id, &member);
 
-				community.members = community_members;
+			Self::deposit_event(Event::RemovedMember(member));
 
-				Self::deposit_event(Event::RemovedMember(member));
-
-				Ok(())
-			})
+			Ok(())
 		}
 
 		/// Update tag and colors of a particular community from a privileged origin.
 		///
-		/// The origin must conform to `CreateOrigin`.
+		/// The origin must conform to `CreateOrigin` and hold the `MANAGE_SETTINGS` permission.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community to be updated.
@@ -562,7 +1093,11 @@ pub mod pallet {
 			primary_color: Option<Vec<u8>>,
 			secondary_color: Option<Vec<u8>>,
 		) -> DispatchResult {
-			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_SETTINGS),
+				Error::<T>::NoPermission
+			);
 
 			let bounded_tag: BoundedVec<u8, T::TagLimit> = if let Some(t) = tagline {
 				t.try_into().map_err(|_| Error::<T>::BadTag)?
@@ -589,8 +1124,6 @@ pub mod pallet {
 					.as_mut()
 					.ok_or(Error::<T>::CommunityNotExist)?;
 
-				ensure!(founder == community.founder, Error::<T>::NoPermission);
-
 				community.tag = bounded_tag;
 				community.primary_color = bounded_primary_color;
 				community.secondary_color = bounded_secondary_color;
@@ -623,126 +1156,2238 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
-}
-
-impl<T: Config> Pallet<T>
-where
-	Error<T>: PartialEq,
-{
-	pub fn do_create_community(
-		community_id: T::CommunityId,
-		founder: T::AccountId,
-		maybe_logo: Option<Vec<u8>>,
-		name: Vec<u8>,
-		maybe_description: Option<Vec<u8>>,
-		maybe_members: Option<Vec<T::AccountId>>,
-		metadata: Option<CommunityMetaDataFor<T>>,
-		category: Category,
-		maybe_tag: Option<Vec<u8>>,
-		maybe_primary_color: Option<Vec<u8>>,
-		maybe_secondary_color: Option<Vec<u8>>,
-		community_type: Option<CommunityType<T::AccountId>>,
-	) -> DispatchResult {
-		let bounded_name: BoundedVec<u8, T::NameLimit> =
-			name.clone().try_into().map_err(|_| Error::<T>::BadName)?;
 
-		ensure!(
-			Communities::<T>::iter_values()
-				.into_iter()
-				.find(|community| {
-					let name: String = String::from_utf8(name.clone())
-						.unwrap_or(Default::default())
-						.split_whitespace()
-						.collect();
-					let original_name: String = String::from_utf8(community.name.to_vec())
-						.unwrap_or(Default::default())
-						.split_whitespace()
-						.collect();
-					name.to_lowercase() == original_name.to_lowercase()
-				})
-				.is_none(),
+		/// Request to join a non-public community.
+		///
+		/// The origin must conform to `CreateOrigin`.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community to join.
+		/// - `message`: Hash of an optional message for the founder.
+		///
+		/// Emits `JoinRequested` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::request_to_join())]
+		pub fn request_to_join(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			message: Option<T::Hash>,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			ensure!(
+				community.category != Category::Public,
+				Error::<T>::NotAllowedForPublicCommunity
+			);
+
+			ensure!(community.founder != who, Error::<T>::AlreadyMember);
+
+			ensure!(
+				!CommunityMembers::<T>::contains_key(community_id, &who),
+				Error::<T>::AlreadyMember
+			);
+
+			ensure!(!Self::is_banned(community_id, &who), Error::<T>::Banned);
+
+			ensure!(
+				!JoinRequests::<T>::contains_key(community_id, &who),
+				Error::<T>::JoinRequestAlreadyExist
+			);
+
+			let requested_at = frame_system::Pallet::<T>::block_number();
+			let expires_at = requested_at.saturating_add(T::JoinRequestTtl::get());
+
+			JoinRequestExpiry::<T>::try_mutate(expires_at, |requests| {
+				requests
+					.try_push((community_id, who.clone()))
+					.map_err(|_| Error::<T>::TooManyJoinRequests)
+			})?;
+
+			let joining_fee =
+				CommunityDues::<T>::get(community_id).and_then(|dues| dues.joining_fee);
+
+			JoinRequests::<T>::insert(
+				community_id,
+				&who,
+				JoinRequest { message, joining_fee, requested_at, expires_at },
+			);
+
+			Self::deposit_event(Event::JoinRequested(community_id, who));
+
+			Ok(())
+		}
+
+		/// Approve a pending join request.
+		///
+		/// The origin must conform to `CreateOrigin` and be allowed to manage the members.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `who`: Account which requested to join the community.
+		///
+		/// Emits `JoinRequestApproved` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_join_request())]
+		pub fn approve_join_request(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let approver = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+
+			ensure!(
+				Self::has_permission(community_id, &approver, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
+
+			if let Some(requirement) = GateRequirements::<T>::get(community_id) {
+				ensure!(
+					T::MembershipGate::is_eligible(&requirement, &who),
+					Error::<T>::NotEligible
+				);
+			}
+
+			let request = Self::do_remove_join_request(community_id, &who)?;
+
+			if !CommunityMembers::<T>::contains_key(community_id, &who) {
+				// The requester only agreed to the joining fee at the time of the request.
+				ensure!(
+					Self::is_within_fee(
+						CommunityDues::<T>::get(community_id).and_then(|dues| dues.joining_fee),
+						request.joining_fee
+					),
+					Error::<T>::JoiningFeeIncreased
+				);
+
+				Self::do_pay_joining_fee(community_id, &who)?;
+				Self::do_add_member(community_id, &who);
+			}
+
+			Self::deposit_event(Event::JoinRequestApproved(community_id, who));
+
+			Ok(())
+		}
+
+		/// Reject a pending join request.
+		///
+		/// The origin must conform to `CreateOrigin` and be allowed to manage the members.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `who`: Account which requested to join the community.
+		///
+		/// Emits `JoinRequestRejected` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_join_request())]
+		pub fn reject_join_request(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let rejecter = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+
+			ensure!(
+				Self::has_permission(community_id, &rejecter, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_remove_join_request(community_id, &who)?;
+
+			Self::deposit_event(Event::JoinRequestRejected(community_id, who));
+
+			Ok(())
+		}
+
+		/// Set the token requirement of a `NFTGated` community.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `MANAGE_SETTINGS` permission.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `requirement`: Token the members have to hold, `None` removes the requirement.
+		///
+		/// Emits `UpdatedMembershipGate` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_membership_gate())]
+		pub fn set_membership_gate(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			requirement: Option<GateRequirementOf<T>>,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_SETTINGS),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(community.category == Category::NFTGated, Error::<T>::NotGatedCommunity);
+
+			GateRequirements::<T>::set(community_id, requirement);
+
+			Self::deposit_event(Event::UpdatedMembershipGate(community_id));
+
+			Ok(())
+		}
+
+		/// Revoke the membership of an account which no longer holds the required token.
+		///
+		/// The origin must be Signed, anyone can revalidate a member.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `member`: Account whose membership is revalidated.
+		///
+		/// Emits `RevokedMembership` event when successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revalidate_membership())]
+		pub fn revalidate_membership(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			member: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				CommunityMembers::<T>::contains_key(community_id, &member),
+				Error::<T>::NotMember
+			);
+
+			let requirement =
+				GateRequirements::<T>::get(community_id).ok_or(Error::<T>::NotGatedCommunity)?;
+
+			ensure!(
+				!T::MembershipGate::is_eligible(&requirement, &member),
+				Error::<T>::StillEligible
+			);
+
+			Self::do_remove_member(community_id, &member);
+
+			Self::deposit_event(Event::RevokedMembership(community_id, member));
+
+			Ok(())
+		}
+
+		/// Grant a named role to a member of the community.
+		///
+		/// The origin must conform to `CreateOrigin` and be the founder of the community.
+		/// A member holds at most one role, granting a new one replaces the previous role.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `who`: Member receiving the role.
+		/// - `name`: Name of the role, e.g. moderator or treasurer.
+		/// - `permissions`: Actions the role allows its holder to perform.
+		///
+		/// Emits `GrantedRole` event when successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+			name: Vec<u8>,
+			permissions: Permissions,
+		) -> DispatchResult {
+			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			ensure!(founder == community.founder, Error::<T>::NoPermission);
+
+			ensure!(CommunityMembers::<T>::contains_key(community_id, &who), Error::<T>::NotMember);
+
+			let name: BoundedVec<u8, T::NameLimit> =
+				name.try_into().map_err(|_| Error::<T>::BadName)?;
+
+			CommunityRoles::<T>::insert(community_id, &who, Role { name, permissions });
+
+			Self::deposit_event(Event::GrantedRole(community_id, who));
+
+			Ok(())
+		}
+
+		/// Revoke the role of a member of the community.
+		///
+		/// The origin must conform to `CreateOrigin` and be the founder of the community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `who`: Member whose role is revoked.
+		///
+		/// Emits `RevokedRole` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			ensure!(founder == community.founder, Error::<T>::NoPermission);
+
+			ensure!(
+				CommunityRoles::<T>::take(community_id, &who).is_some(),
+				Error::<T>::RoleNotExist
+			);
+
+			Self::deposit_event(Event::RevokedRole(community_id, who));
+
+			Ok(())
+		}
+
+		/// Propose a new founder for the community.
+		///
+		/// The origin must conform to `CreateOrigin` and be the founder of the community.
+		/// The transfer only takes effect once the new founder accepts it, a new proposal
+		/// replaces the pending one.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `new_founder`: Account which should become the founder.
+		///
+		/// Emits `FounderTransferProposed` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_founder_transfer())]
+		pub fn propose_founder_transfer(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			new_founder: T::AccountId,
+		) -> DispatchResult {
+			let founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			ensure!(founder == community.founder, Error::<T>::NoPermission);
+
+			ensure!(new_founder != community.founder, Error::<T>::AlreadyFounder);
+
+			PendingFounderTransfers::<T>::insert(community_id, &new_founder);
+
+			Self::deposit_event(Event::FounderTransferProposed(community_id, new_founder));
+
+			Ok(())
+		}
+
+		/// Accept a pending founder transfer and become the founder of the community.
+		///
+		/// The origin must conform to `CreateOrigin` and be the proposed founder. The new
+		/// founder must hold the required founder balance and stay within the community limit.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		///
+		/// Emits `FounderTransferred` event when successful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_founder_transfer())]
+		pub fn accept_founder_transfer(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+		) -> DispatchResult {
+			let new_founder = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let pending_founder = PendingFounderTransfers::<T>::get(community_id)
+				.ok_or(Error::<T>::FounderTransferNotExist)?;
+
+			ensure!(pending_founder == new_founder, Error::<T>::NoPermission);
+
+			let old_founder = Communities::<T>::try_mutate(
+				community_id,
+				|maybe_community| -> Result<T::AccountId, DispatchError> {
+					let community = maybe_community
+						.as_mut()
+						.ok_or(Error::<T>::CommunityNotExist)?;
+					Ok(sp_std::mem::replace(&mut community.founder, new_founder.clone()))
+				},
+			)?;
+
+			CommunityAccount::<T>::try_mutate(&new_founder, |communities| -> DispatchResult {
+				communities
+					.try_push(community_id)
+					.map_err(|_| Error::<T>::TooManyCommunities)?;
+				Ok(())
+			})?;
+
+			CommunityAccount::<T>::mutate(&old_founder, |communities| {
+				communities.retain(|id| *id != community_id);
+			});
+
+			// The bond is handed over, the old founder gets back what was reserved.
+			Self::do_release_founder_bond(community_id, &old_founder);
+			Self::do_reserve_founder_bond(
+				community_id,
+				&new_founder,
+				RequiredFounderBalance::<T>::get(),
+			)?;
+			Self::do_transfer_metadata_deposit(community_id, &old_founder, &new_founder)?;
+
+			PendingFounderTransfers::<T>::remove(community_id);
+
+			// The founder holds every permission, a delegated role is no longer needed.
+			CommunityRoles::<T>::remove(community_id, &new_founder);
+
+			if !CommunityMembers::<T>::contains_key(community_id, &new_founder) {
+				Self::do_add_member(community_id, &new_founder);
+			}
+
+			Self::deposit_event(Event::FounderTransferred(community_id, old_founder, new_founder));
+
+			Ok(())
+		}
+
+		/// Dissolve a community.
+		///
+		/// The origin must be Root or conform to `CreateOrigin` and be the founder of the
+		/// community. The community can not be used anymore once dissolved, the state kept
+		/// for it here and in the dependent pallets is removed lazily in `on_idle`. The JUR of
+		/// the treasury are transferred to the founder, who can claim its assets afterwards.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		///
+		/// Emits `DissolvedCommunity` event when successful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::dissolve_community())]
+		pub fn dissolve_community(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+		) -> DispatchResult {
+			let maybe_founder = match ensure_root(origin.clone()) {
+				Ok(()) => None,
+				Err(_) => Some(T::CreateOrigin::ensure_origin(origin, &community_id)?),
+			};
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			if let Some(founder) = maybe_founder {
+				ensure!(founder == community.founder, Error::<T>::NoPermission);
+			}
+
+			Self::do_dissolve_community(community_id, community);
+
+			Self::deposit_event(Event::DissolvedCommunity(community_id));
+
+			Ok(())
+		}
+
+		/// Slash the founder bond of an abusive community.
+		///
+		/// The origin must conform to `SlashOrigin`. The slashed amount is handed to `OnSlash`.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		///
+		/// Emits `SlashedFounderBond` event when successful.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::slash_founder_bond())]
+		pub fn slash_founder_bond(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			let bond =
+				FounderBonds::<T>::take(community_id).ok_or(Error::<T>::FounderBondNotExist)?;
+
+			let (imbalance, _) =
+				pallet_balances::Pallet::<T>::slash_reserved(&community.founder, bond);
+			T::OnSlash::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::SlashedFounderBond(community_id, bond));
+
+			Ok(())
+		}
+
+		/// Spend JUR from the treasury of a community.
+		///
+		/// The origin must be the founder, hold the `MANAGE_TREASURY` permission or be the
+		/// community itself.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `beneficiary`: Account receiving the funds.
+		/// - `amount`: Amount of JUR to transfer.
+		///
+		/// Emits `TreasurySpent` event when successful.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::spend())]
+		pub fn spend(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			beneficiary: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = Self::ensure_community_origin(origin, community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_TREASURY),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_spend(community_id, &beneficiary, amount)
+		}
+
+		/// Spend `pallet_assets` tokens from the treasury of a community.
+		///
+		/// The origin must be the founder, hold the `MANAGE_TREASURY` permission or be the
+		/// community itself.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `asset_id`: Id of the asset.
+		/// - `beneficiary`: Account receiving the assets.
+		/// - `amount`: Amount of the asset to transfer.
+		///
+		/// Emits `TreasuryAssetSpent` event when successful.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::spend_asset())]
+		pub fn spend_asset(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			asset_id: AssetIdOf<T>,
+			beneficiary: T::AccountId,
+			amount: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			let who = Self::ensure_community_origin(origin, community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_TREASURY),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_spend_asset(community_id, asset_id, &beneficiary, amount)
+		}
+
+		/// Invite an account to join the community.
+		///
+		/// The origin must conform to `CreateOrigin` and be allowed to manage the members.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `who`: Account invited to join the community.
+		///
+		/// Emits `InvitedMember` event when successful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::invite_member())]
+		pub fn invite_member(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let inviter = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			ensure!(
+				Self::has_permission(community_id, &inviter, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(community.founder != who, Error::<T>::AlreadyMember);
+
+			ensure!(
+				!CommunityMembers::<T>::contains_key(community_id, &who),
+				Error::<T>::AlreadyMember
+			);
+
+			ensure!(!Self::is_banned(community_id, &who), Error::<T>::Banned);
+
+			Self::do_invite(community_id, Invitee::Account(who.clone()), inviter)?;
+
+			Self::deposit_event(Event::InvitedMember(community_id, who));
+
+			Ok(())
+		}
+
+		/// Create an invite code for the community.
+		///
+		/// Only the hash of the code is stored, the code itself is shared off-chain and
+		/// can be redeemed once by any account presenting it.
+		///
+		/// The origin must conform to `CreateOrigin` and be allowed to manage the members.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `code_hash`: Hash of the invite code.
+		///
+		/// Emits `CreatedInviteCode` event when successful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_invite_code())]
+		pub fn create_invite_code(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			code_hash: T::Hash,
+		) -> DispatchResult {
+			let inviter = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+
+			ensure!(
+				Self::has_permission(community_id, &inviter, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_invite(community_id, Invitee::Code(code_hash), inviter)?;
+
+			Self::deposit_event(Event::CreatedInviteCode(community_id, code_hash));
+
+			Ok(())
+		}
+
+		/// Accept an invitation and join the community.
+		///
+		/// The origin must conform to `CreateOrigin`.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		///
+		/// Emits `AcceptedInvitation` event when successful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_invitation())]
+		pub fn accept_invitation(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			Self::do_join_by_invitation(community_id, Invitee::Account(who.clone()), &who)?;
+
+			Self::deposit_event(Event::AcceptedInvitation(community_id, who));
+
+			Ok(())
+		}
+
+		/// Decline an invitation.
+		///
+		/// The origin must conform to `CreateOrigin`.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		///
+		/// Emits `DeclinedInvitation` event when successful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::decline_invitation())]
+		pub fn decline_invitation(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			Self::do_remove_invitation(community_id, &Invitee::Account(who.clone()))?;
+
+			Self::deposit_event(Event::DeclinedInvitation(community_id, who));
+
+			Ok(())
+		}
+
+		/// Redeem an invite code and join the community.
+		///
+		/// The origin must conform to `CreateOrigin`.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `code`: The invite code shared off-chain.
+		///
+		/// Emits `RedeemedInviteCode` event when successful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redeem_invite_code())]
+		pub fn redeem_invite_code(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			code: Vec<u8>,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let code_hash = T::Hashing::hash(&code);
+			Self::do_join_by_invitation(community_id, Invitee::Code(code_hash), &who)?;
+
+			Self::deposit_event(Event::RedeemedInviteCode(community_id, who));
+
+			Ok(())
+		}
+
+		/// Revoke a pending invitation or invite code.
+		///
+		/// The origin must conform to `CreateOrigin` and be allowed to manage the members.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `invitee`: The invited account or the hash of the invite code.
+		///
+		/// Emits `RevokedInvitation` event when successful.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_invitation())]
+		pub fn revoke_invitation(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			invitee: InviteeOf<T>,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_remove_invitation(community_id, &invitee)?;
+
+			Self::deposit_event(Event::RevokedInvitation(community_id, invitee));
+
+			Ok(())
+		}
+
+		/// Ban an account from the community.
+		///
+		/// The account loses its membership, pending invitation and join request, and
+		/// cannot join the community again until the ban expires or is lifted.
+		///
+		/// The origin must conform to `CreateOrigin` and be allowed to manage the members.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `who`: Account to ban.
+		/// - `reason`: Hash of an optional reason for the ban.
+		/// - `expires_at`: Block at which the ban is lifted, `None` for a permanent ban.
+		///
+		/// Emits `BannedMember` event when successful.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::ban_member())]
+		pub fn ban_member(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+			reason: Option<T::Hash>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let moderator = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			let community =
+				Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+			ensure!(
+				Self::has_permission(community_id, &moderator, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(community.founder != who, Error::<T>::NoPermission);
+
+			if let Some(expires_at) = expires_at {
+				ensure!(
+					expires_at > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidBanExpiry
+				);
+			}
+
+			if CommunityMembers::<T>::contains_key(community_id, &who) {
+				Self::do_remove_member(community_id, &who);
+			}
+
+			// The account may have nothing pending, in which case there is nothing to drop.
+			let _ = Self::do_remove_join_request(community_id, &who);
+			let _ = Self::do_remove_invitation(community_id, &Invitee::Account(who.clone()));
+
+			Bans::<T>::insert(community_id, &who, Ban { reason, expires_at });
+
+			Self::deposit_event(Event::BannedMember(community_id, who));
+
+			Ok(())
+		}
+
+		/// Lift the ban of an account.
+		///
+		/// The origin must conform to `CreateOrigin` and be allowed to manage the members.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `who`: Banned account.
+		///
+		/// Emits `UnbannedMember` event when successful.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unban_member())]
+		pub fn unban_member(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let moderator = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+
+			ensure!(
+				Self::has_permission(community_id, &moderator, Permissions::ACCEPT_MEMBERS),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(Bans::<T>::contains_key(community_id, &who), Error::<T>::NotBanned);
+
+			Bans::<T>::remove(community_id, &who);
+
+			Self::deposit_event(Event::UnbannedMember(community_id, who));
+
+			Ok(())
+		}
+
+		/// Apply for the citizenship of a State.
+		///
+		/// The origin must conform to `CreateOrigin` and be a member of the State.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the State.
+		///
+		/// Emits `CitizenshipApplied` event when successful.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::apply_for_citizenship())]
+		pub fn apply_for_citizenship(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			Self::state_of(community_id)?;
+
+			ensure!(Self::is_member(community_id, &who), Error::<T>::NotMember);
+
+			ensure!(!Citizens::<T>::contains_key(community_id, &who), Error::<T>::AlreadyCitizen);
+
+			ensure!(
+				!CitizenshipApplications::<T>::contains_key(community_id, &who),
+				Error::<T>::CitizenshipApplicationAlreadyExist
+			);
+
+			CitizenshipApplications::<T>::insert(
+				community_id,
+				&who,
+				frame_system::Pallet::<T>::block_number(),
+			);
+
+			Self::deposit_event(Event::CitizenshipApplied(community_id, who));
+
+			Ok(())
+		}
+
+		/// Approve a citizenship application.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `GRANT_CITIZENSHIP`
+		/// permission, which the government of the State holds.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the State.
+		/// - `who`: Account which applied for the citizenship.
+		///
+		/// Emits `CitizenshipGranted` event when successful.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_citizenship())]
+		pub fn approve_citizenship(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let approver = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			Self::state_of(community_id)?;
+
+			ensure!(
+				Self::has_permission(community_id, &approver, Permissions::GRANT_CITIZENSHIP),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(
+				CitizenshipApplications::<T>::contains_key(community_id, &who),
+				Error::<T>::CitizenshipApplicationNotExist
+			);
+
+			ensure!(Self::is_member(community_id, &who), Error::<T>::NotMember);
+
+			CitizenshipApplications::<T>::remove(community_id, &who);
+			Citizens::<T>::insert(community_id, &who, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::CitizenshipGranted(community_id, who));
+
+			Ok(())
+		}
+
+		/// Reject a citizenship application.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `GRANT_CITIZENSHIP` permission.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the State.
+		/// - `who`: Account which applied for the citizenship.
+		///
+		/// Emits `CitizenshipRejected` event when successful.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_citizenship())]
+		pub fn reject_citizenship(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let approver = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			Self::state_of(community_id)?;
+
+			ensure!(
+				Self::has_permission(community_id, &approver, Permissions::GRANT_CITIZENSHIP),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(
+				CitizenshipApplications::<T>::take(community_id, &who).is_some(),
+				Error::<T>::CitizenshipApplicationNotExist
+			);
+
+			Self::deposit_event(Event::CitizenshipRejected(community_id, who));
+
+			Ok(())
+		}
+
+		/// Revoke the citizenship of an account.
+		///
+		/// The account stays a member of the State.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `GRANT_CITIZENSHIP` permission.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the State.
+		/// - `who`: Citizen account.
+		///
+		/// Emits `CitizenshipRevoked` event when successful.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_citizenship())]
+		pub fn revoke_citizenship(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let approver = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			Self::state_of(community_id)?;
+
+			ensure!(
+				Self::has_permission(community_id, &approver, Permissions::GRANT_CITIZENSHIP),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(Citizens::<T>::contains_key(community_id, &who), Error::<T>::NotCitizen);
+
+			Self::do_revoke_citizenship(community_id, &who);
+
+			Ok(())
+		}
+
+		/// Request to link the community to a parent community.
+		///
+		/// The link takes effect once the parent approves it, a pending request
+		/// is replaced by a new one.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `MANAGE_SUB_COMMUNITIES`
+		/// permission in the sub-community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the sub-community.
+		/// - `parent_id`: Id of the parent community.
+		/// - `inherit_membership`: Members of the parent are members of the sub-community.
+		/// - `inherit_passports`: Passports of the parent are honoured by the sub-community.
+		///
+		/// Emits `ParentRequested` event when successful.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::request_parent()
+			.saturating_add(Pallet::<T>::descendants_walk_weight(1)))]
+		pub fn request_parent(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			parent_id: T::CommunityId,
+			inherit_membership: bool,
+			inherit_passports: bool,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+			ensure!(Communities::<T>::contains_key(parent_id), Error::<T>::CommunityNotExist);
+
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_SUB_COMMUNITIES),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(!Parents::<T>::contains_key(community_id), Error::<T>::AlreadySubCommunity);
+
+			Self::ensure_valid_parent(community_id, parent_id)?;
+
+			ParentRequests::<T>::insert(
+				community_id,
+				ParentLink { parent: parent_id, inherit_membership, inherit_passports },
+			);
+
+			Self::deposit_event(Event::ParentRequested(community_id, parent_id));
+
+			Ok(())
+		}
+
+		/// Approve the request of a community to become a sub-community.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `MANAGE_SUB_COMMUNITIES`
+		/// permission in the parent community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the parent community.
+		/// - `sub_community_id`: Id of the requesting community.
+		///
+		/// Emits `SubCommunityAdded` event when successful.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_sub_community()
+			.saturating_add(Pallet::<T>::descendants_walk_weight(1)))]
+		pub fn approve_sub_community(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			sub_community_id: T::CommunityId,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_SUB_COMMUNITIES),
+				Error::<T>::NoPermission
+			);
+
+			let link = ParentRequests::<T>::get(sub_community_id)
+				.filter(|link| link.parent == community_id)
+				.ok_or(Error::<T>::ParentRequestNotExist)?;
+
+			ensure!(
+				Communities::<T>::contains_key(sub_community_id),
+				Error::<T>::CommunityNotExist
+			);
+
+			// The hierarchy may have changed since the request was made.
+			Self::ensure_valid_parent(sub_community_id, community_id)?;
+
+			ParentRequests::<T>::remove(sub_community_id);
+			Parents::<T>::insert(sub_community_id, link);
+			SubCommunities::<T>::insert(community_id, sub_community_id, ());
+			Self::do_add_descendants(
+				community_id,
+				DescendantCount::<T>::get(sub_community_id).saturating_add(1),
+			);
+
+			Self::deposit_event(Event::SubCommunityAdded(community_id, sub_community_id));
+
+			Ok(())
+		}
+
+		/// Reject the request of a community to become a sub-community.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `MANAGE_SUB_COMMUNITIES`
+		/// permission in the parent community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the parent community.
+		/// - `sub_community_id`: Id of the requesting community.
+		///
+		/// Emits `SubCommunityRejected` event when successful.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_sub_community())]
+		pub fn reject_sub_community(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			sub_community_id: T::CommunityId,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_SUB_COMMUNITIES),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(
+				ParentRequests::<T>::get(sub_community_id)
+					.map_or(false, |link| link.parent == community_id),
+				Error::<T>::ParentRequestNotExist
+			);
+
+			ParentRequests::<T>::remove(sub_community_id);
+
+			Self::deposit_event(Event::SubCommunityRejected(community_id, sub_community_id));
+
+			Ok(())
+		}
+
+		/// Detach a sub-community from its parent.
+		///
+		/// The origin must conform to `CreateOrigin` and hold the `MANAGE_SUB_COMMUNITIES`
+		/// permission in either the sub-community or its parent.
+		///
+		/// Parameters:
+		/// - `sub_community_id`: Id of the sub-community.
+		///
+		/// Emits `SubCommunityDetached` event when successful.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::detach_sub_community())]
+		pub fn detach_sub_community(
+			origin: OriginFor<T>,
+			sub_community_id: T::CommunityId,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &sub_community_id)?;
+
+			let link = Parents::<T>::get(sub_community_id).ok_or(Error::<T>::NotSubCommunity)?;
+
+			ensure!(
+				Self::has_permission(sub_community_id, &who, Permissions::MANAGE_SUB_COMMUNITIES)
+					|| Self::has_permission(link.parent, &who, Permissions::MANAGE_SUB_COMMUNITIES),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_detach_sub_community(sub_community_id);
+
+			Ok(())
+		}
+
+		/// Set the joining fee and the recurring due of a community, paid into its treasury.
+		///
+		/// The origin must be the founder or hold the `MANAGE_TREASURY` permission.
+		/// Passing `None` for both removes the dues.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `joining_fee`: Fee paid once when joining, covering the first period.
+		/// - `recurring_due`: Amount paid for every period and the period length in blocks.
+		///
+		/// Emits `UpdatedMembershipDues` event when successful.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_membership_dues())]
+		pub fn set_membership_dues(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			joining_fee: Option<DuesAmountOf<T>>,
+			recurring_due: Option<RecurringDueOf<T>>,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+			ensure!(
+				Self::has_permission(community_id, &who, Permissions::MANAGE_TREASURY),
+				Error::<T>::NoPermission
+			);
+
+			if let Some(due) = &recurring_due {
+				ensure!(!due.period.is_zero(), Error::<T>::InvalidDuesPeriod);
+			}
+
+			if joining_fee.is_none() && recurring_due.is_none() {
+				CommunityDues::<T>::remove(community_id);
+			} else {
+				// Changing the due does not restart the grace period of the existing members.
+				let since = CommunityDues::<T>::get(community_id)
+					.filter(|dues| dues.recurring_due.is_some())
+					.map_or_else(frame_system::Pallet::<T>::block_number, |dues| dues.since);
+
+				CommunityDues::<T>::insert(
+					community_id,
+					MembershipDues { joining_fee, recurring_due, since },
+				);
+			}
+
+			Self::deposit_event(Event::UpdatedMembershipDues(community_id));
+
+			Ok(())
+		}
+
+		/// Pay the recurring due of a community for a number of periods.
+		///
+		/// The origin must conform to `CreateOrigin` and be a member of the community.
+		/// Lapsed members pay from the current block, without settling the missed periods.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `periods`: Number of periods paid for.
+		///
+		/// Emits `DuesPaid` event when successful.
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pay_dues())]
+		pub fn pay_dues(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			periods: u32,
+		) -> DispatchResult {
+			let who = T::CreateOrigin::ensure_origin(origin, &community_id)?;
+
+			ensure!(Communities::<T>::contains_key(community_id), Error::<T>::CommunityNotExist);
+			ensure!(CommunityMembers::<T>::contains_key(community_id, &who), Error::<T>::NotMember);
+			ensure!(periods > 0, Error::<T>::InvalidDuesPeriod);
+
+			let due = CommunityDues::<T>::get(community_id)
+				.and_then(|dues| dues.recurring_due)
+				.ok_or(Error::<T>::DuesNotExist)?;
+
+			Self::do_pay_to_treasury(community_id, &who, due.amount, periods)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let paid_until = Self::dues_paid_until_of(community_id, &who)
+				.map_or(now, |paid_until| paid_until.max(now))
+				.saturating_add(due.period.saturating_mul(periods.into()));
+
+			DuesPaidUntil::<T>::insert(community_id, &who, paid_until);
+
+			Self::deposit_event(Event::DuesPaid(community_id, who, paid_until));
+
+			Ok(())
+		}
+
+		/// Claim the balance of an asset left in the treasury of a dissolved community.
+		///
+		/// The origin must be Signed by the founder of the community at its dissolution.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the dissolved community.
+		/// - `asset_id`: Id of the asset.
+		///
+		/// Emits `TreasuryAssetSpent` event when successful.
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_treasury_asset())]
+		pub fn claim_treasury_asset(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				TreasuryClaimants::<T>::get(community_id).as_ref() == Some(&who),
+				Error::<T>::NoPermission
+			);
+
+			let amount = Self::treasury_asset_balance(community_id, asset_id.clone());
+			ensure!(!amount.is_zero(), Error::<T>::TreasuryEmpty);
+
+			Self::do_spend_asset(community_id, asset_id, &who, amount)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	Error<T>: PartialEq,
+{
+	pub fn do_create_community(
+		community_id: T::CommunityId,
+		founder: T::AccountId,
+		maybe_logo: Option<Vec<u8>>,
+		name: Vec<u8>,
+		maybe_description: Option<Vec<u8>>,
+		maybe_members: Option<Vec<T::AccountId>>,
+		metadata: Option<CommunityMetaDataFor<T>>,
+		category: Category,
+		maybe_tag: Option<Vec<u8>>,
+		maybe_primary_color: Option<Vec<u8>>,
+		maybe_secondary_color: Option<Vec<u8>>,
+		community_type: Option<CommunityTypeOf<T>>,
+	) -> DispatchResult {
+		let bounded_name: BoundedVec<u8, T::NameLimit> =
+			name.try_into().map_err(|_| Error::<T>::BadName)?;
+
+		let normalized_name = Self::normalize_name(&bounded_name)?;
+
+		ensure!(
+			!CommunityNameIndex::<T>::contains_key(&normalized_name),
 			Error::<T>::CommunityAlreadyExist
 		);
 
-		let bounded_description: BoundedVec<u8, T::DescriptionLimit> =
-			if let Some(desc) = maybe_description {
-				desc.try_into().map_err(|_| Error::<T>::BadDescription)?
-			} else {
-				Default::default()
-			};
+		let bounded_description: BoundedVec<u8, T::DescriptionLimit> =
+			if let Some(desc) = maybe_description {
+				desc.try_into().map_err(|_| Error::<T>::BadDescription)?
+			} else {
+				Default::default()
+			};
+
+		let bounded_tag: BoundedVec<u8, T::TagLimit> = if let Some(tag) = maybe_tag {
+			tag.try_into().map_err(|_| Error::<T>::BadTag)?
+		} else {
+			Default::default()
+		};
+
+		let bounded_primary_color: BoundedVec<u8, T::ColorLimit> =
+			if let Some(color) = maybe_primary_color {
+				color.try_into().map_err(|_| Error::<T>::BadColor)?
+			} else {
+				Default::default()
+			};
+
+		let bounded_secondary_color: BoundedVec<u8, T::ColorLimit> =
+			if let Some(color) = maybe_secondary_color {
+				color.try_into().map_err(|_| Error::<T>::BadColor)?
+			} else {
+				Default::default()
+			};
+
+		let bounded_logo: BoundedVec<u8, T::LogoLimit> = if let Some(logo) = maybe_logo {
+			logo.try_into().map_err(|_| Error::<T>::BadLogo)?
+		} else {
+			Default::default()
+		};
+
+		let members = maybe_members.unwrap_or_default();
+		ensure!(
+			members.len() <= T::MaxInvitationsPerBlock::get() as usize,
+			Error::<T>::TooManyMembers
+		);
+
+		let reference_id = Self::generate_reference_id(community_id)?;
+
+		// The initial citizens of a State are kept in `Citizens` rather than in the State itself.
+		let mut initial_citizens = Vec::new();
+		let community_type = match community_type {
+			Some(CommunityType::State(mut state)) => {
+				initial_citizens = sp_std::mem::take(&mut state.citizens);
+				Some(CommunityType::State(state))
+			},
+			community_type => community_type,
+		};
+		ensure!(
+			initial_citizens.len() <= T::MaxInvitationsPerBlock::get() as usize,
+			Error::<T>::TooManyMembers
+		);
+		let citizen_set: BTreeSet<&T::AccountId> = initial_citizens.iter().collect();
+
+		let community = Community {
+			founder: founder.clone(),
+			logo: bounded_logo,
+			name: bounded_name,
+			description: bounded_description,
+			metadata,
+			reference_id,
+			category,
+			tag: bounded_tag,
+			primary_color: bounded_primary_color,
+			secondary_color: bounded_secondary_color,
+			community_type,
+		};
+
+		Self::do_update_metadata_deposit(community_id, &founder, &community)?;
+
+		<CommunityAccount<T>>::try_mutate(founder.clone(), |communities| -> DispatchResult {
+			communities
+				.try_push(community_id)
+				.map_err(|_| Error::<T>::TooManyCommunities)?;
+			Ok(())
+		})?;
+		<Communities<T>>::insert(community_id, community);
+		<CommunityNameIndex<T>>::insert(normalized_name, community_id);
+		<ReferenceIdToCommunity<T>>::insert(reference_id, community_id);
+
+		// The other listed accounts only join once they accept their invitation.
+		for member in members {
+			// The founder and the initial citizens are added right away.
+			if member == founder || citizen_set.contains(&member) {
+				if !CommunityMembers::<T>::contains_key(community_id, &member) {
+					Self::do_add_member(community_id, &member);
+				}
+				continue;
+			}
+
+			let invitee = Invitee::Account(member.clone());
+			if Invitations::<T>::contains_key(community_id, &invitee) {
+				continue;
+			}
+
+			Self::do_invite(community_id, invitee, founder.clone())?;
+			Self::deposit_event(Event::InvitedMember(community_id, member));
+		}
+
+		for citizen in initial_citizens {
+			if !CommunityMembers::<T>::contains_key(community_id, &citizen) {
+				Self::do_add_member(community_id, &citizen);
+			}
+			Citizens::<T>::insert(
+				community_id,
+				&citizen,
+				frame_system::Pallet::<T>::block_number(),
+			);
+		}
+
+		let next_id = community_id.increment();
+		NextCommunityId::<T>::set(Some(next_id));
+
+		Self::deposit_event(Event::CreatedCommunity(community_id, reference_id, founder));
+
+		Ok(())
+	}
+
+	/// The number of listed members and initial citizens, which the weight of
+	/// `create_community` depends on.
+	pub fn initial_member_count(
+		members: &Option<Vec<T::AccountId>>,
+		community_type: &Option<CommunityTypeOf<T>>,
+	) -> u32 {
+		let citizens = match community_type {
+			Some(CommunityType::State(state)) => state.citizens.len(),
+			_ => 0,
+		};
+		members
+			.as_ref()
+			.map_or(0, |members| members.len())
+			.saturating_add(citizens) as u32
+	}
+
+	/// Resolve a community id from its name.
+	///
+	/// The lookup ignores whitespace and letter case, the same way names are
+	/// compared when a community is created.
+	pub fn community_id_by_name(name: &[u8]) -> Option<T::CommunityId> {
+		let normalized_name = Self::normalize_name(name).ok()?;
+		CommunityNameIndex::<T>::get(normalized_name)
+	}
+
+	/// Normalize a community name by stripping whitespace and lowercasing it.
+	pub fn normalize_name(name: &[u8]) -> Result<BoundedVec<u8, T::NameLimit>, Error<T>> {
+		let normalized_name: String = String::from_utf8(name.to_vec())
+			.unwrap_or(Default::default())
+			.split_whitespace()
+			.collect();
+		normalized_name
+			.to_lowercase()
+			.into_bytes()
+			.try_into()
+			.map_err(|_| Error::<T>::BadName)
+	}
+
+	/// Remove a pending join request together with its expiry entry and return it.
+	fn do_remove_join_request(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+	) -> Result<JoinRequestOf<T>, DispatchError> {
+		let request =
+			JoinRequests::<T>::take(community_id, who).ok_or(Error::<T>::JoinRequestNotExist)?;
+
+		JoinRequestExpiry::<T>::mutate(request.expires_at, |requests| {
+			requests.retain(|(id, account)| !(*id == community_id && account == who));
+		});
+
+		Ok(request)
+	}
+
+	/// Store an invitation and queue it for expiry.
+	fn do_invite(
+		community_id: T::CommunityId,
+		invitee: InviteeOf<T>,
+		invited_by: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			!Invitations::<T>::contains_key(community_id, &invitee),
+			Error::<T>::InvitationAlreadyExist
+		);
+
+		let expires_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::InvitationTtl::get());
+
+		InvitationExpiry::<T>::try_mutate(expires_at, |invitations| {
+			invitations
+				.try_push((community_id, invitee.clone()))
+				.map_err(|_| Error::<T>::TooManyInvitations)
+		})?;
+
+		Invitations::<T>::insert(community_id, invitee, Invitation { invited_by, expires_at });
 
-		let bounded_tag: BoundedVec<u8, T::TagLimit> = if let Some(tag) = maybe_tag {
-			tag.try_into().map_err(|_| Error::<T>::BadTag)?
+		Ok(())
+	}
+
+	/// Remove a pending invitation along with its expiry entry.
+	fn do_remove_invitation(
+		community_id: T::CommunityId,
+		invitee: &InviteeOf<T>,
+	) -> DispatchResult {
+		let invitation =
+			Invitations::<T>::take(community_id, invitee).ok_or(Error::<T>::InvitationNotExist)?;
+
+		InvitationExpiry::<T>::mutate(invitation.expires_at, |invitations| {
+			invitations.retain(|(id, entry)| !(*id == community_id && entry == invitee));
+		});
+
+		Ok(())
+	}
+
+	/// Consume an invitation and add `who` to the community.
+	fn do_join_by_invitation(
+		community_id: T::CommunityId,
+		invitee: InviteeOf<T>,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let community = Communities::<T>::get(community_id).ok_or(Error::<T>::CommunityNotExist)?;
+
+		ensure!(community.founder != *who, Error::<T>::AlreadyMember);
+
+		ensure!(!CommunityMembers::<T>::contains_key(community_id, who), Error::<T>::AlreadyMember);
+
+		ensure!(!Self::is_banned(community_id, who), Error::<T>::Banned);
+
+		if let Some(requirement) = GateRequirements::<T>::get(community_id) {
+			ensure!(T::MembershipGate::is_eligible(&requirement, who), Error::<T>::NotEligible);
+		}
+
+		Self::do_remove_invitation(community_id, &invitee)?;
+
+		Self::do_pay_joining_fee(community_id, who)?;
+		Self::do_add_member(community_id, who);
+
+		Ok(())
+	}
+
+	/// Reserve the founder bond of a community from the founder.
+	pub fn do_reserve_founder_bond(
+		community_id: T::CommunityId,
+		founder: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		pallet_balances::Pallet::<T>::reserve(founder, amount)
+			.map_err(|_| Error::<T>::InsufficientBalanceToBecomeFounder)?;
+
+		FounderBonds::<T>::insert(community_id, amount);
+
+		Ok(())
+	}
+
+	/// Release the founder bond of a community back to the founder.
+	pub fn do_release_founder_bond(community_id: T::CommunityId, founder: &T::AccountId) {
+		if let Some(amount) = FounderBonds::<T>::take(community_id) {
+			pallet_balances::Pallet::<T>::unreserve(founder, amount);
+		}
+	}
+
+	/// The number of bytes of logo, description, metadata and constitution supplied for a
+	/// community.
+	pub fn metadata_bytes(community: &CommunityOf<T>) -> u32 {
+		let metadata_len = community
+			.metadata
+			.as_ref()
+			.map_or(0, |metadata| metadata.encoded_size());
+		let community_type_len = community
+			.community_type
+			.as_ref()
+			.map_or(0, |community_type| community_type.encoded_size());
+		(community.logo.len() + community.description.len() + metadata_len + community_type_len)
+			as u32
+	}
+
+	/// The treasury of the community when the origin is the community itself, which then pays
+	/// the metadata deposit instead of the founder.
+	fn metadata_depositor(
+		origin: &OriginFor<T>,
+		community_id: T::CommunityId,
+	) -> Option<T::AccountId> {
+		T::CommunityOrigin::try_origin(origin.clone())
+			.ok()
+			.map(|_| Self::treasury_account(community_id))
+	}
+
+	/// The account the metadata deposit of a community is reserved from.
+	fn metadata_deposit_holder(
+		community_id: T::CommunityId,
+		founder: &T::AccountId,
+	) -> T::AccountId {
+		if TreasuryMetadataDeposits::<T>::contains_key(community_id) {
+			Self::treasury_account(community_id)
 		} else {
-			Default::default()
-		};
+			founder.clone()
+		}
+	}
 
-		let bounded_primary_color: BoundedVec<u8, T::ColorLimit> =
-			if let Some(color) = maybe_primary_color {
-				color.try_into().map_err(|_| Error::<T>::BadColor)?
-			} else {
-				Default::default()
-			};
+	/// Top up or refund the metadata deposit to match the stored bytes.
+	///
+	/// The deposit is reserved from `depositor`, and moves to it when held by another account.
+	fn do_update_metadata_deposit(
+		community_id: T::CommunityId,
+		depositor: &T::AccountId,
+		community: &CommunityOf<T>,
+	) -> DispatchResult {
+		let deposit =
+			T::MetadataDepositPerByte::get().saturating_mul(Self::metadata_bytes(community).into());
+		let reserved = MetadataDeposits::<T>::get(community_id).unwrap_or_default();
+		let holder = Self::metadata_deposit_holder(community_id, &community.founder);
+
+		if holder != *depositor {
+			pallet_balances::Pallet::<T>::reserve(depositor, deposit)
+				.map_err(|_| Error::<T>::InsufficientBalanceForDeposit)?;
+			pallet_balances::Pallet::<T>::unreserve(&holder, reserved);
+		} else if deposit > reserved {
+			pallet_balances::Pallet::<T>::reserve(depositor, deposit.saturating_sub(reserved))
+				.map_err(|_| Error::<T>::InsufficientBalanceForDeposit)?;
+		} else {
+			pallet_balances::Pallet::<T>::unreserve(depositor, reserved.saturating_sub(deposit));
+		}
 
-		let bounded_secondary_color: BoundedVec<u8, T::ColorLimit> =
-			if let Some(color) = maybe_secondary_color {
-				color.try_into().map_err(|_| Error::<T>::BadColor)?
-			} else {
-				Default::default()
-			};
+		if deposit.is_zero() {
+			MetadataDeposits::<T>::remove(community_id);
+		} else {
+			MetadataDeposits::<T>::insert(community_id, deposit);
+		}
 
-		let bounded_logo: BoundedVec<u8, T::LogoLimit> = if let Some(logo) = maybe_logo {
-			logo.try_into().map_err(|_| Error::<T>::BadLogo)?
+		if *depositor == Self::treasury_account(community_id) && !deposit.is_zero() {
+			TreasuryMetadataDeposits::<T>::insert(community_id, ());
 		} else {
-			Default::default()
-		};
+			TreasuryMetadataDeposits::<T>::remove(community_id);
+		}
 
-		let members = if let Some(members) = maybe_members { members } else { Vec::new() };
+		Ok(())
+	}
 
-		// Random value.
-		let nonce = Self::get_and_increment_nonce();
-		let random_seed = T::MyRandomness::random(&nonce).encode();
+	/// Move the metadata deposit of a community to its new founder, unless the treasury holds
+	/// it.
+	fn do_transfer_metadata_deposit(
+		community_id: T::CommunityId,
+		old_founder: &T::AccountId,
+		new_founder: &T::AccountId,
+	) -> DispatchResult {
+		if TreasuryMetadataDeposits::<T>::contains_key(community_id) {
+			return Ok(());
+		}
 
-		let random_number = u128::decode(&mut random_seed.as_ref())
-			.expect("secure hashes should always be bigger than u32; qed");
+		if let Some(amount) = MetadataDeposits::<T>::get(community_id) {
+			pallet_balances::Pallet::<T>::unreserve(old_founder, amount);
+			pallet_balances::Pallet::<T>::reserve(new_founder, amount)
+				.map_err(|_| Error::<T>::InsufficientBalanceForDeposit)?;
+		}
 
-		let random_value: [u8; 16] = random_number.to_be_bytes();
+		Ok(())
+	}
 
-		let community = Community {
-			founder: founder.clone(),
-			logo: bounded_logo,
-			name: bounded_name,
-			description: bounded_description,
-			members,
-			metadata,
-			reference_id: random_value,
-			category,
-			tag: bounded_tag,
-			primary_color: bounded_primary_color,
-			secondary_color: bounded_secondary_color,
-			community_type,
-		};
+	/// Return the metadata deposit of a community to the founder or the treasury holding it.
+	fn do_release_metadata_deposit(community_id: T::CommunityId, founder: &T::AccountId) {
+		let holder = Self::metadata_deposit_holder(community_id, founder);
+		TreasuryMetadataDeposits::<T>::remove(community_id);
 
-		<CommunityAccount<T>>::try_mutate(founder.clone(), |communities| -> DispatchResult {
-			communities
-				.try_push(community_id)
-				.map_err(|_| Error::<T>::TooManyCommunities)?;
-			Ok(())
+		if let Some(amount) = MetadataDeposits::<T>::take(community_id) {
+			pallet_balances::Pallet::<T>::unreserve(&holder, amount);
+		}
+	}
+
+	/// The keyless account holding the funds of a community.
+	pub fn treasury_account(community_id: T::CommunityId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(community_id)
+	}
+
+	/// The JUR balance of the treasury of a community.
+	pub fn treasury_balance(community_id: T::CommunityId) -> T::Balance {
+		pallet_balances::Pallet::<T>::free_balance(&Self::treasury_account(community_id))
+	}
+
+	/// The balance of an asset held by the treasury of a community.
+	pub fn treasury_asset_balance(
+		community_id: T::CommunityId,
+		asset_id: AssetIdOf<T>,
+	) -> AssetBalanceOf<T> {
+		<T::Assets as fungibles::Inspect<T::AccountId>>::reducible_balance(
+			asset_id,
+			&Self::treasury_account(community_id),
+			Preservation::Expendable,
+			Fortitude::Polite,
+		)
+	}
+
+	/// Transfer JUR from the treasury of a community, without any permission check.
+	pub fn do_spend(
+		community_id: T::CommunityId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		<pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+			&Self::treasury_account(community_id),
+			beneficiary,
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		Self::deposit_event(Event::TreasurySpent(community_id, beneficiary.clone(), amount));
+
+		Ok(())
+	}
+
+	/// Transfer assets from the treasury of a community, without any permission check.
+	pub fn do_spend_asset(
+		community_id: T::CommunityId,
+		asset_id: AssetIdOf<T>,
+		beneficiary: &T::AccountId,
+		amount: AssetBalanceOf<T>,
+	) -> DispatchResult {
+		<T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+			asset_id.clone(),
+			&Self::treasury_account(community_id),
+			beneficiary,
+			amount,
+			Preservation::Expendable,
+		)?;
+
+		Self::deposit_event(Event::TreasuryAssetSpent(
+			community_id,
+			asset_id,
+			beneficiary.clone(),
+			amount,
+		));
+
+		Ok(())
+	}
+
+	/// Remove the community record and queue the rest of its state for the cleanup.
+	fn do_dissolve_community(community_id: T::CommunityId, community: CommunityOf<T>) {
+		Communities::<T>::remove(community_id);
+
+		// The sub-communities are detached by the cleanup.
+		Self::do_detach_sub_community(community_id);
+		ParentRequests::<T>::remove(community_id);
+
+		if let Ok(normalized_name) = Self::normalize_name(&community.name) {
+			CommunityNameIndex::<T>::remove(normalized_name);
+		}
+		ReferenceIdToCommunity::<T>::remove(community.reference_id);
+
+		CommunityAccount::<T>::mutate(&community.founder, |communities| {
+			communities.retain(|id| *id != community_id);
+		});
+
+		MemberCount::<T>::remove(community_id);
+		GateRequirements::<T>::remove(community_id);
+		CommunityDues::<T>::remove(community_id);
+		PendingFounderTransfers::<T>::remove(community_id);
+
+		Self::do_release_founder_bond(community_id, &community.founder);
+		Self::do_release_metadata_deposit(community_id, &community.founder);
+
+		// The treasury can't be spent anymore, its JUR go to the founder who can claim the
+		// assets left in it.
+		let balance = Self::treasury_balance(community_id);
+		if !balance.is_zero() {
+			if let Err(error) = Self::do_spend(community_id, &community.founder, balance) {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to sweep the treasury of the dissolved community {:?}: {:?}",
+					community_id,
+					error
+				);
+			}
+		}
+		TreasuryClaimants::<T>::insert(community_id, community.founder.clone());
+
+		DissolvedCommunities::<T>::insert(community_id, frame_system::Pallet::<T>::block_number());
+	}
+
+	/// Remove up to `limit` storage items kept for a dissolved community, starting with
+	/// the state of this pallet followed by the dependent pallets.
+	///
+	/// Returns the number of removed items, fewer than `limit` means nothing is left.
+	fn do_clean_up_community(community_id: T::CommunityId, limit: u32) -> u32 {
+		let mut removed = CommunityMembers::<T>::drain_prefix(community_id)
+			.take(limit as usize)
+			.count() as u32;
+
+		if removed < limit {
+			removed += CommunityRoles::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			for (who, request) in JoinRequests::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+			{
+				JoinRequestExpiry::<T>::mutate(request.expires_at, |requests| {
+					requests.retain(|(id, account)| !(*id == community_id && *account == who));
+				});
+				removed += 1;
+			}
+		}
+
+		if removed < limit {
+			removed += Citizens::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			removed += CitizenshipApplications::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			removed += Bans::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			for (invitee, invitation) in Invitations::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+			{
+				InvitationExpiry::<T>::mutate(invitation.expires_at, |invitations| {
+					invitations.retain(|(id, entry)| !(*id == community_id && *entry == invitee));
+				});
+				removed += 1;
+			}
+		}
+
+		if removed < limit {
+			removed += DuesPaidUntil::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			for (sub_community_id, _) in SubCommunities::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+			{
+				Parents::<T>::remove(sub_community_id);
+				removed += 1;
+			}
+		}
+
+		if removed < limit {
+			DescendantCount::<T>::remove(community_id);
+			removed = removed.saturating_add(T::OnCommunityDissolved::on_community_dissolved(
+				community_id,
+				limit.saturating_sub(removed),
+			));
+		}
+
+		removed
+	}
+
+	/// The State details of a community, failing for other community types.
+	pub fn state_of(community_id: T::CommunityId) -> Result<StateOf<T>, Error<T>> {
+		match Communities::<T>::get(community_id)
+			.ok_or(Error::<T>::CommunityNotExist)?
+			.community_type
+		{
+			Some(CommunityType::State(state)) => Ok(state),
+			_ => Err(Error::<T>::NotStateCommunity),
+		}
+	}
+
+	/// Check whether the given account is a citizen of the State.
+	pub fn is_citizen(community_id: T::CommunityId, who: &T::AccountId) -> bool {
+		Citizens::<T>::contains_key(community_id, who)
+	}
+
+	/// Remove the citizenship of an account and notify `OnCitizenshipRevoked`.
+	fn do_revoke_citizenship(community_id: T::CommunityId, who: &T::AccountId) {
+		if Citizens::<T>::take(community_id, who).is_some() {
+			T::OnCitizenshipRevoked::on_citizenship_revoked(community_id, who);
+			Self::deposit_event(Event::CitizenshipRevoked(community_id, who.clone()));
+		}
+	}
+
+	/// Apply an amendment to the constitution of a State.
+	///
+	/// There is no permission check, callers are expected to act on an accepted proposal. The
+	/// account holding the metadata deposit covers the new size of the constitution.
+	pub fn do_amend_constitution(
+		community_id: T::CommunityId,
+		amendment: ConstitutionAmendmentOf<T>,
+	) -> DispatchResult {
+		Communities::<T>::try_mutate(community_id, |maybe_community| -> DispatchResult {
+			let community = maybe_community
+				.as_mut()
+				.ok_or(Error::<T>::CommunityNotExist)?;
+
+			let state = match community.community_type.as_mut() {
+				Some(CommunityType::State(state)) => state,
+				_ => return Err(Error::<T>::NotStateCommunity.into()),
+			};
+
+			match amendment {
+				ConstitutionAmendment::AddArticle(text) => state
+					.constitution
+					.try_push(text)
+					.map_err(|_| Error::<T>::TooManyArticles)?,
+				ConstitutionAmendment::ReplaceArticle(index, text) => {
+					let article = state
+						.constitution
+						.get_mut(index as usize)
+						.ok_or(Error::<T>::ArticleNotExist)?;
+					*article = text;
+				},
+				ConstitutionAmendment::RemoveArticle(index) => {
+					ensure!(
+						(index as usize) < state.constitution.len(),
+						Error::<T>::ArticleNotExist
+					);
+					state.constitution.remove(index as usize);
+				},
+			}
+
+			let depositor = Self::metadata_deposit_holder(community_id, &community.founder);
+			Self::do_update_metadata_deposit(community_id, &depositor, community)
 		})?;
-		<Communities<T>>::insert(community_id, community);
 
-		let next_id = community_id.increment();
-		NextCommunityId::<T>::set(Some(next_id));
+		Self::deposit_event(Event::AmendedConstitution(community_id));
+
+		Ok(())
+	}
+
+	/// Check whether the given account is currently banned from the community.
+	pub fn is_banned(community_id: T::CommunityId, who: &T::AccountId) -> bool {
+		match Bans::<T>::get(community_id, who) {
+			Some(ban) => ban
+				.expires_at
+				.map_or(true, |expires_at| expires_at > frame_system::Pallet::<T>::block_number()),
+			None => false,
+		}
+	}
+
+	/// Check whether the given account is a member of the community.
+	///
+	/// Members of gated communities are only counted while they still hold the
+	/// required token, the stale membership itself is removed by `revalidate_membership`.
+	/// Sub-communities inheriting the membership of their parent count the members of
+	/// the parent as well, unless they are banned from the sub-community.
+	pub fn is_member(community_id: T::CommunityId, who: &T::AccountId) -> bool {
+		if !CommunityMembers::<T>::contains_key(community_id, who) {
+			return match Parents::<T>::get(community_id) {
+				Some(link) if link.inherit_membership => {
+					!Self::is_banned(community_id, who) && Self::is_member(link.parent, who)
+				},
+				_ => false,
+			};
+		}
+
+		match GateRequirements::<T>::get(community_id) {
+			Some(requirement) => T::MembershipGate::is_eligible(&requirement, who),
+			None => true,
+		}
+	}
+
+	/// Check whether the given account is a member of any descendant of the community.
+	pub fn is_member_of_descendants(community_id: T::CommunityId, who: &T::AccountId) -> bool {
+		Self::descendants_of(community_id)
+			.into_iter()
+			.any(|descendant| Self::is_member(descendant, who))
+	}
+
+	/// All the direct and indirect sub-communities of a community.
+	pub fn descendants_of(community_id: T::CommunityId) -> Vec<T::CommunityId> {
+		let mut descendants: Vec<T::CommunityId> =
+			SubCommunities::<T>::iter_key_prefix(community_id).collect();
+
+		// `MaxDescendants` bounds the number of communities walked here.
+		let mut index = 0;
+		while index < descendants.len() {
+			descendants.extend(SubCommunities::<T>::iter_key_prefix(descendants[index]));
+			index += 1;
+		}
+
+		descendants
+	}
+
+	/// The community whose passports are honoured by the given community.
+	///
+	/// This is the community itself unless it inherits the passports of its parent.
+	pub fn passport_community(community_id: T::CommunityId) -> T::CommunityId {
+		match Parents::<T>::get(community_id) {
+			Some(link) if link.inherit_passports => Self::passport_community(link.parent),
+			_ => community_id,
+		}
+	}
+
+	/// The weight of walking the descendants of a community, reading `reads` items for the
+	/// community and each of its descendants.
+	pub fn descendants_walk_weight(reads: u64) -> Weight {
+		T::DbWeight::get()
+			.reads(reads.saturating_mul(T::MaxDescendants::get().saturating_add(1) as u64))
+	}
+
+	/// Ensure linking the community to the parent keeps the hierarchy acyclic and
+	/// within `MaxHierarchyDepth` and `MaxDescendants`.
+	fn ensure_valid_parent(
+		community_id: T::CommunityId,
+		parent_id: T::CommunityId,
+	) -> DispatchResult {
+		ensure!(community_id != parent_id, Error::<T>::InvalidParent);
+
+		// Number of levels from the top of the hierarchy down to the parent.
+		let mut parent_depth = 1_u32;
+		let mut ancestor = parent_id;
+		while let Some(link) = Parents::<T>::get(ancestor) {
+			ensure!(link.parent != community_id, Error::<T>::InvalidParent);
+			ancestor = link.parent;
+			parent_depth = parent_depth.saturating_add(1);
+		}
+
+		ensure!(
+			parent_depth.saturating_add(Self::height_of(community_id))
+				<= T::MaxHierarchyDepth::get(),
+			Error::<T>::HierarchyTooDeep
+		);
+
+		// The top of the hierarchy counts the descendants of every community below it.
+		ensure!(
+			DescendantCount::<T>::get(ancestor)
+				.saturating_add(DescendantCount::<T>::get(community_id))
+				.saturating_add(1)
+				<= T::MaxDescendants::get(),
+			Error::<T>::TooManySubCommunities
+		);
+
+		Ok(())
+	}
+
+	/// Number of levels in the hierarchy rooted at the community, including itself.
+	fn height_of(community_id: T::CommunityId) -> u32 {
+		SubCommunities::<T>::iter_key_prefix(community_id)
+			.map(Self::height_of)
+			.max()
+			.unwrap_or_default()
+			.saturating_add(1)
+	}
+
+	/// Add the given number of descendants to the community and its ancestors.
+	fn do_add_descendants(community_id: T::CommunityId, count: u32) {
+		let mut ancestor = Some(community_id);
+		while let Some(id) = ancestor {
+			DescendantCount::<T>::mutate(id, |descendants| {
+				*descendants = descendants.saturating_add(count)
+			});
+			ancestor = Parents::<T>::get(id).map(|link| link.parent);
+		}
+	}
+
+	/// Remove the given number of descendants from the community and its ancestors.
+	fn do_remove_descendants(community_id: T::CommunityId, count: u32) {
+		let mut ancestor = Some(community_id);
+		while let Some(id) = ancestor {
+			DescendantCount::<T>::mutate_exists(id, |descendants| {
+				*descendants = descendants
+					.map(|descendants| descendants.saturating_sub(count))
+					.filter(|descendants| !descendants.is_zero());
+			});
+			ancestor = Parents::<T>::get(id).map(|link| link.parent);
+		}
+	}
+
+	/// Remove the link between a sub-community and its parent, if any.
+	fn do_detach_sub_community(sub_community_id: T::CommunityId) {
+		if let Some(link) = Parents::<T>::take(sub_community_id) {
+			SubCommunities::<T>::remove(link.parent, sub_community_id);
+			Self::do_remove_descendants(
+				link.parent,
+				DescendantCount::<T>::get(sub_community_id).saturating_add(1),
+			);
+			Self::deposit_event(Event::SubCommunityDetached(link.parent, sub_community_id));
+		}
+	}
+
+	/// Add the account to the community members, bump the member count and notify
+	/// `OnMemberJoined`.
+	///
+	/// The caller is responsible for checking that the account is not already a member.
+	pub fn do_add_member(community_id: T::CommunityId, who: &T::AccountId) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let member_info = MemberInfo { joined_at: now };
+		CommunityMembers::<T>::insert(community_id, who, member_info);
+
+		// Joining covers the first period of the recurring due.
+		if let Some(due) = CommunityDues::<T>::get(community_id).and_then(|dues| dues.recurring_due)
+		{
+			DuesPaidUntil::<T>::insert(community_id, who, now.saturating_add(due.period));
+		}
+
+		MemberCount::<T>::mutate(community_id, |count| *count = count.saturating_add(1));
+		T::OnMemberJoined::on_member_joined(community_id, who);
+	}
+
+	/// Remove the account from the community members, decrease the member count and notify
+	/// `OnMemberLeft`.
+	///
+	/// The caller is responsible for checking that the account is a member.
+	pub fn do_remove_member(community_id: T::CommunityId, who: &T::AccountId) {
+		CommunityMembers::<T>::remove(community_id, who);
+		CommunityRoles::<T>::remove(community_id, who);
+		CitizenshipApplications::<T>::remove(community_id, who);
+		DuesPaidUntil::<T>::remove(community_id, who);
+		Self::do_revoke_citizenship(community_id, who);
+		MemberCount::<T>::mutate(community_id, |count| *count = count.saturating_sub(1));
+		T::OnMemberLeft::on_member_left(community_id, who);
+	}
+
+	/// The block until which a member paid the recurring due of the community.
+	///
+	/// `None` when the community charges no recurring due or the account is exempt from
+	/// it, i.e. the founder and the accounts which are members only through a parent.
+	pub fn dues_paid_until_of(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+	) -> Option<BlockNumberFor<T>> {
+		let dues = CommunityDues::<T>::get(community_id)?;
+		let due = dues.recurring_due?;
+
+		let community = Communities::<T>::get(community_id)?;
+		if community.founder == *who || !CommunityMembers::<T>::contains_key(community_id, who) {
+			return None;
+		}
 
-		Self::deposit_event(Event::CreatedCommunity(community_id, random_value, founder));
+		Some(
+			DuesPaidUntil::<T>::get(community_id, who)
+				.unwrap_or_else(|| dues.since.saturating_add(due.period)),
+		)
+	}
+
+	/// Check whether the member stopped paying the recurring due of the community.
+	///
+	/// Lapsed members can neither vote nor receive badges until they pay again.
+	pub fn is_lapsed(community_id: T::CommunityId, who: &T::AccountId) -> bool {
+		Self::dues_paid_until_of(community_id, who)
+			.map_or(false, |paid_until| paid_until < frame_system::Pallet::<T>::block_number())
+	}
+
+	/// Transfer an amount from the account to the treasury of the community.
+	fn do_pay_to_treasury(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+		amount: DuesAmountOf<T>,
+		periods: u32,
+	) -> DispatchResult {
+		let treasury = Self::treasury_account(community_id);
+
+		match amount {
+			DuesAmount::Native(amount) => {
+				<pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(
+					who,
+					&treasury,
+					amount.saturating_mul(periods.into()),
+					ExistenceRequirement::KeepAlive,
+				)?;
+			},
+			DuesAmount::Asset(asset_id, amount) => {
+				<T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset_id,
+					who,
+					&treasury,
+					amount.saturating_mul(periods.into()),
+					Preservation::Preserve,
+				)?;
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Charge the joining fee of the community, if any, to the joining account.
+	fn do_pay_joining_fee(community_id: T::CommunityId, who: &T::AccountId) -> DispatchResult {
+		if let Some(fee) = CommunityDues::<T>::get(community_id).and_then(|dues| dues.joining_fee) {
+			Self::do_pay_to_treasury(community_id, who, fee, 1)?;
+			Self::deposit_event(Event::JoiningFeePaid(community_id, who.clone()));
+		}
 
 		Ok(())
 	}
 
-	fn get_and_increment_nonce() -> Vec<u8> {
-		let nonce = Nonce::<T>::get();
-		Nonce::<T>::put(nonce.wrapping_add(1));
-		nonce.encode()
+	/// Whether charging `fee` stays within `agreed_fee`, in the same currency.
+	fn is_within_fee(fee: Option<DuesAmountOf<T>>, agreed_fee: Option<DuesAmountOf<T>>) -> bool {
+		match (fee, agreed_fee) {
+			(None, _) => true,
+			(Some(DuesAmount::Native(amount)), Some(DuesAmount::Native(agreed))) => {
+				amount <= agreed
+			},
+			(
+				Some(DuesAmount::Asset(asset_id, amount)),
+				Some(DuesAmount::Asset(agreed_asset_id, agreed)),
+			) => asset_id == agreed_asset_id && amount <= agreed,
+			_ => false,
+		}
+	}
+
+	/// Ensure the origin is the community itself or conforms to `CreateOrigin`.
+	///
+	/// The community origin acts with the rights of the founder, so that the accepted
+	/// proposals of a community can govern it without the founder signing. Returns the signer,
+	/// or the founder for the community origin.
+	pub fn ensure_community_origin(
+		origin: OriginFor<T>,
+		community_id: T::CommunityId,
+	) -> Result<T::AccountId, DispatchError> {
+		match T::CommunityOrigin::try_origin(origin) {
+			Ok(origin_community_id) => {
+				ensure!(origin_community_id == community_id, DispatchError::BadOrigin);
+				Communities::<T>::get(community_id)
+					.map(|community| community.founder)
+					.ok_or_else(|| Error::<T>::CommunityNotExist.into())
+			},
+			Err(origin) => T::CreateOrigin::ensure_origin(origin, &community_id),
+		}
+	}
+
+	/// Derive the reference id of a community from its id.
+	///
+	/// The id is hashed together with a salt, bumped until the reference id is not taken
+	/// by another community.
+	pub fn generate_reference_id(community_id: T::CommunityId) -> Result<[u8; 16], Error<T>> {
+		(0..MAX_REFERENCE_ID_ATTEMPTS)
+			.map(|salt| (REFERENCE_ID_CONTEXT, community_id, salt).using_encoded(Blake2_128::hash))
+			.find(|reference_id| !ReferenceIdToCommunity::<T>::contains_key(reference_id))
+			.ok_or(Error::<T>::ReferenceIdCollision)
+	}
+}
+
+/// Ensure the origin is a community, returning its id.
+pub struct EnsureCommunity<T>(PhantomData<T>);
+impl<T: Config, O: Into<Result<Origin<T>, O>> + From<Origin<T>>> EnsureOrigin<O>
+	for EnsureCommunity<T>
+{
+	type Success = T::CommunityId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().map(|CommunityOrigin(community_id)| community_id)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(CommunityOrigin(T::Helper::community(0))))
+	}
+}
+
+impl<T: Config> CommunityPermissions<T::CommunityId, T::AccountId> for Pallet<T> {
+	fn has_permission(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+		permission: Permissions,
+	) -> bool {
+		match Communities::<T>::get(community_id) {
+			Some(community) if community.founder == *who => true,
+			Some(Community { community_type: Some(CommunityType::State(state)), .. })
+				if state.government.contains(who) =>
+			{
+				true
+			},
+			Some(_) => {
+				Self::is_member(community_id, who)
+					&& CommunityRoles::<T>::get(community_id, who)
+						.map_or(false, |role| role.permissions.contains(permission))
+			},
+			None => false,
+		}
 	}
 }
@@ -4,7 +4,7 @@ use log;
 use sp_runtime::Saturating;
 
 pub mod v8 {
-	use frame_support::{pallet_prelude::*, weights::Weight};
+	use frame_support::{pallet_prelude::*, storage_alias, weights::Weight};
 
 	use super::*;
 	#[derive(Decode)]
@@ -14,13 +14,14 @@ pub mod v8 {
 		DescriptionLimit: Get<u32>,
 		TagLimit: Get<u32>,
 		ColorLimit: Get<u32>,
+		Articles,
 	> {
 		pub founder: AccountId,
 		pub logo: Option<Vec<u8>>,
 		pub name: BoundedVec<u8, NameLimit>,
 		pub description: BoundedVec<u8, DescriptionLimit>,
 		pub members: Vec<AccountId>,
-		pub metadata: Option<OldCommunityMetaData<AccountId>>,
+		pub metadata: Option<OldCommunityMetaData<AccountId, Articles>>,
 		pub reference_id: [u8; 16],
 		pub category: Category,
 		pub tag: BoundedVec<u8, TagLimit>,
@@ -29,8 +30,8 @@ pub mod v8 {
 	}
 
 	#[derive(Decode)]
-	pub struct OldCommunityMetaData<AccountId> {
-		pub community_type: Option<CommunityType<AccountId>>,
+	pub struct OldCommunityMetaData<AccountId, Articles> {
+		pub community_type: Option<CommunityType<AccountId, Articles>>,
 		pub customs: Option<Vec<Vec<u8>>>,
 		pub languages: Option<Vec<Vec<u8>>>,
 		pub norms: Option<Vec<Vec<u8>>>,
@@ -40,13 +41,31 @@ pub mod v8 {
 		pub values: Option<Vec<Vec<u8>>>,
 	}
 
+	/// The communities in the format of version 8, still holding their members.
+	#[storage_alias]
+	type Communities<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::CommunityId,
+		v9::OldCommunity<
+			<T as frame_system::Config>::AccountId,
+			<T as Config>::NameLimit,
+			<T as Config>::DescriptionLimit,
+			<T as Config>::TagLimit,
+			<T as Config>::ColorLimit,
+			<T as Config>::StringLimit,
+			<T as Config>::LogoLimit,
+			ArticlesOf<T>,
+		>,
+		OptionQuery,
+	>;
+
 	pub struct MigrateToV8<T>(sp_std::marker::PhantomData<T>);
 	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
 		fn on_runtime_upgrade() -> Weight {
-			let current_version = Pallet::<T>::current_storage_version();
 			let onchain_version = Pallet::<T>::on_chain_storage_version();
 
-			if onchain_version == 7 && current_version == 8 {
+			if onchain_version == 7 {
 				let mut translated = 0u64;
 				Communities::<T>::translate::<
 					OldCommunity<
@@ -55,12 +74,13 @@ pub mod v8 {
 						T::DescriptionLimit,
 						T::TagLimit,
 						T::ColorLimit,
+						ArticlesOf<T>,
 					>,
 					_,
 				>(|_key, old_value| {
 					translated.saturating_inc();
 
-					let mut community_type: Option<CommunityType<T::AccountId>> = None;
+					let mut community_type: Option<CommunityTypeOf<T>> = None;
 					let mut meta_data: Option<CommunityMetaData<T::StringLimit>> = None;
 					if let Some(meta) = old_value.metadata {
 						community_type = meta.community_type;
@@ -223,7 +243,7 @@ pub mod v8 {
 							Default::default()
 						};
 
-					Some(Community {
+					Some(v9::OldCommunity {
 						founder: old_value.founder,
 						logo: bounded_logo,
 						name: old_value.name,
@@ -238,12 +258,11 @@ pub mod v8 {
 						community_type,
 					})
 				});
-				current_version.put::<Pallet<T>>();
+				StorageVersion::new(8).put::<Pallet<T>>();
 				log::info!(
 					target: LOG_TARGET,
-					"Community Upgraded {} pools, storage to version {:?}",
-					translated,
-					current_version
+					"Community Upgraded {} pools, storage to version 8",
+					translated
 				);
 				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
 			} else {
@@ -258,10 +277,142 @@ pub mod v8 {
 		#[cfg(feature = "try-runtime")]
 		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
 			frame_support::ensure!(
-				Pallet::<T>::on_chain_storage_version() == 1,
+				Pallet::<T>::on_chain_storage_version() == 7,
 				"must upgrade linearly"
 			);
-			let prev_count = Communities::<T>::iter().count();
+			let prev_count = Communities::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), &'static str> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = Communities::<T>::iter_keys().count() as u32;
+			assert_eq!(
+				prev_count, post_count,
+				"the community count before and after the migration should be the same"
+			);
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 8, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v9 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	use super::*;
+	#[derive(Encode, Decode)]
+	pub struct OldCommunity<
+		AccountId,
+		NameLimit: Get<u32>,
+		DescriptionLimit: Get<u32>,
+		TagLimit: Get<u32>,
+		ColorLimit: Get<u32>,
+		StringLimit: Get<u32>,
+		LogoLimit: Get<u32>,
+		Articles,
+	> {
+		pub founder: AccountId,
+		pub logo: BoundedVec<u8, LogoLimit>,
+		pub name: BoundedVec<u8, NameLimit>,
+		pub description: BoundedVec<u8, DescriptionLimit>,
+		pub members: Vec<AccountId>,
+		pub metadata: Option<CommunityMetaData<StringLimit>>,
+		pub reference_id: [u8; 16],
+		pub category: Category,
+		pub tag: BoundedVec<u8, TagLimit>,
+		pub primary_color: BoundedVec<u8, ColorLimit>,
+		pub secondary_color: BoundedVec<u8, ColorLimit>,
+		pub community_type: Option<CommunityType<AccountId, Articles>>,
+	}
+
+	pub struct MigrateToV9<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 8 {
+				let mut translated = 0u64;
+				let mut migrated_members = 0u64;
+				let joined_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+
+				Communities::<T>::translate::<
+					OldCommunity<
+						T::AccountId,
+						T::NameLimit,
+						T::DescriptionLimit,
+						T::TagLimit,
+						T::ColorLimit,
+						T::StringLimit,
+						T::LogoLimit,
+						ArticlesOf<T>,
+					>,
+					_,
+				>(|community_id, old_value| {
+					translated.saturating_inc();
+
+					let mut member_count = 0u32;
+					for member in old_value.members {
+						if CommunityMembers::<T>::contains_key(community_id, &member) {
+							continue;
+						}
+						CommunityMembers::<T>::insert(
+							community_id,
+							member,
+							MemberInfo { joined_at },
+						);
+						member_count.saturating_inc();
+						migrated_members.saturating_inc();
+					}
+					MemberCount::<T>::insert(community_id, member_count);
+
+					Some(Community {
+						founder: old_value.founder,
+						logo: old_value.logo,
+						name: old_value.name,
+						description: old_value.description,
+						metadata: old_value.metadata,
+						reference_id: old_value.reference_id,
+						category: old_value.category,
+						tag: old_value.tag,
+						primary_color: old_value.primary_color,
+						secondary_color: old_value.secondary_color,
+						community_type: old_value.community_type,
+					})
+				});
+				StorageVersion::new(9).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Community Upgraded {} pools with {} members, storage to version 9",
+					translated,
+					migrated_members
+				);
+				T::DbWeight::get().reads_writes(
+					translated + migrated_members + 1,
+					translated * 2 + migrated_members + 1,
+				)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Community Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 8,
+				"must upgrade linearly"
+			);
+			let prev_count = Communities::<T>::iter_keys().count();
 			Ok((prev_count as u32).encode())
 		}
 
@@ -276,48 +427,566 @@ pub mod v8 {
 				"the community count before and after the migration should be the same"
 			);
 
-			let current_version = Pallet::<T>::current_storage_version();
+			for community_id in Communities::<T>::iter_keys() {
+				assert_eq!(
+					MemberCount::<T>::get(community_id),
+					CommunityMembers::<T>::iter_prefix(community_id).count() as u32,
+					"the member count should match the migrated members"
+				);
+			}
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 9, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v10 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	pub struct MigrateToV10<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV10<T> {
+		fn on_runtime_upgrade() -> Weight {
 			let onchain_version = Pallet::<T>::on_chain_storage_version();
 
-			frame_support::ensure!(current_version == 2, "must_upgrade");
+			if onchain_version == 9 {
+				let mut indexed = 0u64;
+				let mut skipped = 0u64;
+
+				for (community_id, community) in Communities::<T>::iter() {
+					let normalized_name = match Pallet::<T>::normalize_name(&community.name) {
+						Ok(normalized_name) => normalized_name,
+						Err(_) => {
+							log::error!(
+								target: LOG_TARGET,
+								"Failed to normalize the name of community {:?}",
+								community_id
+							);
+							skipped.saturating_inc();
+							continue;
+						},
+					};
+
+					if CommunityNameIndex::<T>::contains_key(&normalized_name) {
+						log::error!(
+							target: LOG_TARGET,
+							"Duplicate name found for community {:?}",
+							community_id
+						);
+						skipped.saturating_inc();
+						continue;
+					}
+
+					CommunityNameIndex::<T>::insert(normalized_name, community_id);
+					indexed.saturating_inc();
+				}
+
+				StorageVersion::new(10).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Community indexed {} names, skipped {}, storage to version 10",
+					indexed,
+					skipped
+				);
+				T::DbWeight::get().reads_writes(indexed * 2 + skipped * 2 + 1, indexed + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Community Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 9,
+				"must upgrade linearly"
+			);
+			// Only the first community of a duplicate name is indexed.
+			let names = Communities::<T>::iter_values()
+				.filter_map(|community| Pallet::<T>::normalize_name(&community.name).ok())
+				.collect::<sp_std::collections::btree_set::BTreeSet<_>>();
+			Ok((names.len() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(name_count: Vec<u8>) -> Result<(), &'static str> {
+			let name_count: u32 = Decode::decode(&mut name_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = CommunityNameIndex::<T>::iter_keys().count() as u32;
 			assert_eq!(
-				current_version, onchain_version,
-				"after migration, the current_version and onchain_version should be the same"
+				name_count, post_count,
+				"every distinct community name should have an entry in the name index"
 			);
 
+			for (normalized_name, community_id) in CommunityNameIndex::<T>::iter() {
+				let community =
+					Communities::<T>::get(community_id).ok_or("indexed community not found")?;
+				frame_support::ensure!(
+					Pallet::<T>::normalize_name(&community.name).ok() == Some(normalized_name),
+					"indexed name does not resolve to its community"
+				);
+			}
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 10, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v11 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Reserve the founder bond of the existing communities.
+	///
+	/// Founders without enough free balance keep their community without a bond.
+	pub struct MigrateToV11<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV11<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 10 {
+				let required_balance = RequiredFounderBalance::<T>::get();
+				let mut reserved = 0u64;
+				let mut skipped = 0u64;
+
+				for (community_id, community) in Communities::<T>::iter() {
+					if FounderBonds::<T>::contains_key(community_id) {
+						continue;
+					}
+
+					match Pallet::<T>::do_reserve_founder_bond(
+						community_id,
+						&community.founder,
+						required_balance,
+					) {
+						Ok(()) => reserved.saturating_inc(),
+						Err(_) => {
+							log::warn!(
+								target: LOG_TARGET,
+								"Failed to reserve the founder bond of community {:?}",
+								community_id
+							);
+							skipped.saturating_inc();
+						},
+					}
+				}
+
+				StorageVersion::new(11).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Community reserved {} founder bonds, skipped {}, storage to version 11",
+					reserved,
+					skipped
+				);
+				T::DbWeight::get().reads_writes((reserved + skipped) * 3 + 2, reserved * 2 + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Community Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 10,
+				"must upgrade linearly"
+			);
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 11, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v12 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Move the citizens listed in the existing States into `Citizens`.
+	pub struct MigrateToV12<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV12<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 11 {
+				let now = frame_system::Pallet::<T>::block_number();
+				let mut communities = 0u64;
+				let mut states = Vec::new();
+
+				for (community_id, community) in Communities::<T>::iter() {
+					communities.saturating_inc();
+					if let Some(CommunityType::State(state)) = &community.community_type {
+						if !state.citizens.is_empty() {
+							states.push(community_id);
+						}
+					}
+				}
+
+				let mut citizens = 0u64;
+				for community_id in states.iter() {
+					Communities::<T>::mutate(community_id, |maybe_community| {
+						let state = match maybe_community
+							.as_mut()
+							.and_then(|community| community.community_type.as_mut())
+						{
+							Some(CommunityType::State(state)) => state,
+							_ => return,
+						};
+
+						for citizen in sp_std::mem::take(&mut state.citizens) {
+							// No membership hooks run during the upgrade.
+							if !CommunityMembers::<T>::contains_key(community_id, &citizen) {
+								CommunityMembers::<T>::insert(
+									community_id,
+									&citizen,
+									MemberInfo { joined_at: now },
+								);
+								MemberCount::<T>::mutate(community_id, |count| {
+									*count = count.saturating_add(1)
+								});
+							}
+							Citizens::<T>::insert(community_id, &citizen, now);
+							citizens.saturating_inc();
+						}
+					});
+				}
+
+				StorageVersion::new(12).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Community moved {} citizens of {} States, storage to version 12",
+					citizens,
+					states.len()
+				);
+				let states = states.len() as u64;
+				T::DbWeight::get().reads_writes(
+					communities + states + citizens * 2 + 1,
+					states + citizens * 3 + 1,
+				)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Community Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 11,
+				"must upgrade linearly"
+			);
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 12, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v13 {
+	use frame_support::{pallet_prelude::*, storage_alias, weights::Weight};
+
+	use super::*;
+
+	/// The nonce formerly mixed into the random reference ids.
+	#[storage_alias]
+	type Nonce<T: Config> = StorageValue<Pallet<T>, u64, ValueQuery>;
+
+	/// Index the reference ids of the existing communities in `ReferenceIdToCommunity`.
+	///
+	/// The existing reference ids are kept, only the new communities get a derived one.
+	pub struct MigrateToV13<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV13<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 12 {
+				let mut indexed = 0u64;
+				let mut skipped = 0u64;
+
+				for (community_id, community) in Communities::<T>::iter() {
+					if ReferenceIdToCommunity::<T>::contains_key(community.reference_id) {
+						log::error!(
+							target: LOG_TARGET,
+							"Duplicate reference id found for community {:?}",
+							community_id
+						);
+						skipped.saturating_inc();
+						continue;
+					}
+
+					ReferenceIdToCommunity::<T>::insert(community.reference_id, community_id);
+					indexed.saturating_inc();
+				}
+
+				Nonce::<T>::kill();
+
+				StorageVersion::new(13).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Community indexed {} reference ids, skipped {}, storage to version 13",
+					indexed,
+					skipped
+				);
+				T::DbWeight::get().reads_writes((indexed + skipped) * 2 + 1, indexed + 2)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Community Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 12,
+				"must upgrade linearly"
+			);
+			let reference_ids: Vec<(T::CommunityId, [u8; 16])> = Communities::<T>::iter()
+				.map(|(community_id, community)| (community_id, community.reference_id))
+				.collect();
+			Ok(reference_ids.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(reference_ids: Vec<u8>) -> Result<(), &'static str> {
+			let reference_ids: Vec<(T::CommunityId, [u8; 16])> = Decode::decode(
+				&mut reference_ids.as_slice(),
+			)
+			.expect("the state parameter should be something that was generated by pre_upgrade");
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 13, "must_upgrade");
+
+			for (community_id, reference_id) in reference_ids {
+				let community =
+					Communities::<T>::get(community_id).ok_or("migrated community not found")?;
+				frame_support::ensure!(
+					community.reference_id == reference_id,
+					"reference id changed by the migration"
+				);
+				frame_support::ensure!(
+					ReferenceIdToCommunity::<T>::contains_key(reference_id),
+					"reference id not indexed"
+				);
+			}
+
 			Ok(())
 		}
 	}
 }
 
 #[cfg(test)]
-#[cfg(feature = "try-runtime")]
 mod test {
 	use super::*;
 	use crate::mock::{Test as T, *};
-	use frame_support::pallet_prelude::StorageVersion;
+	use frame_support::{pallet_prelude::StorageVersion, storage::unhashed};
 
 	#[test]
-	fn migration_works() {
+	fn migration_to_v9_works() {
 		new_test_ext().execute_with(|| {
-			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0);
+			StorageVersion::new(8).put::<Pallet<T>>();
+
+			let old_community: v9::OldCommunity<
+				u64,
+				<T as Config>::NameLimit,
+				<T as Config>::DescriptionLimit,
+				<T as Config>::TagLimit,
+				<T as Config>::ColorLimit,
+				<T as Config>::StringLimit,
+				<T as Config>::LogoLimit,
+				ArticlesOf<T>,
+			> = v9::OldCommunity {
+				founder: 1u64,
+				logo: Default::default(),
+				name: "Jur".as_bytes().to_vec().try_into().unwrap(),
+				description: Default::default(),
+				members: vec![1, 2, 3, 2],
+				metadata: Some(get_metadata()),
+				reference_id: [0; 16],
+				category: Category::Public,
+				tag: Default::default(),
+				primary_color: Default::default(),
+				secondary_color: Default::default(),
+				community_type: Some(CommunityType::Nation),
+			};
+			unhashed::put(&Communities::<T>::hashed_key_for(1), &old_community);
+
+			let _w = v9::MigrateToV9::<T>::on_runtime_upgrade();
 
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 9);
+			assert_eq!(Communities::<T>::get(1).unwrap().name.to_vec(), "Jur".as_bytes().to_vec());
+			assert_eq!(MemberCount::<T>::get(1), 3);
+			assert!(CommunityMembers::<T>::contains_key(1, 1));
+			assert!(CommunityMembers::<T>::contains_key(1, 2));
+			assert!(CommunityMembers::<T>::contains_key(1, 3));
+			assert!(!CommunityMembers::<T>::contains_key(1, 4));
+		});
+	}
+
+	#[test]
+	fn migration_to_v10_works() {
+		new_test_ext().execute_with(|| {
+			set_balance(10000000000000000000);
+			set_required_balance_to_create_community(10000000000000000000);
 			create_community();
-			setup_blocks(5);
+			let _ = CommunityNameIndex::<T>::clear(u32::MAX, None);
+			StorageVersion::new(9).put::<Pallet<T>>();
+
+			assert_eq!(Pallet::<T>::community_id_by_name("Jur".as_bytes()), None);
+
+			let _w = v10::MigrateToV10::<T>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 10);
+			assert_eq!(Pallet::<T>::community_id_by_name("Jur".as_bytes()), Some(1));
+		});
+	}
+
+	#[test]
+	#[cfg(feature = "try-runtime")]
+	fn migration_to_v10_skips_duplicate_names() {
+		new_test_ext().execute_with(|| {
+			set_balance(10000000000000000000);
 			create_community();
+			Community::create_community(
+				RuntimeOrigin::signed(2),
+				None,
+				"Jur2".into(),
+				None,
+				None,
+				None,
+				Category::Public,
+				None,
+				None,
+				None,
+				None,
+			)
+			.unwrap();
+			// Names were not unique before the index.
+			Communities::<T>::mutate(2, |community| {
+				community.as_mut().unwrap().name = "J ur".as_bytes().to_vec().try_into().unwrap();
+			});
+			let _ = CommunityNameIndex::<T>::clear(u32::MAX, None);
+			StorageVersion::new(9).put::<Pallet<T>>();
 
-			assert_eq!(Communities::<T>::iter_values().count() as u32, 2);
+			let state = v10::MigrateToV10::<T>::pre_upgrade().unwrap();
+			let _w = v10::MigrateToV10::<T>::on_runtime_upgrade();
+			v10::MigrateToV10::<T>::post_upgrade(state).unwrap();
 
-			let state = v3::MigrateToV3::<T>::pre_upgrade().unwrap();
-			let _w = v3::MigrateToV3::<T>::on_runtime_upgrade();
-			v3::MigrateToV3::<T>::post_upgrade(state).unwrap();
+			assert_eq!(CommunityNameIndex::<T>::iter_keys().count(), 1);
+			assert!(Pallet::<T>::community_id_by_name("Jur".as_bytes()).is_some());
+		});
+	}
+
+	#[test]
+	fn migration_to_v11_works() {
+		new_test_ext().execute_with(|| {
+			set_balance(10000000000000000000);
+			create_community();
+			Community::create_community(
+				RuntimeOrigin::signed(2),
+				None,
+				"Jur2".into(),
+				None,
+				None,
+				None,
+				Category::Public,
+				None,
+				None,
+				None,
+				None,
+			)
+			.unwrap();
+			set_required_balance_to_create_community(1000000000000000000);
+			StorageVersion::new(10).put::<Pallet<T>>();
 
-			assert_eq!(Communities::<T>::iter_values().count() as u32, 2);
-			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
-			assert_ne!(
-				Some(Communities::<Test>::get(1).unwrap().reference_id),
-				Some(Communities::<Test>::get(0).unwrap().reference_id)
+			let _w = v11::MigrateToV11::<T>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 11);
+			assert_eq!(FounderBonds::<T>::get(1), Some(1000000000000000000));
+			assert_eq!(Balances::reserved_balance(1), 1000000000000000000);
+			assert_eq!(FounderBonds::<T>::get(2), None);
+		});
+	}
+
+	#[test]
+	fn migration_to_v12_works() {
+		new_test_ext().execute_with(|| {
+			set_balance(10000000000000000000);
+			create_community();
+			Communities::<T>::mutate(1, |community| {
+				community.as_mut().unwrap().community_type = Some(CommunityType::State(State {
+					constitution: Default::default(),
+					government: vec![],
+					citizens: vec![2, 3],
+				}));
+			});
+			StorageVersion::new(11).put::<Pallet<T>>();
+
+			let _w = v12::MigrateToV12::<T>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 12);
+			assert!(Citizens::<T>::contains_key(1, 2));
+			assert!(Citizens::<T>::contains_key(1, 3));
+			assert!(CommunityMembers::<T>::contains_key(1, 3));
+			assert_eq!(MemberCount::<T>::get(1), 3);
+			assert_eq!(
+				Communities::<T>::get(1).unwrap().community_type,
+				Some(CommunityType::State(State {
+					constitution: Default::default(),
+					government: vec![],
+					citizens: vec![],
+				}))
 			);
 		});
 	}
+	#[test]
+	fn migration_to_v13_works() {
+		new_test_ext().execute_with(|| {
+			set_balance(10000000000000000000);
+			create_community();
+			let reference_id = Communities::<T>::get(1).unwrap().reference_id;
+
+			// Communities created before carry a random reference id and no index.
+			Communities::<T>::mutate(1, |community| {
+				community.as_mut().unwrap().reference_id = [7; 16];
+			});
+			ReferenceIdToCommunity::<T>::remove(reference_id);
+			StorageVersion::new(12).put::<Pallet<T>>();
+
+			let _w = v13::MigrateToV13::<T>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 13);
+			assert_eq!(Communities::<T>::get(1).unwrap().reference_id, [7; 16]);
+			assert_eq!(ReferenceIdToCommunity::<T>::get([7; 16]), Some(1));
+			assert_eq!(Pallet::<T>::community_by_reference_id([7; 16]), Some(1));
+		});
+	}
 }
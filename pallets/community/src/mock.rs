@@ -1,14 +1,14 @@
 use crate as pallet_community;
 use crate::{
-	Category, CommunityMetaData, CommunityType, Customs, Languages, Religions, Territories,
-	Traditions, Values,
+	Category, CommunityMembers, CommunityMetaData, CommunityType, Customs, Languages,
+	MembershipGate, NextCommunityId, Religions, State, Territories, Traditions, Values,
 };
 use frame_support::traits::fungible::Mutate;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, Hooks},
+	PalletId,
 };
-use frame_support_test::TestRandomness;
 use frame_system as system;
 use primitives::Balance;
 use sp_core::H256;
@@ -27,6 +27,7 @@ frame_support::construct_runtime!(
 		Community: pallet_community,
 		Whitelist: pallet_whitelist,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 	}
 );
 
@@ -66,6 +67,21 @@ parameter_types! {
 	pub const MaxLocks: u32 = 50;
 }
 
+parameter_types! {
+	pub static GateHolders: Vec<(u32, u64)> = vec![];
+	pub static MetadataDepositPerByte: Balance = 0;
+}
+
+/// Membership gate where the requirement is a collection id held by the accounts in `GateHolders`.
+pub struct TestGate;
+impl MembershipGate<u64> for TestGate {
+	type Requirement = u32;
+
+	fn is_eligible(requirement: &u32, who: &u64) -> bool {
+		GateHolders::get().contains(&(*requirement, *who))
+	}
+}
+
 impl pallet_community::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type CommunityId = u32;
@@ -75,12 +91,31 @@ impl pallet_community::Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type WeightInfo = ();
-	type MyRandomness = TestRandomness<Self>;
 	type TagLimit = ConstU32<50>;
 	type ColorLimit = ConstU32<7>;
 	type CommunityLimit = ConstU32<3>;
 	type StringLimit = ConstU32<250>;
 	type LogoLimit = ConstU32<60>;
+	type MaxArticles = ConstU32<10>;
+	type ArticleLimit = ConstU32<256>;
+	type JoinRequestTtl = ConstU64<10>;
+	type MaxJoinRequestsPerBlock = ConstU32<10>;
+	type InvitationTtl = ConstU64<10>;
+	type MaxInvitationsPerBlock = ConstU32<10>;
+	type MembershipGate = TestGate;
+	type OnCommunityDissolved = ();
+	type DissolveCleanupLimit = ConstU32<10>;
+	type SlashOrigin = frame_system::EnsureRoot<u64>;
+	type OnSlash = ();
+	type OnCitizenshipRevoked = ();
+	type PalletId = CommunityPalletId;
+	type Assets = Assets;
+	type MaxHierarchyDepth = ConstU32<4>;
+	type MaxDescendants = ConstU32<10>;
+	type OnMemberJoined = ();
+	type OnMemberLeft = ();
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type CommunityOrigin = pallet_community::EnsureCommunity<Test>;
 }
 
 impl pallet_whitelist::Config for Test {
@@ -104,6 +139,31 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ();
 }
 
+parameter_types! {
+	pub const CommunityPalletId: PalletId = PalletId(*b"jur/cmty");
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities = system::GenesisConfig::<Test>::default()
@@ -127,6 +187,14 @@ pub fn setup_blocks(blocks: u64) {
 	}
 }
 
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+		Community::on_initialize(System::block_number());
+	}
+}
+
 pub fn get_metadata() -> CommunityMetaData<ConstU32<250>> {
 	let custom_one: Vec<u8> =
 		"in public transport young people should leave the seat to elderly or pregnant women"
@@ -176,6 +244,7 @@ pub fn get_metadata() -> CommunityMetaData<ConstU32<250>> {
 }
 
 pub fn create_community() {
+	let community_id = NextCommunityId::<Test>::get().unwrap_or(1);
 	Community::create_community(
 		RuntimeOrigin::signed(1),
 		// hash of IPFS path of dummy logo
@@ -194,6 +263,49 @@ pub fn create_community() {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	accept_invitations(community_id, &[2]);
+}
+
+pub fn create_nft_gated_community() {
+	Community::create_community(
+		RuntimeOrigin::signed(1),
+		None,
+		"Jur Gated".into(),
+		None,
+		Some(vec![1]),
+		None,
+		Category::NFTGated,
+		None,
+		None,
+		None,
+		Some(CommunityType::Community),
+	)
+	.unwrap();
+}
+
+pub fn create_state() {
+	let community_id = NextCommunityId::<Test>::get().unwrap_or(1);
+	Community::create_community(
+		RuntimeOrigin::signed(1),
+		None,
+		"Jur State".into(),
+		None,
+		Some(vec![1, 2, 3]),
+		None,
+		Category::Public,
+		None,
+		None,
+		None,
+		Some(CommunityType::State(State {
+			constitution: vec![b"Article one".to_vec().try_into().unwrap()]
+				.try_into()
+				.unwrap(),
+			government: vec![2],
+			citizens: vec![3],
+		})),
+	)
+	.unwrap();
+	accept_invitations(community_id, &[2]);
 }
 
 pub fn set_balance(amount: u128) {
@@ -203,3 +315,16 @@ pub fn set_balance(amount: u128) {
 pub fn set_required_balance_to_create_community(amount: u128) {
 	Community::update_required_founder_balance(RuntimeOrigin::root(), amount).unwrap();
 }
+
+/// Accept the invitations sent to the members listed at the creation of a community.
+pub fn accept_invitations(community_id: u32, members: &[u64]) {
+	for member in members {
+		Community::accept_invitation(RuntimeOrigin::signed(*member), community_id).unwrap();
+	}
+}
+
+pub fn members_of(community_id: u32) -> Vec<u64> {
+	let mut members: Vec<u64> = CommunityMembers::<Test>::iter_key_prefix(community_id).collect();
+	members.sort();
+	members
+}
@@ -1,16 +1,33 @@
 use crate::types::Category;
 use crate::{
 	mock::*,
-	types::{CommunityMetaData, CommunityType},
-	Communities, Customs, Error, Languages, Religions, Traditions, Values,
+	types::{
+		Ban, CommunityMetaData, CommunityOrigin, CommunityType, ConstitutionAmendment, DuesAmount,
+		Invitation, Invitee, ParentLink, Permissions, RecurringDue, State,
+	},
+	Bans, Citizens, CitizenshipApplications, Communities, CommunityAccount, CommunityDues,
+	CommunityPermissions, CommunityRoles, Customs, DescendantCount, DissolvedCommunities,
+	DuesPaidUntil, Error, Event, FounderBonds, GateRequirements, InvitationExpiry, Invitations,
+	JoinRequestExpiry, JoinRequests, Languages, MemberCount, MetadataDeposits,
+	PendingFounderTransfers, Religions, Traditions, TreasuryClaimants, TreasuryMetadataDeposits,
+	Values,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::Mutate, Hooks},
+	weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
 };
-use frame_support::{assert_noop, assert_ok};
 
 #[test]
 fn create_community_works() {
 	new_test_ext().execute_with(|| {
 		assert!(!Communities::<Test>::contains_key(1));
-		set_balance(10000000000000000000);
+		set_balance(20000000000000000000);
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 		assert!(Communities::<Test>::contains_key(1));
@@ -118,11 +135,24 @@ fn create_community_not_works_with_duplicate_name() {
 }
 
 #[test]
-fn founder_with_more_communities_not_allowed() {
+fn community_id_by_name_works() {
 	new_test_ext().execute_with(|| {
 		set_balance(10000000000000000000);
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
+
+		assert_eq!(Community::community_id_by_name("Jur".as_bytes()), Some(1));
+		assert_eq!(Community::community_id_by_name("  j U r ".as_bytes()), Some(1));
+		assert_eq!(Community::community_id_by_name("Jur1".as_bytes()), None);
+	});
+}
+
+#[test]
+fn founder_with_more_communities_not_allowed() {
+	new_test_ext().execute_with(|| {
+		set_balance(40000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
 		Community::create_community(
 			RuntimeOrigin::signed(1),
 			// hash of IPFS path of dummy logo
@@ -208,6 +238,85 @@ fn create_community_works_only_with_name() {
 	});
 }
 
+#[test]
+fn create_community_invites_listed_members() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		Community::create_community(
+			RuntimeOrigin::signed(1),
+			None,
+			"Jur".into(),
+			None,
+			Some(vec![1, 2, 3]),
+			None,
+			Category::Public,
+			None,
+			None,
+			None,
+			Some(CommunityType::Nation),
+		)
+		.unwrap();
+
+		// Only the founder joins without consent.
+		assert_eq!(members_of(1), vec![1]);
+		assert!(Invitations::<Test>::contains_key(1, Invitee::Account(2)));
+		assert!(Invitations::<Test>::contains_key(1, Invitee::Account(3)));
+		System::assert_has_event(Event::<Test>::InvitedMember(1, 2).into());
+
+		assert_ok!(Community::accept_invitation(RuntimeOrigin::signed(2), 1));
+		assert_ok!(Community::decline_invitation(RuntimeOrigin::signed(3), 1));
+		assert_eq!(members_of(1), vec![1, 2]);
+	});
+}
+
+#[test]
+fn create_community_not_works_with_too_many_members() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+
+		// `MaxInvitationsPerBlock` allows ten accounts.
+		assert_noop!(
+			Community::create_community(
+				RuntimeOrigin::signed(1),
+				None,
+				"Jur".into(),
+				None,
+				Some((2..=12).collect()),
+				None,
+				Category::Public,
+				None,
+				None,
+				None,
+				Some(CommunityType::Nation),
+			),
+			Error::<Test>::TooManyMembers
+		);
+
+		assert_noop!(
+			Community::create_community(
+				RuntimeOrigin::signed(1),
+				None,
+				"Jur".into(),
+				None,
+				None,
+				None,
+				Category::Public,
+				None,
+				None,
+				None,
+				Some(CommunityType::State(State {
+					constitution: Default::default(),
+					government: vec![],
+					citizens: (2..=12).collect(),
+				})),
+			),
+			Error::<Test>::TooManyMembers
+		);
+	});
+}
+
 #[test]
 fn create_community_not_works_with_invalid_color() {
 	new_test_ext().execute_with(|| {
@@ -313,42 +422,161 @@ fn update_community_works() {
 }
 
 #[test]
-fn accept_members_works() {
+fn invite_member_works() {
 	new_test_ext().execute_with(|| {
-		assert!(!Communities::<Test>::contains_key(0));
 		set_balance(10000000000000000000);
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		let new_members = vec![3, 4];
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
-		assert_ok!(Community::accept_members(RuntimeOrigin::signed(1), 1, new_members));
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2, 3, 4]);
+		assert_ok!(Community::invite_member(RuntimeOrigin::signed(1), 1, 3));
+		System::assert_last_event(Event::<Test>::InvitedMember(1, 3).into());
+		assert_eq!(
+			Invitations::<Test>::get(1, Invitee::Account(3)),
+			Some(Invitation { invited_by: 1, expires_at: 11 })
+		);
+		// the invitee is not a member until the invitation is accepted.
+		assert_eq!(members_of(1), vec![1, 2]);
+
+		assert_ok!(Community::accept_invitation(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(Event::<Test>::AcceptedInvitation(1, 3).into());
+		assert_eq!(members_of(1), vec![1, 2, 3]);
+		assert_eq!(MemberCount::<Test>::get(1), 3);
+		assert!(!Invitations::<Test>::contains_key(1, Invitee::Account(3)));
+		assert!(InvitationExpiry::<Test>::get(11).is_empty());
 	});
 }
 
 #[test]
-fn accept_members_not_works_for_invalid_input() {
+fn invite_member_not_works_for_invalid_input() {
 	new_test_ext().execute_with(|| {
-		assert!(!Communities::<Test>::contains_key(0));
+		assert_noop!(
+			Community::invite_member(RuntimeOrigin::signed(1), 1, 3),
+			Error::<Test>::CommunityNotExist
+		);
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
 
-		let new_members = vec![3, 4];
+		assert_noop!(
+			Community::invite_member(RuntimeOrigin::signed(2), 1, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Community::invite_member(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::AlreadyMember
+		);
 
+		assert_ok!(Community::invite_member(RuntimeOrigin::signed(1), 1, 3));
 		assert_noop!(
-			Community::accept_members(RuntimeOrigin::signed(1), 1, new_members.clone()),
-			Error::<Test>::CommunityNotExist
+			Community::invite_member(RuntimeOrigin::signed(1), 1, 3),
+			Error::<Test>::InvitationAlreadyExist
+		);
+		assert_noop!(
+			Community::accept_invitation(RuntimeOrigin::signed(4), 1),
+			Error::<Test>::InvitationNotExist
+		);
+	});
+}
+
+#[test]
+fn decline_invitation_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_ok!(Community::invite_member(RuntimeOrigin::signed(1), 1, 3));
+		assert_ok!(Community::decline_invitation(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(Event::<Test>::DeclinedInvitation(1, 3).into());
+
+		assert!(!Invitations::<Test>::contains_key(1, Invitee::Account(3)));
+		assert_eq!(members_of(1), vec![1, 2]);
+
+		assert_noop!(
+			Community::accept_invitation(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::InvitationNotExist
+		);
+	});
+}
+
+#[test]
+fn revoke_invitation_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_ok!(Community::invite_member(RuntimeOrigin::signed(1), 1, 3));
+
+		assert_noop!(
+			Community::revoke_invitation(RuntimeOrigin::signed(2), 1, Invitee::Account(3)),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::revoke_invitation(RuntimeOrigin::signed(1), 1, Invitee::Account(3)));
+		System::assert_last_event(Event::<Test>::RevokedInvitation(1, Invitee::Account(3)).into());
+
+		assert_noop!(
+			Community::accept_invitation(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::InvitationNotExist
+		);
+	});
+}
+
+#[test]
+fn invitation_expires() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_ok!(Community::invite_member(RuntimeOrigin::signed(1), 1, 3));
+
+		run_to_block(11);
+		System::assert_last_event(Event::<Test>::InvitationExpired(1, Invitee::Account(3)).into());
+		assert!(!Invitations::<Test>::contains_key(1, Invitee::Account(3)));
+
+		assert_noop!(
+			Community::accept_invitation(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::InvitationNotExist
 		);
+	});
+}
+
+#[test]
+fn redeem_invite_code_works() {
+	new_test_ext().execute_with(|| {
 		set_balance(10000000000000000000);
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		let code: Vec<u8> = "jur-invite".into();
+		let code_hash = BlakeTwo256::hash(&code);
 
 		assert_noop!(
-			Community::accept_members(RuntimeOrigin::signed(2), 1, new_members),
+			Community::create_invite_code(RuntimeOrigin::signed(2), 1, code_hash),
 			Error::<Test>::NoPermission
 		);
+
+		assert_ok!(Community::create_invite_code(RuntimeOrigin::signed(1), 1, code_hash));
+		System::assert_last_event(Event::<Test>::CreatedInviteCode(1, code_hash).into());
+
+		assert_noop!(
+			Community::redeem_invite_code(RuntimeOrigin::signed(3), 1, "wrong".into()),
+			Error::<Test>::InvitationNotExist
+		);
+
+		assert_ok!(Community::redeem_invite_code(RuntimeOrigin::signed(3), 1, code.clone()));
+		System::assert_last_event(Event::<Test>::RedeemedInviteCode(1, 3).into());
+		assert_eq!(members_of(1), vec![1, 2, 3]);
+
+		// invite codes can only be redeemed once.
+		assert_noop!(
+			Community::redeem_invite_code(RuntimeOrigin::signed(4), 1, code),
+			Error::<Test>::InvitationNotExist
+		);
 	});
 }
 
@@ -492,10 +720,11 @@ fn join_community_works() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
 		assert_ok!(Community::join_community(RuntimeOrigin::signed(3), 1));
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2, 3]);
+		assert_eq!(members_of(1), vec![1, 2, 3]);
+		assert_eq!(MemberCount::<Test>::get(1), 3);
 	});
 }
 
@@ -507,7 +736,7 @@ fn join_community_not_works_for_already_joined() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 		assert_noop!(
 			Community::join_community(RuntimeOrigin::signed(2), 1),
 			Error::<Test>::AlreadyMember
@@ -523,7 +752,7 @@ fn join_community_not_works_for_invalid_community() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 		assert_noop!(
 			Community::join_community(RuntimeOrigin::signed(2), 2),
 			Error::<Test>::CommunityNotExist
@@ -539,10 +768,11 @@ fn leave_community_works() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
 		assert_ok!(Community::leave_community(RuntimeOrigin::signed(2), 1));
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1]);
+		assert_eq!(members_of(1), vec![1]);
+		assert_eq!(MemberCount::<Test>::get(1), 1);
 	});
 }
 
@@ -554,7 +784,7 @@ fn leave_community_not_work_for_member_not_part_of_community() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
 		assert_noop!(
 			Community::leave_community(RuntimeOrigin::signed(3), 1),
@@ -571,7 +801,7 @@ fn leave_community_not_work_for_invalid_community() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
 		assert_noop!(
 			Community::leave_community(RuntimeOrigin::signed(2), 2),
@@ -588,10 +818,11 @@ fn remove_member_works() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
 		assert_ok!(Community::remove_member(RuntimeOrigin::signed(1), 2, 1));
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1]);
+		assert_eq!(members_of(1), vec![1]);
+		assert_eq!(MemberCount::<Test>::get(1), 1);
 	});
 }
 
@@ -603,7 +834,7 @@ fn remove_member_not_work_for_member_not_part_of_community() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
 		assert_noop!(
 			Community::remove_member(RuntimeOrigin::signed(1), 3, 1),
@@ -620,7 +851,7 @@ fn remove_member_not_work_for_invalid_community() {
 		set_required_balance_to_create_community(10000000000000000000);
 		create_community();
 
-		assert_eq!(Communities::<Test>::get(1).unwrap().members, vec![1, 2]);
+		assert_eq!(members_of(1), vec![1, 2]);
 
 		assert_noop!(
 			Community::remove_member(RuntimeOrigin::signed(1), 2, 2),
@@ -669,3 +900,1359 @@ fn update_community_tag_and_colors_works() {
 		);
 	});
 }
+
+#[test]
+fn join_community_not_works_for_non_public_community() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+
+		assert_noop!(
+			Community::join_community(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::JoinRequestRequired
+		);
+	});
+}
+
+#[test]
+fn request_to_join_not_works_for_public_community() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::request_to_join(RuntimeOrigin::signed(3), 1, None),
+			Error::<Test>::NotAllowedForPublicCommunity
+		);
+	});
+}
+
+#[test]
+fn request_to_join_not_works_for_duplicate_request() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+
+		assert_ok!(Community::request_to_join(RuntimeOrigin::signed(2), 1, None));
+		assert_noop!(
+			Community::request_to_join(RuntimeOrigin::signed(2), 1, None),
+			Error::<Test>::JoinRequestAlreadyExist
+		);
+		assert_noop!(
+			Community::request_to_join(RuntimeOrigin::signed(1), 1, None),
+			Error::<Test>::AlreadyMember
+		);
+	});
+}
+
+#[test]
+fn approve_join_request_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+
+		let message = H256::repeat_byte(1);
+		assert_ok!(Community::request_to_join(RuntimeOrigin::signed(2), 1, Some(message)));
+		assert_eq!(JoinRequests::<Test>::get(1, 2).unwrap().message, Some(message));
+		assert_eq!(JoinRequestExpiry::<Test>::get(11).to_vec(), vec![(1, 2)]);
+
+		assert_noop!(
+			Community::approve_join_request(RuntimeOrigin::signed(2), 1, 2),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::approve_join_request(RuntimeOrigin::signed(1), 1, 2));
+		assert_eq!(members_of(1), vec![1, 2]);
+		assert!(JoinRequests::<Test>::get(1, 2).is_none());
+		assert!(JoinRequestExpiry::<Test>::get(11).is_empty());
+		System::assert_last_event(Event::<Test>::JoinRequestApproved(1, 2).into());
+	});
+}
+
+#[test]
+fn reject_join_request_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+
+		assert_ok!(Community::request_to_join(RuntimeOrigin::signed(2), 1, None));
+		assert_ok!(Community::reject_join_request(RuntimeOrigin::signed(1), 1, 2));
+		assert_eq!(members_of(1), vec![1]);
+		assert!(JoinRequests::<Test>::get(1, 2).is_none());
+
+		assert_noop!(
+			Community::reject_join_request(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::JoinRequestNotExist
+		);
+	});
+}
+
+#[test]
+fn join_request_expires() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+
+		assert_ok!(Community::request_to_join(RuntimeOrigin::signed(2), 1, None));
+
+		run_to_block(10);
+		assert!(JoinRequests::<Test>::get(1, 2).is_some());
+
+		run_to_block(11);
+		assert!(JoinRequests::<Test>::get(1, 2).is_none());
+		System::assert_last_event(Event::<Test>::JoinRequestExpired(1, 2).into());
+
+		assert_noop!(
+			Community::approve_join_request(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::JoinRequestNotExist
+		);
+	});
+}
+
+#[test]
+fn set_membership_gate_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+
+		assert_noop!(
+			Community::set_membership_gate(RuntimeOrigin::signed(2), 1, Some(7)),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::set_membership_gate(RuntimeOrigin::signed(1), 1, Some(7)));
+		assert_eq!(GateRequirements::<Test>::get(1), Some(7));
+		System::assert_last_event(Event::<Test>::UpdatedMembershipGate(1).into());
+
+		assert_ok!(Community::set_membership_gate(RuntimeOrigin::signed(1), 1, None));
+		assert!(GateRequirements::<Test>::get(1).is_none());
+	});
+}
+
+#[test]
+fn set_membership_gate_not_works_for_public_community() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::set_membership_gate(RuntimeOrigin::signed(1), 1, Some(7)),
+			Error::<Test>::NotGatedCommunity
+		);
+	});
+}
+
+#[test]
+fn join_gated_community_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+		GateHolders::set(vec![(7, 1), (7, 2)]);
+		assert_ok!(Community::set_membership_gate(RuntimeOrigin::signed(1), 1, Some(7)));
+
+		assert_noop!(
+			Community::join_community(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NotEligible
+		);
+
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(2), 1));
+		assert_eq!(members_of(1), vec![1, 2]);
+		assert!(Community::is_member(1, &2));
+	});
+}
+
+#[test]
+fn approve_join_request_not_works_for_ineligible_account() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+
+		assert_ok!(Community::request_to_join(RuntimeOrigin::signed(2), 1, None));
+		assert_ok!(Community::set_membership_gate(RuntimeOrigin::signed(1), 1, Some(7)));
+
+		assert_noop!(
+			Community::approve_join_request(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::NotEligible
+		);
+	});
+}
+
+#[test]
+fn approve_join_request_not_works_for_increased_joining_fee() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+		Balances::set_balance(&2, 100);
+
+		assert_ok!(Community::set_membership_dues(
+			RuntimeOrigin::signed(1),
+			1,
+			Some(DuesAmount::Native(50)),
+			None
+		));
+		assert_ok!(Community::request_to_join(RuntimeOrigin::signed(2), 1, None));
+		assert_eq!(
+			JoinRequests::<Test>::get(1, 2).unwrap().joining_fee,
+			Some(DuesAmount::Native(50))
+		);
+
+		assert_ok!(Community::set_membership_dues(
+			RuntimeOrigin::signed(1),
+			1,
+			Some(DuesAmount::Native(80)),
+			None
+		));
+		assert_noop!(
+			Community::approve_join_request(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::JoiningFeeIncreased
+		);
+
+		// A lower fee than the agreed one is charged as is.
+		assert_ok!(Community::set_membership_dues(
+			RuntimeOrigin::signed(1),
+			1,
+			Some(DuesAmount::Native(30)),
+			None
+		));
+		assert_ok!(Community::approve_join_request(RuntimeOrigin::signed(1), 1, 2));
+		assert_eq!(Balances::free_balance(2), 70);
+		assert_eq!(members_of(1), vec![1, 2]);
+	});
+}
+
+#[test]
+fn revalidate_membership_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_nft_gated_community();
+		GateHolders::set(vec![(7, 1), (7, 2)]);
+		assert_ok!(Community::set_membership_gate(RuntimeOrigin::signed(1), 1, Some(7)));
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(2), 1));
+
+		assert_noop!(
+			Community::revalidate_membership(RuntimeOrigin::signed(3), 1, 2),
+			Error::<Test>::StillEligible
+		);
+
+		// The member transfers the token away.
+		GateHolders::set(vec![(7, 1)]);
+		assert!(!Community::is_member(1, &2));
+
+		assert_ok!(Community::revalidate_membership(RuntimeOrigin::signed(3), 1, 2));
+		assert_eq!(members_of(1), vec![1]);
+		assert_eq!(MemberCount::<Test>::get(1), 1);
+		System::assert_last_event(Event::<Test>::RevokedMembership(1, 2).into());
+
+		assert_noop!(
+			Community::revalidate_membership(RuntimeOrigin::signed(3), 1, 2),
+			Error::<Test>::NotMember
+		);
+	});
+}
+
+#[test]
+fn grant_role_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::grant_role(
+				RuntimeOrigin::signed(2),
+				1,
+				2,
+				"Moderator".into(),
+				Permissions::ACCEPT_MEMBERS
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_noop!(
+			Community::grant_role(
+				RuntimeOrigin::signed(1),
+				1,
+				3,
+				"Moderator".into(),
+				Permissions::ACCEPT_MEMBERS
+			),
+			Error::<Test>::NotMember
+		);
+
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Moderator".into(),
+			Permissions::ACCEPT_MEMBERS | Permissions::ISSUE_BADGES
+		));
+		System::assert_last_event(Event::<Test>::GrantedRole(1, 2).into());
+
+		assert!(Community::has_permission(1, &2, Permissions::ACCEPT_MEMBERS));
+		assert!(Community::has_permission(1, &2, Permissions::ISSUE_BADGES));
+		assert!(!Community::has_permission(1, &2, Permissions::CREATE_PROPOSALS));
+		assert!(Community::has_permission(1, &1, Permissions::CREATE_PROPOSALS));
+	});
+}
+
+#[test]
+fn delegated_role_can_manage_members() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::invite_member(RuntimeOrigin::signed(2), 1, 3),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Moderator".into(),
+			Permissions::ACCEPT_MEMBERS
+		));
+
+		assert_ok!(Community::invite_member(RuntimeOrigin::signed(2), 1, 3));
+		assert_ok!(Community::accept_invitation(RuntimeOrigin::signed(3), 1));
+		assert_eq!(members_of(1), vec![1, 2, 3]);
+
+		assert_ok!(Community::remove_member(RuntimeOrigin::signed(2), 3, 1));
+		assert_eq!(members_of(1), vec![1, 2]);
+	});
+}
+
+#[test]
+fn revoke_role_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::revoke_role(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::RoleNotExist
+		);
+
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Moderator".into(),
+			Permissions::ACCEPT_MEMBERS
+		));
+		assert_ok!(Community::revoke_role(RuntimeOrigin::signed(1), 1, 2));
+		System::assert_last_event(Event::<Test>::RevokedRole(1, 2).into());
+		assert!(CommunityRoles::<Test>::get(1, 2).is_none());
+		assert!(!Community::has_permission(1, &2, Permissions::ACCEPT_MEMBERS));
+	});
+}
+
+#[test]
+fn role_is_removed_with_membership() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Moderator".into(),
+			Permissions::ACCEPT_MEMBERS
+		));
+		assert_ok!(Community::leave_community(RuntimeOrigin::signed(2), 1));
+		assert!(CommunityRoles::<Test>::get(1, 2).is_none());
+	});
+}
+
+#[test]
+fn founder_transfer_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::propose_founder_transfer(RuntimeOrigin::signed(2), 1, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Community::propose_founder_transfer(RuntimeOrigin::signed(1), 1, 1),
+			Error::<Test>::AlreadyFounder
+		);
+		assert_noop!(
+			Community::accept_founder_transfer(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::FounderTransferNotExist
+		);
+
+		assert_ok!(Community::propose_founder_transfer(RuntimeOrigin::signed(1), 1, 3));
+		System::assert_last_event(Event::<Test>::FounderTransferProposed(1, 3).into());
+
+		assert_noop!(
+			Community::accept_founder_transfer(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Community::accept_founder_transfer(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::InsufficientBalanceToBecomeFounder
+		);
+
+		Balances::set_balance(&3, 10000000000000000000);
+		assert_ok!(Community::accept_founder_transfer(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(Event::<Test>::FounderTransferred(1, 1, 3).into());
+
+		assert_eq!(Communities::<Test>::get(1).unwrap().founder, 3);
+		assert!(CommunityAccount::<Test>::get(1).is_empty());
+		assert_eq!(CommunityAccount::<Test>::get(3).to_vec(), vec![1]);
+		assert!(PendingFounderTransfers::<Test>::get(1).is_none());
+		assert_eq!(members_of(1), vec![1, 2, 3]);
+
+		assert_noop!(
+			Community::propose_founder_transfer(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn dissolve_community_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Moderator".into(),
+			Permissions::ACCEPT_MEMBERS
+		));
+
+		assert_noop!(
+			Community::dissolve_community(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+		System::assert_last_event(Event::<Test>::DissolvedCommunity(1).into());
+
+		assert!(Communities::<Test>::get(1).is_none());
+		assert!(Community::community_id_by_name(b"Jur").is_none());
+		assert!(CommunityAccount::<Test>::get(1).is_empty());
+		assert!(DissolvedCommunities::<Test>::contains_key(1));
+
+		assert_noop!(
+			Community::join_community(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::CommunityNotExist
+		);
+
+		Community::on_idle(1, Weight::MAX);
+		assert!(members_of(1).is_empty());
+		assert!(CommunityRoles::<Test>::get(1, 2).is_none());
+		assert!(!DissolvedCommunities::<Test>::contains_key(1));
+		System::assert_last_event(Event::<Test>::CleanedUpCommunity(1).into());
+
+		// The name can be used again.
+		create_community();
+	});
+}
+
+#[test]
+fn dissolve_community_works_for_root() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::root(), 1));
+		assert!(Communities::<Test>::get(1).is_none());
+
+		assert_noop!(
+			Community::dissolve_community(RuntimeOrigin::root(), 1),
+			Error::<Test>::CommunityNotExist
+		);
+	});
+}
+
+#[test]
+fn dissolved_community_is_cleaned_up_across_blocks() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		for who in 3..=20 {
+			assert_ok!(Community::join_community(RuntimeOrigin::signed(who), 1));
+		}
+		assert_eq!(MemberCount::<Test>::get(1), 20);
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+
+		// `DissolveCleanupLimit` allows ten items per block.
+		Community::on_idle(1, Weight::MAX);
+		assert_eq!(members_of(1).len(), 10);
+		assert!(DissolvedCommunities::<Test>::contains_key(1));
+
+		Community::on_idle(2, Weight::MAX);
+		assert!(members_of(1).is_empty());
+		assert!(DissolvedCommunities::<Test>::contains_key(1));
+
+		Community::on_idle(3, Weight::MAX);
+		assert!(!DissolvedCommunities::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn founder_bond_is_reserved() {
+	new_test_ext().execute_with(|| {
+		set_balance(15000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_eq!(FounderBonds::<Test>::get(1), Some(10000000000000000000));
+		assert_eq!(Balances::reserved_balance(1), 10000000000000000000);
+		assert_eq!(Balances::free_balance(1), 5000000000000000000);
+
+		// The remaining free balance does not cover a second bond.
+		assert_noop!(
+			Community::create_community(
+				RuntimeOrigin::signed(1),
+				None,
+				"Jur1".into(),
+				None,
+				None,
+				None,
+				Category::Public,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InsufficientBalanceToBecomeFounder
+		);
+	});
+}
+
+#[test]
+fn founder_bond_is_released_on_dissolution() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		assert_eq!(Balances::reserved_balance(1), 10000000000000000000);
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+		assert!(FounderBonds::<Test>::get(1).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10000000000000000000);
+	});
+}
+
+#[test]
+fn founder_bond_is_moved_on_founder_transfer() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		Balances::set_balance(&3, 10000000000000000000);
+
+		assert_ok!(Community::propose_founder_transfer(RuntimeOrigin::signed(1), 1, 3));
+		assert_ok!(Community::accept_founder_transfer(RuntimeOrigin::signed(3), 1));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(3), 10000000000000000000);
+		assert_eq!(FounderBonds::<Test>::get(1), Some(10000000000000000000));
+	});
+}
+
+#[test]
+fn slash_founder_bond_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::slash_founder_bond(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Community::slash_founder_bond(RuntimeOrigin::root(), 1));
+		System::assert_last_event(
+			Event::<Test>::SlashedFounderBond(1, 10000000000000000000).into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 0);
+
+		assert_noop!(
+			Community::slash_founder_bond(RuntimeOrigin::root(), 1),
+			Error::<Test>::FounderBondNotExist
+		);
+	});
+}
+
+#[test]
+fn community_origin_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		let treasury = Community::treasury_account(1);
+		Balances::set_balance(&treasury, 100);
+
+		let description = "Jur is governed by its members";
+
+		// The community acts with the rights of its founder.
+		assert_ok!(Community::update_community(
+			RuntimeOrigin::from(CommunityOrigin(1)),
+			1,
+			None,
+			Some(description.into())
+		));
+		assert_eq!(
+			Communities::<Test>::get(1).unwrap().description.to_vec(),
+			description.as_bytes().to_vec()
+		);
+
+		assert_ok!(Community::spend(RuntimeOrigin::from(CommunityOrigin(1)), 1, 3, 40));
+		System::assert_last_event(Event::<Test>::TreasurySpent(1, 3, 40).into());
+		assert_eq!(Balances::free_balance(3), 40);
+
+		// A community can't act for another one.
+		assert_noop!(
+			Community::spend(RuntimeOrigin::from(CommunityOrigin(2)), 1, 3, 40),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn treasury_spend_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		let treasury = Community::treasury_account(1);
+		Balances::set_balance(&treasury, 100);
+		assert_eq!(Community::treasury_balance(1), 100);
+
+		assert_noop!(
+			Community::spend(RuntimeOrigin::signed(2), 1, 3, 40),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::spend(RuntimeOrigin::signed(1), 1, 3, 40));
+		System::assert_last_event(Event::<Test>::TreasurySpent(1, 3, 40).into());
+		assert_eq!(Balances::free_balance(3), 40);
+		assert_eq!(Community::treasury_balance(1), 60);
+
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Treasurer".into(),
+			Permissions::MANAGE_TREASURY
+		));
+		assert_ok!(Community::spend(RuntimeOrigin::signed(2), 1, 3, 60));
+		assert_eq!(Community::treasury_balance(1), 0);
+	});
+}
+
+#[test]
+fn treasury_spend_asset_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		let treasury = Community::treasury_account(1);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 7, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7, treasury, 100));
+		assert_eq!(Community::treasury_asset_balance(1, 7), 100);
+
+		assert_noop!(
+			Community::spend_asset(RuntimeOrigin::signed(2), 1, 7, 3, 40),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::spend_asset(RuntimeOrigin::signed(1), 1, 7, 3, 40));
+		System::assert_last_event(Event::<Test>::TreasuryAssetSpent(1, 7, 3, 40).into());
+		assert_eq!(Assets::balance(7, 3), 40);
+		assert_eq!(Community::treasury_asset_balance(1, 7), 60);
+	});
+}
+
+#[test]
+fn dissolve_community_sweeps_the_treasury() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		let treasury = Community::treasury_account(1);
+		Balances::set_balance(&treasury, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 7, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7, treasury, 100));
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+		System::assert_has_event(Event::<Test>::TreasurySpent(1, 1, 100).into());
+		assert_eq!(Community::treasury_balance(1), 0);
+		assert_eq!(TreasuryClaimants::<Test>::get(1), Some(1));
+
+		assert_noop!(
+			Community::claim_treasury_asset(RuntimeOrigin::signed(2), 1, 7),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::claim_treasury_asset(RuntimeOrigin::signed(1), 1, 7));
+		System::assert_last_event(Event::<Test>::TreasuryAssetSpent(1, 7, 1, 100).into());
+		assert_eq!(Assets::balance(7, 1), 100);
+
+		assert_noop!(
+			Community::claim_treasury_asset(RuntimeOrigin::signed(1), 1, 7),
+			Error::<Test>::TreasuryEmpty
+		);
+	});
+}
+
+#[test]
+fn ban_member_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		let reason = H256::repeat_byte(1);
+
+		assert_noop!(
+			Community::ban_member(RuntimeOrigin::signed(2), 1, 3, None, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Community::ban_member(RuntimeOrigin::signed(1), 1, 1, None, None),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Community::ban_member(RuntimeOrigin::signed(1), 1, 2, Some(reason), None));
+		System::assert_last_event(Event::<Test>::BannedMember(1, 2).into());
+		assert_eq!(Bans::<Test>::get(1, 2), Some(Ban { reason: Some(reason), expires_at: None }));
+		assert_eq!(members_of(1), vec![1]);
+
+		assert_noop!(Community::join_community(RuntimeOrigin::signed(2), 1), Error::<Test>::Banned);
+		assert_noop!(
+			Community::invite_member(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::Banned
+		);
+
+		assert_ok!(Community::unban_member(RuntimeOrigin::signed(1), 1, 2));
+		System::assert_last_event(Event::<Test>::UnbannedMember(1, 2).into());
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(2), 1));
+
+		assert_noop!(
+			Community::unban_member(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::NotBanned
+		);
+	});
+}
+
+#[test]
+fn ban_member_drops_pending_invitation() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_ok!(Community::invite_member(RuntimeOrigin::signed(1), 1, 3));
+		assert_ok!(Community::ban_member(RuntimeOrigin::signed(1), 1, 3, None, None));
+
+		assert!(!Invitations::<Test>::contains_key(1, Invitee::Account(3)));
+		assert_noop!(
+			Community::accept_invitation(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::Banned
+		);
+	});
+}
+
+#[test]
+fn ban_expires() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::ban_member(RuntimeOrigin::signed(1), 1, 2, None, Some(1)),
+			Error::<Test>::InvalidBanExpiry
+		);
+
+		assert_ok!(Community::ban_member(RuntimeOrigin::signed(1), 1, 2, None, Some(5)));
+		assert!(Community::is_banned(1, &2));
+
+		run_to_block(5);
+		assert!(!Community::is_banned(1, &2));
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(2), 1));
+	});
+}
+
+#[test]
+fn create_state_moves_citizens_into_storage() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_state();
+
+		assert!(Community::is_citizen(1, &3));
+		assert!(!Community::is_citizen(1, &2));
+		assert_eq!(Community::state_of(1).unwrap().citizens, Vec::<u64>::new());
+
+		// The government holds every permission of the founder.
+		assert!(Community::has_permission(1, &2, Permissions::GRANT_CITIZENSHIP));
+		assert!(!Community::has_permission(1, &3, Permissions::GRANT_CITIZENSHIP));
+	});
+}
+
+#[test]
+fn citizenship_application_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_state();
+
+		assert_noop!(
+			Community::apply_for_citizenship(RuntimeOrigin::signed(4), 1),
+			Error::<Test>::NotMember
+		);
+		assert_noop!(
+			Community::apply_for_citizenship(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::AlreadyCitizen
+		);
+
+		assert_ok!(Community::apply_for_citizenship(RuntimeOrigin::signed(1), 1));
+		System::assert_last_event(Event::<Test>::CitizenshipApplied(1, 1).into());
+		assert!(CitizenshipApplications::<Test>::contains_key(1, 1));
+		assert_noop!(
+			Community::apply_for_citizenship(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CitizenshipApplicationAlreadyExist
+		);
+
+		assert_noop!(
+			Community::approve_citizenship(RuntimeOrigin::signed(3), 1, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Community::approve_citizenship(RuntimeOrigin::signed(2), 1, 1));
+		System::assert_last_event(Event::<Test>::CitizenshipGranted(1, 1).into());
+		assert!(Community::is_citizen(1, &1));
+		assert!(!CitizenshipApplications::<Test>::contains_key(1, 1));
+
+		assert_ok!(Community::revoke_citizenship(RuntimeOrigin::signed(2), 1, 1));
+		System::assert_last_event(Event::<Test>::CitizenshipRevoked(1, 1).into());
+		assert!(!Community::is_citizen(1, &1));
+		assert_noop!(
+			Community::revoke_citizenship(RuntimeOrigin::signed(2), 1, 1),
+			Error::<Test>::NotCitizen
+		);
+	});
+}
+
+#[test]
+fn reject_citizenship_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_state();
+
+		assert_noop!(
+			Community::reject_citizenship(RuntimeOrigin::signed(2), 1, 1),
+			Error::<Test>::CitizenshipApplicationNotExist
+		);
+
+		assert_ok!(Community::apply_for_citizenship(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Community::reject_citizenship(RuntimeOrigin::signed(2), 1, 1));
+		System::assert_last_event(Event::<Test>::CitizenshipRejected(1, 1).into());
+		assert!(!CitizenshipApplications::<Test>::contains_key(1, 1));
+		assert!(!Community::is_citizen(1, &1));
+	});
+}
+
+#[test]
+fn citizenship_not_works_for_other_community_types() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_noop!(
+			Community::apply_for_citizenship(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotStateCommunity
+		);
+		assert_noop!(
+			Community::do_amend_constitution(1, ConstitutionAmendment::RemoveArticle(0)),
+			Error::<Test>::NotStateCommunity
+		);
+	});
+}
+
+#[test]
+fn leaving_state_revokes_citizenship() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_state();
+
+		assert_ok!(Community::leave_community(RuntimeOrigin::signed(3), 1));
+		assert!(!Citizens::<Test>::contains_key(1, 3));
+	});
+}
+
+#[test]
+fn amend_constitution_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_state();
+
+		assert_ok!(Community::do_amend_constitution(
+			1,
+			ConstitutionAmendment::AddArticle(b"Article two".to_vec().try_into().unwrap())
+		));
+		assert_ok!(Community::do_amend_constitution(
+			1,
+			ConstitutionAmendment::ReplaceArticle(0, b"Article zero".to_vec().try_into().unwrap())
+		));
+		assert_eq!(
+			Community::state_of(1).unwrap().constitution,
+			vec![
+				b"Article zero".to_vec().try_into().unwrap(),
+				b"Article two".to_vec().try_into().unwrap()
+			]
+		);
+
+		assert_ok!(Community::do_amend_constitution(1, ConstitutionAmendment::RemoveArticle(0)));
+		assert_eq!(
+			Community::state_of(1).unwrap().constitution,
+			vec![b"Article two".to_vec().try_into().unwrap()]
+		);
+
+		assert_noop!(
+			Community::do_amend_constitution(1, ConstitutionAmendment::RemoveArticle(1)),
+			Error::<Test>::ArticleNotExist
+		);
+	});
+}
+
+#[test]
+fn amend_constitution_not_works_beyond_max_articles() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_state();
+
+		// `MaxArticles` allows ten articles.
+		for _ in 1..10 {
+			assert_ok!(Community::do_amend_constitution(
+				1,
+				ConstitutionAmendment::AddArticle(b"Article".to_vec().try_into().unwrap())
+			));
+		}
+		assert_noop!(
+			Community::do_amend_constitution(
+				1,
+				ConstitutionAmendment::AddArticle(b"Article".to_vec().try_into().unwrap())
+			),
+			Error::<Test>::TooManyArticles
+		);
+	});
+}
+
+#[test]
+fn amend_constitution_updates_metadata_deposit() {
+	new_test_ext().execute_with(|| {
+		MetadataDepositPerByte::set(1);
+		set_balance(20000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_state();
+		let deposit = MetadataDeposits::<Test>::get(1).unwrap();
+
+		// The article and its length prefix.
+		assert_ok!(Community::do_amend_constitution(
+			1,
+			ConstitutionAmendment::AddArticle(b"Article two".to_vec().try_into().unwrap())
+		));
+		assert_eq!(MetadataDeposits::<Test>::get(1), Some(deposit + 12));
+		assert_eq!(Balances::reserved_balance(1), 10000000000000000000 + deposit + 12);
+
+		assert_ok!(Community::do_amend_constitution(1, ConstitutionAmendment::RemoveArticle(1)));
+		assert_eq!(MetadataDeposits::<Test>::get(1), Some(deposit));
+	});
+}
+
+#[test]
+fn sub_community_link_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(40000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		create_state();
+
+		assert_noop!(
+			Community::request_parent(RuntimeOrigin::signed(3), 2, 1, true, false),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Community::request_parent(RuntimeOrigin::signed(1), 2, 2, true, false),
+			Error::<Test>::InvalidParent
+		);
+
+		assert_ok!(Community::request_parent(RuntimeOrigin::signed(1), 2, 1, true, false));
+		System::assert_last_event(Event::<Test>::ParentRequested(2, 1).into());
+
+		assert_noop!(
+			Community::approve_sub_community(RuntimeOrigin::signed(2), 1, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Community::approve_sub_community(RuntimeOrigin::signed(1), 1, 2));
+		System::assert_last_event(Event::<Test>::SubCommunityAdded(1, 2).into());
+
+		assert_eq!(
+			Parents::<Test>::get(2),
+			Some(ParentLink { parent: 1, inherit_membership: true, inherit_passports: false })
+		);
+		assert!(!ParentRequests::<Test>::contains_key(2));
+		assert_eq!(Community::descendants_of(1), vec![2]);
+		assert_eq!(DescendantCount::<Test>::get(1), 1);
+		assert_eq!(Community::passport_community(2), 2);
+
+		// The link can't be reversed into a cycle.
+		assert_noop!(
+			Community::request_parent(RuntimeOrigin::signed(1), 1, 2, true, false),
+			Error::<Test>::InvalidParent
+		);
+		assert_noop!(
+			Community::request_parent(RuntimeOrigin::signed(1), 2, 1, true, false),
+			Error::<Test>::AlreadySubCommunity
+		);
+
+		assert_ok!(Community::detach_sub_community(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(Event::<Test>::SubCommunityDetached(1, 2).into());
+		assert!(!Parents::<Test>::contains_key(2));
+		assert!(!SubCommunities::<Test>::contains_key(1, 2));
+		assert!(!DescendantCount::<Test>::contains_key(1));
+		assert_noop!(
+			Community::detach_sub_community(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::NotSubCommunity
+		);
+	});
+}
+
+#[test]
+fn sub_community_link_not_works_beyond_max_descendants() {
+	new_test_ext().execute_with(|| {
+		set_balance(40000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		create_state();
+
+		// `MaxDescendants` allows ten sub-communities below the top of a hierarchy.
+		DescendantCount::<Test>::insert(1, 10);
+		assert_noop!(
+			Community::request_parent(RuntimeOrigin::signed(1), 2, 1, true, false),
+			Error::<Test>::TooManySubCommunities
+		);
+
+		DescendantCount::<Test>::insert(1, 9);
+		assert_ok!(Community::request_parent(RuntimeOrigin::signed(1), 2, 1, true, false));
+		assert_ok!(Community::approve_sub_community(RuntimeOrigin::signed(1), 1, 2));
+		assert_eq!(DescendantCount::<Test>::get(1), 10);
+	});
+}
+
+#[test]
+fn sub_community_inherits_membership() {
+	new_test_ext().execute_with(|| {
+		set_balance(40000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		create_state();
+
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(4), 1));
+		assert!(!Community::is_member(2, &4));
+		assert!(!Community::is_member_of_descendants(1, &4));
+
+		assert_ok!(Community::request_parent(RuntimeOrigin::signed(1), 2, 1, true, true));
+		assert_ok!(Community::approve_sub_community(RuntimeOrigin::signed(1), 1, 2));
+
+		assert!(Community::is_member(2, &4));
+		assert!(Community::is_member_of_descendants(1, &3));
+		assert_eq!(Community::passport_community(2), 1);
+
+		// A ban from the sub-community overrides the inherited membership.
+		assert_ok!(Community::ban_member(RuntimeOrigin::signed(1), 2, 4, None, None));
+		assert!(!Community::is_member(2, &4));
+	});
+}
+
+#[test]
+fn reject_sub_community_works() {
+	new_test_ext().execute_with(|| {
+		set_balance(40000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		create_state();
+
+		assert_noop!(
+			Community::reject_sub_community(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::ParentRequestNotExist
+		);
+
+		assert_ok!(Community::request_parent(RuntimeOrigin::signed(1), 2, 1, false, false));
+		assert_ok!(Community::reject_sub_community(RuntimeOrigin::signed(1), 1, 2));
+		System::assert_last_event(Event::<Test>::SubCommunityRejected(1, 2).into());
+		assert!(!ParentRequests::<Test>::contains_key(2));
+
+		assert_noop!(
+			Community::approve_sub_community(RuntimeOrigin::signed(1), 1, 2),
+			Error::<Test>::ParentRequestNotExist
+		);
+	});
+}
+
+#[test]
+fn sub_communities_are_detached_when_parent_is_dissolved() {
+	new_test_ext().execute_with(|| {
+		set_balance(40000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		create_state();
+
+		assert_ok!(Community::request_parent(RuntimeOrigin::signed(1), 2, 1, true, false));
+		assert_ok!(Community::approve_sub_community(RuntimeOrigin::signed(1), 1, 2));
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+		Community::on_idle(1, Weight::MAX);
+
+		assert!(!Parents::<Test>::contains_key(2));
+		assert_eq!(SubCommunities::<Test>::iter_prefix(1).count(), 0);
+		assert!(!Community::is_member(2, &4));
+	});
+}
+
+#[test]
+fn reference_id_resolves_to_community() {
+	new_test_ext().execute_with(|| {
+		set_balance(40000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+		create_state();
+
+		let reference_id = Communities::<Test>::get(1).unwrap().reference_id;
+		assert_eq!(Community::generate_reference_id(1), Ok(reference_id));
+		assert_ne!(Communities::<Test>::get(2).unwrap().reference_id, reference_id);
+		assert_eq!(Community::community_by_reference_id(reference_id), Some(1));
+
+		// A taken reference id moves the derivation to the next salt.
+		assert_ne!(
+			Community::generate_reference_id(2),
+			Ok(Communities::<Test>::get(2).unwrap().reference_id)
+		);
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+		assert!(!ReferenceIdToCommunity::<Test>::contains_key(reference_id));
+	});
+}
+
+#[test]
+fn membership_dues_work() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		let due = RecurringDue { amount: DuesAmount::Native(10), period: 10 };
+
+		assert_noop!(
+			Community::set_membership_dues(
+				RuntimeOrigin::signed(2),
+				1,
+				Some(DuesAmount::Native(50)),
+				Some(due.clone())
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Community::set_membership_dues(
+				RuntimeOrigin::signed(1),
+				1,
+				None,
+				Some(RecurringDue { amount: DuesAmount::Native(10), period: 0 })
+			),
+			Error::<Test>::InvalidDuesPeriod
+		);
+
+		assert_ok!(Community::set_membership_dues(
+			RuntimeOrigin::signed(1),
+			1,
+			Some(DuesAmount::Native(50)),
+			Some(due)
+		));
+		System::assert_last_event(Event::<Test>::UpdatedMembershipDues(1).into());
+
+		// The joining fee goes to the treasury and covers the first period.
+		Balances::set_balance(&3, 100);
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(3), 1));
+		assert_eq!(Balances::free_balance(3), 50);
+		assert_eq!(Community::treasury_balance(1), 50);
+		assert_eq!(DuesPaidUntil::<Test>::get(1, 3), Some(11));
+
+		// Accounts which cannot pay the joining fee do not join.
+		assert!(Community::join_community(RuntimeOrigin::signed(4), 1).is_err());
+		assert!(!Community::is_member(1, &4));
+
+		run_to_block(12);
+		assert!(Community::is_lapsed(1, &3));
+		// Members joined before the dues were set are charged from that block.
+		assert!(Community::is_lapsed(1, &2));
+		// The founder is exempt from the dues.
+		assert!(!Community::is_lapsed(1, &1));
+
+		assert_ok!(Community::pay_dues(RuntimeOrigin::signed(3), 1, 2));
+		System::assert_last_event(Event::<Test>::DuesPaid(1, 3, 32).into());
+		assert!(!Community::is_lapsed(1, &3));
+		assert_eq!(Balances::free_balance(3), 30);
+		assert_eq!(Community::treasury_balance(1), 70);
+
+		assert_noop!(
+			Community::pay_dues(RuntimeOrigin::signed(3), 1, 0),
+			Error::<Test>::InvalidDuesPeriod
+		);
+		assert_noop!(Community::pay_dues(RuntimeOrigin::signed(4), 1, 1), Error::<Test>::NotMember);
+
+		// Leaving the community drops the payment record.
+		assert_ok!(Community::leave_community(RuntimeOrigin::signed(3), 1));
+		assert!(DuesPaidUntil::<Test>::get(1, 3).is_none());
+
+		assert_ok!(Community::set_membership_dues(RuntimeOrigin::signed(1), 1, None, None));
+		assert!(CommunityDues::<Test>::get(1).is_none());
+		assert!(!Community::is_lapsed(1, &2));
+	});
+}
+
+#[test]
+fn membership_dues_payable_in_assets() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 7, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7, 3, 100));
+
+		assert_ok!(Community::set_membership_dues(
+			RuntimeOrigin::signed(1),
+			1,
+			Some(DuesAmount::Asset(7, 40)),
+			Some(RecurringDue { amount: DuesAmount::Asset(7, 20), period: 10 })
+		));
+
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(3), 1));
+		System::assert_has_event(Event::<Test>::JoiningFeePaid(1, 3).into());
+		assert_eq!(Community::treasury_asset_balance(1, 7), 40);
+
+		assert_ok!(Community::pay_dues(RuntimeOrigin::signed(3), 1, 1));
+		assert_eq!(DuesPaidUntil::<Test>::get(1, 3), Some(21));
+		assert_eq!(Assets::balance(7, 3), 40);
+		assert_eq!(Community::treasury_asset_balance(1, 7), 60);
+	});
+}
+
+#[test]
+fn metadata_deposit_works() {
+	new_test_ext().execute_with(|| {
+		MetadataDepositPerByte::set(1);
+		set_balance(20000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		let deposit = Community::metadata_bytes(&Communities::<Test>::get(1).unwrap()) as u128;
+		assert!(deposit > 0);
+		assert_eq!(MetadataDeposits::<Test>::get(1), Some(deposit));
+		assert_eq!(Balances::reserved_balance(1), 10000000000000000000 + deposit);
+
+		// A shorter description refunds part of the deposit.
+		assert_ok!(Community::update_community(
+			RuntimeOrigin::signed(1),
+			1,
+			None,
+			Some("Jur".into())
+		));
+		let updated_deposit =
+			Community::metadata_bytes(&Communities::<Test>::get(1).unwrap()) as u128;
+		assert!(updated_deposit < deposit);
+		assert_eq!(MetadataDeposits::<Test>::get(1), Some(updated_deposit));
+		assert_eq!(Balances::reserved_balance(1), 10000000000000000000 + updated_deposit);
+
+		// Larger metadata tops it up again.
+		assert_ok!(Community::update_metadata(RuntimeOrigin::signed(1), 1, get_metadata()));
+		let topped_up_deposit =
+			Community::metadata_bytes(&Communities::<Test>::get(1).unwrap()) as u128;
+		assert_eq!(Balances::reserved_balance(1), 10000000000000000000 + topped_up_deposit);
+
+		// The treasury pays for the updates of the community itself.
+		let treasury = Community::treasury_account(1);
+		Balances::set_balance(&treasury, 1_000_000);
+		assert_ok!(Community::update_community(
+			RuntimeOrigin::from(CommunityOrigin(1)),
+			1,
+			None,
+			Some("Jur is governed by its members".into())
+		));
+		let treasury_deposit =
+			Community::metadata_bytes(&Communities::<Test>::get(1).unwrap()) as u128;
+		assert_eq!(MetadataDeposits::<Test>::get(1), Some(treasury_deposit));
+		assert!(TreasuryMetadataDeposits::<Test>::contains_key(1));
+		assert_eq!(Balances::reserved_balance(treasury), treasury_deposit);
+		assert_eq!(Balances::reserved_balance(1), 10000000000000000000);
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+		assert!(MetadataDeposits::<Test>::get(1).is_none());
+		assert!(!TreasuryMetadataDeposits::<Test>::contains_key(1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(treasury), 0);
+	});
+}
+
+#[test]
+fn metadata_deposit_not_works_for_insufficient_balance() {
+	new_test_ext().execute_with(|| {
+		set_balance(10000000000000000000);
+		set_required_balance_to_create_community(10000000000000000000);
+		create_community();
+
+		MetadataDepositPerByte::set(1_000_000_000);
+		assert_noop!(
+			Community::update_community(
+				RuntimeOrigin::signed(1),
+				1,
+				None,
+				Some("Jur is the core community of the Jur ecosystem".into())
+			),
+			Error::<Test>::InsufficientBalanceForDeposit
+		);
+	});
+}
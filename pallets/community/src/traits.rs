@@ -0,0 +1,154 @@
+use frame_support::{
+	traits::tokens::{fungibles, nonfungibles, nonfungibles_v2},
+	Parameter,
+};
+use sp_runtime::traits::Zero;
+use sp_std::marker::PhantomData;
+
+use crate::types::Permissions;
+
+/// Lets other pallets check what an account may do within a community.
+pub trait CommunityPermissions<CommunityId, AccountId> {
+	/// Check whether the account holds the given permissions in the community.
+	///
+	/// The founder holds every permission, other members only those of their role.
+	fn has_permission(community_id: CommunityId, who: &AccountId, permission: Permissions) -> bool;
+}
+
+/// Handler which removes the state a pallet keeps for a dissolved community.
+///
+/// The cleanup is spread across blocks, the handler is called repeatedly with a limit
+/// on the number of storage items it may remove until it reports that nothing is left.
+pub trait OnCommunityDissolved<CommunityId> {
+	/// Remove up to `limit` storage items kept for the community.
+	///
+	/// Returns the number of removed items, returning fewer than `limit` signals that
+	/// no state is left for the community.
+	fn on_community_dissolved(community_id: CommunityId, limit: u32) -> u32;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<CommunityId: Copy> OnCommunityDissolved<CommunityId> for Tuple {
+	#[allow(unused_variables, unused_mut)]
+	fn on_community_dissolved(community_id: CommunityId, limit: u32) -> u32 {
+		let mut removed = 0_u32;
+		for_tuples!( #(
+			if removed < limit {
+				removed = removed.saturating_add(
+					Tuple::on_community_dissolved(community_id, limit.saturating_sub(removed))
+				);
+			}
+		)* );
+		removed
+	}
+}
+
+/// Handler notified when an account loses the citizenship of a State.
+pub trait OnCitizenshipRevoked<CommunityId, AccountId> {
+	/// Called after the citizenship was removed.
+	fn on_citizenship_revoked(community_id: CommunityId, who: &AccountId);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<CommunityId: Copy, AccountId> OnCitizenshipRevoked<CommunityId, AccountId> for Tuple {
+	#[allow(unused_variables)]
+	fn on_citizenship_revoked(community_id: CommunityId, who: &AccountId) {
+		for_tuples!( #( Tuple::on_citizenship_revoked(community_id, who); )* );
+	}
+}
+
+/// Handler notified when an account becomes a member of a community.
+pub trait OnMemberJoined<CommunityId, AccountId> {
+	/// Called after the account was added to the members.
+	fn on_member_joined(community_id: CommunityId, who: &AccountId);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<CommunityId: Copy, AccountId> OnMemberJoined<CommunityId, AccountId> for Tuple {
+	#[allow(unused_variables)]
+	fn on_member_joined(community_id: CommunityId, who: &AccountId) {
+		for_tuples!( #( Tuple::on_member_joined(community_id, who); )* );
+	}
+}
+
+/// Handler notified when an account leaves or is removed from a community.
+///
+/// Not called for the members of a dissolved community, their state is removed
+/// through `OnCommunityDissolved`.
+pub trait OnMemberLeft<CommunityId, AccountId> {
+	/// Called after the account was removed from the members.
+	fn on_member_left(community_id: CommunityId, who: &AccountId);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<CommunityId: Copy, AccountId> OnMemberLeft<CommunityId, AccountId> for Tuple {
+	#[allow(unused_variables)]
+	fn on_member_left(community_id: CommunityId, who: &AccountId) {
+		for_tuples!( #( Tuple::on_member_left(community_id, who); )* );
+	}
+}
+
+/// Decides whether an account fulfils the token requirement of a gated community.
+pub trait MembershipGate<AccountId> {
+	/// Describes the token an account has to hold, e.g. a collection or an asset.
+	type Requirement: Parameter;
+
+	/// Check whether the account currently fulfils the requirement.
+	fn is_eligible(requirement: &Self::Requirement, who: &AccountId) -> bool;
+}
+
+/// Gate which lets everyone in.
+impl<AccountId> MembershipGate<AccountId> for () {
+	type Requirement = ();
+
+	fn is_eligible(_requirement: &Self::Requirement, _who: &AccountId) -> bool {
+		true
+	}
+}
+
+/// Gate backed by `pallet_nfts`, the account has to hold an item of the collection.
+pub struct NftsGate<Nfts>(PhantomData<Nfts>);
+
+impl<AccountId, Nfts> MembershipGate<AccountId> for NftsGate<Nfts>
+where
+	Nfts: nonfungibles_v2::InspectEnumerable<AccountId>,
+	Nfts::CollectionId: Parameter,
+{
+	type Requirement = Nfts::CollectionId;
+
+	fn is_eligible(requirement: &Self::Requirement, who: &AccountId) -> bool {
+		Nfts::owned_in_collection(requirement, who).next().is_some()
+	}
+}
+
+/// Gate backed by `pallet_uniques`, the account has to hold an item of the collection.
+pub struct UniquesGate<Uniques>(PhantomData<Uniques>);
+
+impl<AccountId, Uniques> MembershipGate<AccountId> for UniquesGate<Uniques>
+where
+	Uniques: nonfungibles::InspectEnumerable<AccountId>,
+	Uniques::CollectionId: Parameter,
+{
+	type Requirement = Uniques::CollectionId;
+
+	fn is_eligible(requirement: &Self::Requirement, who: &AccountId) -> bool {
+		Uniques::owned_in_collection(requirement, who)
+			.next()
+			.is_some()
+	}
+}
+
+/// Gate backed by `pallet_assets`, the account has to hold at least the given amount of the asset.
+pub struct AssetsGate<Assets>(PhantomData<Assets>);
+
+impl<AccountId, Assets> MembershipGate<AccountId> for AssetsGate<Assets>
+where
+	Assets: fungibles::Inspect<AccountId>,
+{
+	type Requirement = (Assets::AssetId, Assets::Balance);
+
+	fn is_eligible((asset_id, min_balance): &Self::Requirement, who: &AccountId) -> bool {
+		let balance = Assets::balance(*asset_id, who);
+		!balance.is_zero() && balance >= *min_balance
+	}
+}
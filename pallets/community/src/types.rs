@@ -1,12 +1,69 @@
 use super::*;
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::{CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound};
-use frame_support::{pallet_prelude::Get, BoundedVec};
+use frame_support::{
+	pallet_prelude::Get,
+	traits::{fungibles, Currency},
+	BoundedVec,
+};
 use scale_info::TypeInfo;
 use sp_std::{prelude::*, vec::Vec};
 
 pub type CommunityMetaDataFor<T> = CommunityMetaData<<T as Config>::StringLimit>;
 
+pub type NegativeImbalanceOf<T> = <pallet_balances::Pallet<T> as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+pub type AssetBalanceOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type InviteeOf<T> =
+	Invitee<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Hash>;
+
+pub type GateRequirementOf<T> = <<T as Config>::MembershipGate as MembershipGate<
+	<T as frame_system::Config>::AccountId,
+>>::Requirement;
+
+pub type DuesAmountOf<T> =
+	DuesAmount<<T as pallet_balances::Config>::Balance, AssetIdOf<T>, AssetBalanceOf<T>>;
+
+pub type RecurringDueOf<T> =
+	RecurringDue<DuesAmountOf<T>, frame_system::pallet_prelude::BlockNumberFor<T>>;
+
+pub type MembershipDuesOf<T> =
+	MembershipDues<DuesAmountOf<T>, frame_system::pallet_prelude::BlockNumberFor<T>>;
+
+pub type JoinRequestOf<T> = JoinRequest<
+	frame_system::pallet_prelude::BlockNumberFor<T>,
+	<T as frame_system::Config>::Hash,
+	DuesAmountOf<T>,
+>;
+
+pub type CommunityOf<T> = Community<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::NameLimit,
+	<T as Config>::DescriptionLimit,
+	<T as Config>::TagLimit,
+	<T as Config>::ColorLimit,
+	<T as Config>::StringLimit,
+	<T as Config>::LogoLimit,
+	ArticlesOf<T>,
+>;
+
+pub type ArticleOf<T> = BoundedVec<u8, <T as Config>::ArticleLimit>;
+
+pub type ArticlesOf<T> = BoundedVec<ArticleOf<T>, <T as Config>::MaxArticles>;
+
+pub type CommunityTypeOf<T> = CommunityType<<T as frame_system::Config>::AccountId, ArticlesOf<T>>;
+
+pub type StateOf<T> = State<<T as frame_system::Config>::AccountId, ArticlesOf<T>>;
+
+pub type ConstitutionAmendmentOf<T> = ConstitutionAmendment<ArticleOf<T>>;
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
 #[scale_info(skip_type_params(
 	NameLimit,
@@ -24,19 +81,166 @@ pub struct Community<
 	ColorLimit: Get<u32>,
 	StringLimit: Get<u32>,
 	LogoLimit: Get<u32>,
+	Articles,
 > {
 	pub founder: AccountId,
 	pub logo: BoundedVec<u8, LogoLimit>,
 	pub name: BoundedVec<u8, NameLimit>,
 	pub description: BoundedVec<u8, DescriptionLimit>,
-	pub members: Vec<AccountId>,
 	pub metadata: Option<CommunityMetaData<StringLimit>>,
 	pub reference_id: [u8; 16],
 	pub category: Category,
 	pub tag: BoundedVec<u8, TagLimit>,
 	pub primary_color: BoundedVec<u8, ColorLimit>,
 	pub secondary_color: BoundedVec<u8, ColorLimit>,
-	pub community_type: Option<CommunityType<AccountId>>,
+	pub community_type: Option<CommunityType<AccountId, Articles>>,
+}
+
+/// Details stored for every member of a community.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct MemberInfo<BlockNumber> {
+	/// Block at which the account became a member.
+	pub joined_at: BlockNumber,
+}
+
+/// Set of actions a role allows its holder to perform within a community.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct Permissions(pub u32);
+
+impl Permissions {
+	/// Accept members, handle join requests and remove members.
+	pub const ACCEPT_MEMBERS: Self = Self(1 << 0);
+	/// Create proposals.
+	pub const CREATE_PROPOSALS: Self = Self(1 << 1);
+	/// Add badges to the directory and issue them to passports.
+	pub const ISSUE_BADGES: Self = Self(1 << 2);
+	/// Create bounties and manage their participants and contributors.
+	pub const MANAGE_BOUNTIES: Self = Self(1 << 3);
+	/// Create events.
+	pub const MANAGE_EVENTS: Self = Self(1 << 4);
+	/// Check in event attendees by issuing their proof of presence.
+	pub const CHECK_IN_ATTENDEES: Self = Self(1 << 5);
+	/// Spend the funds held by the community treasury.
+	pub const MANAGE_TREASURY: Self = Self(1 << 6);
+	/// Handle the citizenship applications of a State.
+	pub const GRANT_CITIZENSHIP: Self = Self(1 << 7);
+	/// Link the community to a parent and handle the sub-communities linking to it.
+	pub const MANAGE_SUB_COMMUNITIES: Self = Self(1 << 8);
+	/// Update the passport metadata and the membership gate of the community.
+	pub const MANAGE_SETTINGS: Self = Self(1 << 9);
+	/// Set the proposal threshold and the voting asset of the community.
+	pub const MANAGE_GOVERNANCE: Self = Self(1 << 10);
+	/// Propose calls dispatched from the origin of the community.
+	pub const EXECUTE_CALLS: Self = Self(1 << 11);
+
+	/// Check whether all the given permissions are part of the set.
+	pub fn contains(&self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl sp_std::ops::BitOr for Permissions {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+/// A named role granted to a member of a community.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(NameLimit))]
+pub struct Role<NameLimit: Get<u32>> {
+	/// Name of the role, e.g. moderator or treasurer.
+	pub name: BoundedVec<u8, NameLimit>,
+	/// Actions the role allows its holder to perform.
+	pub permissions: Permissions,
+}
+
+/// A pending request to join a non-public community.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct JoinRequest<BlockNumber, Hash, Amount> {
+	/// Hash of the message attached to the request.
+	pub message: Option<Hash>,
+	/// Joining fee of the community when the request was submitted, the most the requester
+	/// is charged on approval.
+	pub joining_fee: Option<Amount>,
+	/// Block at which the request was submitted.
+	pub requested_at: BlockNumber,
+	/// Block at which the request expires if nobody handled it.
+	pub expires_at: BlockNumber,
+}
+
+/// A ban preventing an account from joining a community.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Ban<BlockNumber, Hash> {
+	/// Hash of the reason given for the ban.
+	pub reason: Option<Hash>,
+	/// Block at which the ban is lifted, `None` for a permanent ban.
+	pub expires_at: Option<BlockNumber>,
+}
+
+/// Origin of a community dispatching the call of one of its accepted proposals.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CommunityOrigin<CommunityId>(pub CommunityId);
+
+/// Link from a sub-community to its parent community.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ParentLink<CommunityId> {
+	/// The parent community.
+	pub parent: CommunityId,
+	/// Members of the parent are members of the sub-community.
+	pub inherit_membership: bool,
+	/// Passports of the parent are honoured by the sub-community.
+	pub inherit_passports: bool,
+}
+
+/// An amount payable in JUR or in a `pallet_assets` token.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum DuesAmount<Balance, AssetId, AssetBalance> {
+	/// Amount of JUR.
+	Native(Balance),
+	/// Amount of the given asset.
+	Asset(AssetId, AssetBalance),
+}
+
+/// A due the members of a community pay for every period of their membership.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RecurringDue<Amount, BlockNumber> {
+	/// Amount paid for a single period.
+	pub amount: Amount,
+	/// Length of a period in blocks.
+	pub period: BlockNumber,
+}
+
+/// The fees charged by a community for its membership.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MembershipDues<Amount, BlockNumber> {
+	/// Fee paid once when joining the community, covering the first period.
+	pub joining_fee: Option<Amount>,
+	/// Due paid for every following period.
+	pub recurring_due: Option<RecurringDue<Amount, BlockNumber>>,
+	/// Block from which the members who joined before the recurring due was set are
+	/// charged.
+	pub since: BlockNumber,
+}
+
+/// Recipient of an invitation to join a community.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum Invitee<AccountId, Hash> {
+	/// An account which accepts or declines the invitation itself.
+	Account(AccountId),
+	/// Hash of an off-chain invite code, redeemed by the account presenting the code.
+	Code(Hash),
+}
+
+/// A pending invitation to join a community.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Invitation<AccountId, BlockNumber> {
+	/// Account which issued the invitation.
+	pub invited_by: AccountId,
+	/// Block at which the invitation expires if it was not accepted.
+	pub expires_at: BlockNumber,
 }
 
 #[derive(
@@ -82,20 +286,35 @@ pub struct Traditions<StringLimit: Get<u32>>(pub BoundedVec<u8, StringLimit>);
 pub struct Values<StringLimit: Get<u32>>(pub BoundedVec<u8, StringLimit>);
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
-pub struct State<AccountId> {
-	pub constitution: Vec<Vec<u8>>,
+pub struct State<AccountId, Articles> {
+	/// Articles of the constitution, amended through accepted proposals.
+	pub constitution: Articles,
+	/// Accounts holding the founder powers of the State.
 	pub government: Vec<AccountId>,
+	/// Initial citizens, moved into `Citizens` when the State is created.
 	pub citizens: Vec<AccountId>,
 }
+
+/// A change to the constitution of a State.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ConstitutionAmendment<Article> {
+	/// Append a new article.
+	AddArticle(Article),
+	/// Replace the text of the article at the given index.
+	ReplaceArticle(u32, Article),
+	/// Remove the article at the given index.
+	RemoveArticle(u32),
+}
+
 /// Different types of Communities.
 #[derive(Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, Encode, Decode)]
-pub enum CommunityType<AccountId> {
+pub enum CommunityType<AccountId, Articles> {
 	/// Community Type.
 	Community,
 	/// A community of entities and people united by a commonality.
 	Nation,
 	/// A State is the next step of a Nation.
-	State(State<AccountId>),
+	State(State<AccountId, Articles>),
 }
 
 #[derive(Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, Encode, Decode)]
@@ -106,7 +325,7 @@ pub enum Category {
 	NFTGated,
 }
 
-impl<AccountId> Default for CommunityType<AccountId> {
+impl<AccountId, Articles> Default for CommunityType<AccountId, Articles> {
 	fn default() -> Self {
 		Self::Community
 	}
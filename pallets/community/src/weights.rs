@@ -29,15 +29,47 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_community.
 pub trait WeightInfo {
-	fn create_community() -> Weight;
+	fn create_community(m: u32, ) -> Weight;
 	fn update_community() -> Weight;
 	fn update_metadata() -> Weight;
-	fn accept_members() -> Weight;
 	fn join_community() -> Weight;
 	fn leave_community() -> Weight;
 	fn remove_member() -> Weight;
 	fn update_passport_metadata() -> Weight;
 	fn update_required_founder_balance() -> Weight;
+	fn request_to_join() -> Weight;
+	fn approve_join_request() -> Weight;
+	fn reject_join_request() -> Weight;
+	fn set_membership_gate() -> Weight;
+	fn revalidate_membership() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn propose_founder_transfer() -> Weight;
+	fn accept_founder_transfer() -> Weight;
+	fn dissolve_community() -> Weight;
+	fn slash_founder_bond() -> Weight;
+	fn spend() -> Weight;
+	fn spend_asset() -> Weight;
+	fn invite_member() -> Weight;
+	fn create_invite_code() -> Weight;
+	fn accept_invitation() -> Weight;
+	fn decline_invitation() -> Weight;
+	fn redeem_invite_code() -> Weight;
+	fn revoke_invitation() -> Weight;
+	fn ban_member() -> Weight;
+	fn unban_member() -> Weight;
+	fn apply_for_citizenship() -> Weight;
+	fn approve_citizenship() -> Weight;
+	fn reject_citizenship() -> Weight;
+	fn revoke_citizenship() -> Weight;
+	fn request_parent() -> Weight;
+	fn approve_sub_community() -> Weight;
+	fn reject_sub_community() -> Weight;
+	fn detach_sub_community() -> Weight;
+	fn set_membership_dues() -> Weight;
+	fn pay_dues() -> Weight;
+	fn claim_treasury_asset() -> Weight;
+	fn clean_up_community(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_community using the Substrate node and recommended hardware.
@@ -49,53 +81,76 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Whitelist::Founders` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Community::Nonce` (r:1 w:1)
-	/// Proof: `Community::Nonce` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `Community::ReferenceIdToCommunity` (r:1 w:1)
+	/// Proof: `Community::ReferenceIdToCommunity` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Community::CommunityAccount` (r:1 w:1)
 	/// Proof: `Community::CommunityAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn create_community() -> Weight {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Community::FounderBonds` (r:0 w:1)
+	/// Proof: `Community::FounderBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:2 w:2)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:100 w:100)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:0 w:100)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[0, 100]`.
+	fn create_community(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `253`
-		//  Estimated: `4079`
+		//  Estimated: `4079 + m * (2475 ±0)`
 		// Minimum execution time: 30_000_000 picoseconds.
-		Weight::from_parts(32_000_000, 4079)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(38_000_000, 4079)
+			// Standard Error: 11_000
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(m.into()))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_community() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 17_000_000 picoseconds.
 		Weight::from_parts(18_000_000, 4229)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 19_000_000 picoseconds.
 		Weight::from_parts(19_000_000, 4229)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: `Community::Communities` (r:1 w:1)
-	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn accept_members() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `764`
-		//  Estimated: `4229`
-		// Minimum execution time: 17_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 4229)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -110,35 +165,71 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:1 w:1)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:0 w:1)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:1 w:1)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::Bounties` (r:20 w:20)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::AttendedEvents` (r:1 w:1)
+	/// Proof: `EventPallet::AttendedEvents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::Events` (r:50 w:50)
+	/// Proof: `EventPallet::Events` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn leave_community() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 16_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 4229)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(76_u64))
+			.saturating_add(T::DbWeight::get().writes(77_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:1 w:1)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:0 w:1)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:1 w:1)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::Bounties` (r:20 w:20)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::AttendedEvents` (r:1 w:1)
+	/// Proof: `EventPallet::AttendedEvents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::Events` (r:50 w:50)
+	/// Proof: `EventPallet::Events` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn remove_member() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 17_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 4229)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(76_u64))
+			.saturating_add(T::DbWeight::get().writes(77_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_passport_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 17_000_000 picoseconds.
 		Weight::from_parts(18_000_000, 4229)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Community::RequiredFounderBalance` (r:0 w:1)
@@ -151,6 +242,594 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(8_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequestExpiry` (r:1 w:1)
+	/// Proof: `Community::JoinRequestExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_to_join() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequestExpiry` (r:1 w:1)
+	/// Proof: `Community::JoinRequestExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_join_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `538`
+		//  Estimated: `4003`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 4003)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequestExpiry` (r:1 w:1)
+	/// Proof: `Community::JoinRequestExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_join_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `538`
+		//  Estimated: `4003`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4003)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:0 w:1)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_membership_gate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:1 w:0)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revalidate_membership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `3963`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3963)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:0 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `3920`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3920)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `470`
+		//  Estimated: `3935`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3935)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::PendingFounderTransfers` (r:0 w:1)
+	/// Proof: `Community::PendingFounderTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_founder_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::PendingFounderTransfers` (r:1 w:1)
+	/// Proof: `Community::PendingFounderTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::RequiredFounderBalance` (r:1 w:0)
+	/// Proof: `Community::RequiredFounderBalance` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Communities` (r:1 w:1)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityAccount` (r:2 w:2)
+	/// Proof: `Community::CommunityAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:0 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:0)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_founder_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `742`
+		//  Estimated: `6207`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 6207)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:1)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityNameIndex` (r:0 w:1)
+	/// Proof: `Community::CommunityNameIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ReferenceIdToCommunity` (r:0 w:1)
+	/// Proof: `Community::ReferenceIdToCommunity` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityAccount` (r:1 w:1)
+	/// Proof: `Community::CommunityAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:0 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:0 w:1)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::PendingFounderTransfers` (r:0 w:1)
+	/// Proof: `Community::PendingFounderTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DissolvedCommunities` (r:0 w:1)
+	/// Proof: `Community::DissolvedCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::TreasuryClaimants` (r:0 w:1)
+	/// Proof: `Community::TreasuryClaimants` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:1 w:1)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn dissolve_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `617`
+		//  Estimated: `4082`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 4082)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::FounderBonds` (r:1 w:1)
+	/// Proof: `Community::FounderBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn slash_founder_bond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `721`
+		//  Estimated: `4186`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4186)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `6196`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn spend_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1104`
+		//  Estimated: `6208`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn invite_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `745`
+		//  Estimated: `4210`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4210)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_invite_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4167`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 4167)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:1 w:0)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `871`
+		//  Estimated: `4336`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4336)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn decline_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:1 w:0)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn redeem_invite_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `871`
+		//  Estimated: `4336`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 4336)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4167`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4167)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Bans` (r:0 w:1)
+	/// Proof: `Community::Bans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn ban_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `903`
+		//  Estimated: `4368`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 4368)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Bans` (r:1 w:1)
+	/// Proof: `Community::Bans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unban_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `689`
+		//  Estimated: `4154`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4154)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:1 w:0)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:1)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_for_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4277`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 4277)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:1)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:0 w:1)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `874`
+		//  Estimated: `4339`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4339)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:1)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `874`
+		//  Estimated: `4339`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4339)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:1 w:1)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:1 w:1)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `921`
+		//  Estimated: `4386`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 4386)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:2 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Parents` (r:3 w:0)
+	/// Proof: `Community::Parents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:0)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ParentRequests` (r:0 w:1)
+	/// Proof: `Community::ParentRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:2 w:0)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_parent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1146`
+		//  Estimated: `6078`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 6078)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:2 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ParentRequests` (r:1 w:1)
+	/// Proof: `Community::ParentRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Parents` (r:2 w:1)
+	/// Proof: `Community::Parents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:1)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:3 w:1)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_sub_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1214`
+		//  Estimated: `6146`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 6146)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ParentRequests` (r:1 w:1)
+	/// Proof: `Community::ParentRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_sub_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `845`
+		//  Estimated: `4310`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4310)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Parents` (r:1 w:1)
+	/// Proof: `Community::Parents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:0 w:1)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:2 w:1)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn detach_sub_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `903`
+		//  Estimated: `4368`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 4368)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:1)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_membership_dues() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520`
+		//  Estimated: `3985`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3985)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DuesPaidUntil` (r:1 w:1)
+	/// Proof: `Community::DuesPaidUntil` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pay_dues() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4277`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 4277)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::TreasuryClaimants` (r:1 w:0)
+	/// Proof: `Community::TreasuryClaimants` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn claim_treasury_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `6208`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(59_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:0)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:1 w:0)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:0)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Bans` (r:1 w:0)
+	/// Proof: `Community::Bans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:101 w:100)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:100 w:100)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DuesPaidUntil` (r:1 w:0)
+	/// Proof: `Community::DuesPaidUntil` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:0)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 100]`.
+	fn clean_up_community(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (97 ±0)`
+		//  Estimated: `3877 + n * (2572 ±0)`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3877)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2572).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -161,53 +840,76 @@ impl WeightInfo for () {
 	/// Proof: `Whitelist::Founders` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Community::Nonce` (r:1 w:1)
-	/// Proof: `Community::Nonce` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `Community::ReferenceIdToCommunity` (r:1 w:1)
+	/// Proof: `Community::ReferenceIdToCommunity` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Community::CommunityAccount` (r:1 w:1)
 	/// Proof: `Community::CommunityAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn create_community() -> Weight {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Community::FounderBonds` (r:0 w:1)
+	/// Proof: `Community::FounderBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:2 w:2)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:100 w:100)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:0 w:100)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[0, 100]`.
+	fn create_community(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `253`
-		//  Estimated: `4079`
+		//  Estimated: `4079 + m * (2475 ±0)`
 		// Minimum execution time: 30_000_000 picoseconds.
-		Weight::from_parts(32_000_000, 4079)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		Weight::from_parts(38_000_000, 4079)
+			// Standard Error: 11_000
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(m.into()))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_community() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 17_000_000 picoseconds.
 		Weight::from_parts(18_000_000, 4229)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 19_000_000 picoseconds.
 		Weight::from_parts(19_000_000, 4229)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: `Community::Communities` (r:1 w:1)
-	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn accept_members() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `764`
-		//  Estimated: `4229`
-		// Minimum execution time: 17_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 4229)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -222,35 +924,71 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:1 w:1)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:0 w:1)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:1 w:1)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::Bounties` (r:20 w:20)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::AttendedEvents` (r:1 w:1)
+	/// Proof: `EventPallet::AttendedEvents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::Events` (r:50 w:50)
+	/// Proof: `EventPallet::Events` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn leave_community() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 16_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 4229)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(76_u64))
+			.saturating_add(RocksDbWeight::get().writes(77_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:1 w:1)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:0 w:1)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::ParticipatedBounties` (r:1 w:1)
+	/// Proof: `Bounties::ParticipatedBounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Bounties::Bounties` (r:20 w:20)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::AttendedEvents` (r:1 w:1)
+	/// Proof: `EventPallet::AttendedEvents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EventPallet::Events` (r:50 w:50)
+	/// Proof: `EventPallet::Events` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn remove_member() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 17_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 4229)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(76_u64))
+			.saturating_add(RocksDbWeight::get().writes(77_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:1)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn update_passport_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `764`
 		//  Estimated: `4229`
 		// Minimum execution time: 17_000_000 picoseconds.
 		Weight::from_parts(18_000_000, 4229)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Community::RequiredFounderBalance` (r:0 w:1)
@@ -263,4 +1001,592 @@ impl WeightInfo for () {
 		Weight::from_parts(8_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequestExpiry` (r:1 w:1)
+	/// Proof: `Community::JoinRequestExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_to_join() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequestExpiry` (r:1 w:1)
+	/// Proof: `Community::JoinRequestExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_join_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `538`
+		//  Estimated: `4003`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 4003)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequestExpiry` (r:1 w:1)
+	/// Proof: `Community::JoinRequestExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_join_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `538`
+		//  Estimated: `4003`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4003)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:0 w:1)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_membership_gate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:1 w:0)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revalidate_membership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `3963`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3963)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:0 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `3920`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3920)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `470`
+		//  Estimated: `3935`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3935)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::PendingFounderTransfers` (r:0 w:1)
+	/// Proof: `Community::PendingFounderTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_founder_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::PendingFounderTransfers` (r:1 w:1)
+	/// Proof: `Community::PendingFounderTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::RequiredFounderBalance` (r:1 w:0)
+	/// Proof: `Community::RequiredFounderBalance` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Communities` (r:1 w:1)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityAccount` (r:2 w:2)
+	/// Proof: `Community::CommunityAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:0 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:0)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_founder_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `742`
+		//  Estimated: `6207`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 6207)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:1)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityNameIndex` (r:0 w:1)
+	/// Proof: `Community::CommunityNameIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ReferenceIdToCommunity` (r:0 w:1)
+	/// Proof: `Community::ReferenceIdToCommunity` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityAccount` (r:1 w:1)
+	/// Proof: `Community::CommunityAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:0 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:0 w:1)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::PendingFounderTransfers` (r:0 w:1)
+	/// Proof: `Community::PendingFounderTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DissolvedCommunities` (r:0 w:1)
+	/// Proof: `Community::DissolvedCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::MetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::TreasuryMetadataDeposits` (r:1 w:1)
+	/// Proof: `Community::TreasuryMetadataDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::TreasuryClaimants` (r:0 w:1)
+	/// Proof: `Community::TreasuryClaimants` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:1 w:1)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn dissolve_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `617`
+		//  Estimated: `4082`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 4082)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::FounderBonds` (r:1 w:1)
+	/// Proof: `Community::FounderBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn slash_founder_bond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `721`
+		//  Estimated: `4186`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 4186)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `6196`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn spend_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1104`
+		//  Estimated: `6208`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn invite_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `745`
+		//  Estimated: `4210`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4210)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_invite_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4167`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 4167)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:1 w:0)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `871`
+		//  Estimated: `4336`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4336)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn decline_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::GateRequirements` (r:1 w:0)
+	/// Proof: `Community::GateRequirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn redeem_invite_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `871`
+		//  Estimated: `4336`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 4336)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:1 w:1)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4167`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4167)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:1)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:1)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:1)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:1)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:1 w:1)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Bans` (r:0 w:1)
+	/// Proof: `Community::Bans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn ban_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `903`
+		//  Estimated: `4368`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 4368)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Bans` (r:1 w:1)
+	/// Proof: `Community::Bans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unban_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `689`
+		//  Estimated: `4154`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4154)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:1 w:0)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:1)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_for_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4277`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 4277)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:1)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:0 w:1)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `874`
+		//  Estimated: `4339`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4339)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:1)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `874`
+		//  Estimated: `4339`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4339)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:1 w:1)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:1 w:1)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_citizenship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `921`
+		//  Estimated: `4386`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 4386)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::Communities` (r:2 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Parents` (r:3 w:0)
+	/// Proof: `Community::Parents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:0)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ParentRequests` (r:0 w:1)
+	/// Proof: `Community::ParentRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:2 w:0)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_parent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1146`
+		//  Estimated: `6078`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 6078)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:2 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ParentRequests` (r:1 w:1)
+	/// Proof: `Community::ParentRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Parents` (r:2 w:1)
+	/// Proof: `Community::Parents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:1)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:3 w:1)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_sub_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1214`
+		//  Estimated: `6146`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 6146)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::ParentRequests` (r:1 w:1)
+	/// Proof: `Community::ParentRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_sub_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `845`
+		//  Estimated: `4310`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4310)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Parents` (r:1 w:1)
+	/// Proof: `Community::Parents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:0 w:1)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DescendantCount` (r:2 w:1)
+	/// Proof: `Community::DescendantCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn detach_sub_community() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `903`
+		//  Estimated: `4368`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 4368)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:1)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_membership_dues() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520`
+		//  Estimated: `3985`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3985)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DuesPaidUntil` (r:1 w:1)
+	/// Proof: `Community::DuesPaidUntil` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pay_dues() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4277`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 4277)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Community::TreasuryClaimants` (r:1 w:0)
+	/// Proof: `Community::TreasuryClaimants` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn claim_treasury_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `6208`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(59_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::JoinRequests` (r:1 w:0)
+	/// Proof: `Community::JoinRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Citizens` (r:1 w:0)
+	/// Proof: `Community::Citizens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CitizenshipApplications` (r:1 w:0)
+	/// Proof: `Community::CitizenshipApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Bans` (r:1 w:0)
+	/// Proof: `Community::Bans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Invitations` (r:101 w:100)
+	/// Proof: `Community::Invitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::InvitationExpiry` (r:100 w:100)
+	/// Proof: `Community::InvitationExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::DuesPaidUntil` (r:1 w:0)
+	/// Proof: `Community::DuesPaidUntil` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:0)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 100]`.
+	fn clean_up_community(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (97 ±0)`
+		//  Estimated: `3877 + n * (2572 ±0)`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3877)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2572).saturating_mul(n.into()))
+	}
 }
\ No newline at end of file
@@ -3,6 +3,7 @@ use super::*;
 
 // use crate::Pallet;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
 use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 use pallet_community::types::{
@@ -10,6 +11,7 @@ use pallet_community::types::{
 	Traditions, Values,
 };
 use pallet_passport::types::BadgesType;
+use sp_runtime::Saturating;
 use sp_std::vec;
 use sp_std::vec::Vec;
 const SEED: u32 = 0;
@@ -81,7 +83,7 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 			"Jur is the core community of the Jur ecosystem, which includes all the contributors."
 				.into(),
 		),
-		Some(members),
+		Some(members.clone()),
 		Some(get_community_metadata::<T>()),
 		Category::Public,
 		Some("tag".into()),
@@ -90,11 +92,24 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in members {
+		pallet_community::Pallet::<T>::accept_invitation(
+			RawOrigin::Signed(member).into(),
+			community_id,
+		)
+		.unwrap();
+	}
 
 	community_id
 }
 
 pub fn add_founder<T: Config>(caller: T::AccountId) {
+	// Enough balance to reserve the metadata deposit of the community.
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		&caller,
+		<T as pallet_community::Config>::MetadataDepositPerByte::get()
+			.saturating_mul(100_000u32.into()),
+	);
 	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller).unwrap();
 }
 
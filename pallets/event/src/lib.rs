@@ -17,6 +17,9 @@ pub use pallet::*;
 mod types;
 use crate::types::{EventDetails, EventType};
 use frame_support::{dispatch::DispatchResultWithPostInfo, BoundedVec};
+use pallet_community::{
+	types::Permissions, CommunityPermissions, OnCommunityDissolved, OnMemberLeft,
+};
 use pallet_passport::Passports;
 use primitives::Incrementable;
 use sp_std::vec::Vec;
@@ -29,16 +32,21 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
-
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
+const LOG_TARGET: &str = "runtime::events";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[cfg(feature = "runtime-benchmarks")]
 	pub trait BenchmarkHelper<EventId> {
 		fn event(i: u32) -> EventId;
@@ -81,6 +89,7 @@ pub mod pallet {
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -108,6 +117,19 @@ pub mod pallet {
 	pub type NextEventId<T: Config> =
 		StorageMap<_, Twox64Concat, T::CommunityId, T::EventId, OptionQuery>;
 
+	/// Store the events an account attended
+	#[pallet::storage]
+	pub type AttendedEvents<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CommunityId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::EventId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -145,7 +167,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Create a new event for a particular community from a origin.
 		///
-		/// The origin must be Signed and the community founder.
+		/// The origin must be Signed and hold the `MANAGE_EVENTS` permission in the community.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community.
@@ -173,11 +195,20 @@ pub mod pallet {
 			badge: BoundedVec<u8, T::BadgeNameLimit>,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
-			let community = pallet_community::Communities::<T>::get(community_id)
-				.ok_or(Error::<T>::CommunityDoesNotExist)?;
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
 
-			// Ensuring the event creator should be founder.
-			ensure!(origin == community.founder, Error::<T>::NotAllowed);
+			// Ensuring the event creator is allowed to manage events.
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::MANAGE_EVENTS
+				),
+				Error::<T>::NotAllowed
+			);
 
 			// Ensuring the event badge should exist in the community badge directory.
 			let is_badge_exist = pallet_passport::Badges::<T>::get(community_id, &badge);
@@ -201,7 +232,8 @@ pub mod pallet {
 
 		/// Issue Proof of presence in the event as passport badge.
 		///
-		/// The origin must be Signed and the community founder of the community.
+		/// The origin must be Signed and either an admin or hold the `CHECK_IN_ATTENDEES`
+		/// permission in the community. The founder can not issue the proof of presence.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community.
@@ -225,16 +257,24 @@ pub mod pallet {
 			// Ensuring the badge issuer should not be the founder of the community
 			ensure!(origin != community.founder, Error::<T>::NotAllowed);
 
-			// Ensure the origin should be admin.
+			// Ensure the origin should be admin or allowed to check in attendees.
 			ensure!(
 				pallet_whitelist::Admins::<T>::get()
 					.binary_search(&origin)
-					.is_ok(),
+					.is_ok()
+					|| pallet_community::Pallet::<T>::has_permission(
+						community_id,
+						&origin,
+						Permissions::CHECK_IN_ATTENDEES
+					),
 				Error::<T>::NotAllowed
 			);
 
 			// Ensuring the member should be the of community.
-			ensure!(community.members.contains(&member), Error::<T>::MemberDoesNotExist);
+			ensure!(
+				pallet_community::Pallet::<T>::is_member(community_id, &member),
+				Error::<T>::MemberDoesNotExist
+			);
 
 			let event_data =
 				<Events<T>>::get(community_id, event_id).ok_or(Error::<T>::EventDoesNotExist)?;
@@ -254,6 +294,8 @@ pub mod pallet {
 				Error::<T>::BadgeAlreadyIssued
 			);
 
+			AttendedEvents::<T>::insert((community_id, &member, event_id), ());
+
 			// Adding the member to the attendees list of the event.
 			Events::<T>::try_mutate(community_id, event_id, |event_details| -> DispatchResult {
 				let event = event_details
@@ -270,21 +312,7 @@ pub mod pallet {
 			})?;
 
 			// Issuing the badge to the member
-			Passports::<T>::try_mutate(
-				community_id,
-				member.clone(),
-				|passport_details| -> DispatchResult {
-					let passport = passport_details
-						.as_mut()
-						.ok_or(Error::<T>::PassportNotAvailable)?;
-
-					let mut badges = passport.badges.clone();
-					badges.push(event_badge.clone());
-					passport.badges = badges;
-
-					Ok(())
-				},
-			)?;
+			pallet_passport::Pallet::<T>::do_issue_badge(community_id, &member, &event_badge)?;
 
 			Self::deposit_event(Event::IssuedBadge(community_id, event_id, member));
 			Ok(())
@@ -329,3 +357,35 @@ impl<T: Config> Pallet<T> {
 		Ok(().into())
 	}
 }
+
+impl<T: Config> OnCommunityDissolved<T::CommunityId> for Pallet<T> {
+	fn on_community_dissolved(community_id: T::CommunityId, limit: u32) -> u32 {
+		let mut removed = Events::<T>::drain_prefix(community_id)
+			.take(limit as usize)
+			.count() as u32;
+
+		if removed < limit {
+			removed += AttendedEvents::<T>::drain_prefix((community_id,))
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			NextEventId::<T>::remove(community_id);
+		}
+
+		removed
+	}
+}
+
+impl<T: Config> OnMemberLeft<T::CommunityId, T::AccountId> for Pallet<T> {
+	fn on_member_left(community_id: T::CommunityId, who: &T::AccountId) {
+		for (event_id, ()) in AttendedEvents::<T>::drain_prefix((community_id, who)) {
+			Events::<T>::mutate(community_id, event_id, |maybe_event| {
+				if let Some(event) = maybe_event {
+					event.attendees_list.retain(|attendee| attendee != who);
+				}
+			});
+		}
+	}
+}
@@ -0,0 +1,95 @@
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use log;
+use sp_runtime::Saturating;
+
+pub mod v1 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Index the attendees of the existing events in `AttendedEvents`.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 0 {
+				let mut reads = 0u64;
+				let mut indexed = 0u64;
+
+				for (community_id, event_id, event) in Events::<T>::iter() {
+					reads.saturating_inc();
+
+					for attendee in event.attendees_list.iter() {
+						AttendedEvents::<T>::insert((community_id, attendee, event_id), ());
+						indexed.saturating_inc();
+					}
+				}
+
+				StorageVersion::new(1).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Events indexed {} attendances, storage to version 1",
+					indexed
+				);
+				T::DbWeight::get().reads_writes(reads + 1, indexed + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Events Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"must upgrade linearly"
+			);
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must_upgrade");
+
+			for (community_id, event_id, event) in Events::<T>::iter() {
+				for attendee in event.attendees_list.iter() {
+					frame_support::ensure!(
+						AttendedEvents::<T>::contains_key((community_id, attendee, event_id)),
+						"attendance not indexed"
+					);
+				}
+			}
+
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "try-runtime")]
+mod test {
+	use super::*;
+	use crate::mock::{Test as T, *};
+	use frame_support::pallet_prelude::StorageVersion;
+
+	#[test]
+	fn migration_to_v1_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<T>>();
+			crate::tests::attend_event();
+			let _ = AttendedEvents::<T>::clear(u32::MAX, None);
+
+			let state = v1::MigrateToV1::<T>::pre_upgrade().unwrap();
+			let _w = v1::MigrateToV1::<T>::on_runtime_upgrade();
+			v1::MigrateToV1::<T>::post_upgrade(state).unwrap();
+
+			assert!(AttendedEvents::<T>::contains_key((1, 3, 1)));
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
+		});
+	}
+}
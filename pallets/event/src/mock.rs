@@ -1,9 +1,11 @@
 use crate as pallet_events;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, ConstU64},
+	PalletId,
 };
 use frame_system as system;
+use pallet_passport::types::PassportLeavePolicy;
 use primitives::Balance;
 use sp_core::H256;
 use sp_runtime::{
@@ -24,6 +26,7 @@ frame_support::construct_runtime!(
 		Passport: pallet_passport,
 		Whitelist: pallet_whitelist,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 	}
 );
 
@@ -69,12 +72,31 @@ impl pallet_community::Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type WeightInfo = ();
-	type MyRandomness = RandomnessCollectiveFlip;
 	type TagLimit = ConstU32<50>;
 	type ColorLimit = ConstU32<7>;
 	type CommunityLimit = ConstU32<3>;
 	type StringLimit = ConstU32<250>;
 	type LogoLimit = ConstU32<60>;
+	type MaxArticles = ConstU32<10>;
+	type ArticleLimit = ConstU32<256>;
+	type JoinRequestTtl = ConstU64<10>;
+	type MaxJoinRequestsPerBlock = ConstU32<10>;
+	type InvitationTtl = ConstU64<10>;
+	type MaxInvitationsPerBlock = ConstU32<10>;
+	type MembershipGate = ();
+	type OnCommunityDissolved = (EventPallet, Passport);
+	type DissolveCleanupLimit = ConstU32<10>;
+	type SlashOrigin = frame_system::EnsureRoot<u64>;
+	type OnSlash = ();
+	type OnCitizenshipRevoked = Passport;
+	type PalletId = CommunityPalletId;
+	type Assets = Assets;
+	type MaxHierarchyDepth = ConstU32<4>;
+	type MaxDescendants = ConstU32<10>;
+	type OnMemberJoined = Passport;
+	type OnMemberLeft = (Passport, EventPallet);
+	type MetadataDepositPerByte = ConstU128<0>;
+	type CommunityOrigin = pallet_community::EnsureCommunity<Test>;
 }
 
 impl pallet_whitelist::Config for Test {
@@ -82,12 +104,18 @@ impl pallet_whitelist::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const LeavePolicy: PassportLeavePolicy = PassportLeavePolicy::Keep;
+}
+
 impl pallet_passport::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type PassportId = u32;
 	type BadgeNameLimit = ConstU32<20>;
 	type DescriptionLimit = ConstU32<250>;
 	type AddressLimit = ConstU32<60>;
+	type MintPassportOnJoin = ConstBool<false>;
+	type LeavePolicy = LeavePolicy;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type WeightInfo = ();
@@ -124,6 +152,31 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ();
 }
 
+parameter_types! {
+	pub const CommunityPalletId: PalletId = PalletId(*b"jur/cmty");
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities = system::GenesisConfig::<Test>::default()
@@ -1,8 +1,8 @@
 use crate::types::EventType;
-use crate::{mock::*, Error, Events};
-use frame_support::assert_noop;
+use crate::{mock::*, AttendedEvents, Error, Events};
 use frame_support::pallet_prelude::ConstU32;
 use frame_support::BoundedVec;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
 use pallet_community::types::{
 	Category, CommunityMetaData, CommunityType, Customs, Languages, Religions, Territories,
 	Traditions, Values,
@@ -66,6 +66,7 @@ fn add_admin() {
 }
 
 fn create_community() {
+	let community_id = pallet_community::NextCommunityId::<Test>::get().unwrap_or(1);
 	Community::create_community(
 		RuntimeOrigin::signed(1),
 		// hash of IPFS path of dummy logo
@@ -84,6 +85,9 @@ fn create_community() {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in [2, 3, 4, 5] {
+		Community::accept_invitation(RuntimeOrigin::signed(member), community_id).unwrap();
+	}
 }
 
 fn add_badge() {
@@ -364,3 +368,69 @@ fn proof_of_presence_not_works_for_non_passport_holder() {
 		);
 	});
 }
+
+pub fn create_event() -> DispatchResultWithPostInfo {
+	let event_name: Vec<u8> = "DEV Force".into();
+	let bounded_event_name: BoundedVec<u8, ConstU32<512>> = event_name.try_into().unwrap();
+
+	let event_description: Vec<u8> = "Event for the jur community members".into();
+	let bounded_events_description: BoundedVec<u8, ConstU32<8192>> =
+		event_description.try_into().unwrap();
+
+	let badge_name: Vec<u8> = "EVENT".into();
+	let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
+
+	EventPallet::create_event(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_event_name,
+		bounded_events_description,
+		1703745212,
+		1703831612,
+		EventType::Virtual,
+		None,
+		bounded_badge_name,
+	)
+}
+
+pub fn attend_event() {
+	mint_passport_with_badge();
+	add_admin();
+	create_event().unwrap();
+
+	EventPallet::proof_of_presence(RuntimeOrigin::signed(2), 1, 1, 3).unwrap();
+}
+
+#[test]
+fn leaving_community_removes_event_attendee() {
+	new_test_ext().execute_with(|| {
+		attend_event();
+		assert_eq!(Events::<Test>::get(1, 1).unwrap().attendees_list, vec![3]);
+		assert!(AttendedEvents::<Test>::contains_key((1, 3, 1)));
+
+		Community::leave_community(RuntimeOrigin::signed(3), 1).unwrap();
+
+		assert!(Events::<Test>::get(1, 1).unwrap().attendees_list.is_empty());
+		assert!(!AttendedEvents::<Test>::contains_key((1, 3, 1)));
+	});
+}
+
+#[test]
+fn proof_of_presence_works_after_many_attended_events() {
+	new_test_ext().execute_with(|| {
+		mint_passport_with_badge();
+		add_admin();
+		assert_ok!(create_event());
+
+		for event_id in 100..150 {
+			AttendedEvents::<Test>::insert((1, 3, event_id), ());
+		}
+
+		assert_ok!(EventPallet::proof_of_presence(RuntimeOrigin::signed(2), 1, 1, 3));
+		assert!(AttendedEvents::<Test>::contains_key((1, 3, 1)));
+
+		Community::leave_community(RuntimeOrigin::signed(3), 1).unwrap();
+
+		assert_eq!(AttendedEvents::<Test>::iter_prefix((1, 3)).count(), 0);
+	});
+}
@@ -54,14 +54,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 
+	/// Storage: `EventPallet::AttendedEvents` (r:0 w:1)
+	/// Proof: `EventPallet::AttendedEvents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:1 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn proof_of_presence() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1197`
 		//  Estimated: `4662`
 		// Minimum execution time: 27_000_000 picoseconds.
 		Weight::from_parts(39_000_000, 4662)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
@@ -85,13 +91,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 
+	/// Storage: `EventPallet::AttendedEvents` (r:0 w:1)
+	/// Proof: `EventPallet::AttendedEvents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:1 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn proof_of_presence() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1197`
 		//  Estimated: `4662`
 		// Minimum execution time: 27_000_000 picoseconds.
 		Weight::from_parts(39_000_000, 4662)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
\ No newline at end of file
@@ -5,12 +5,14 @@ use super::*;
 use crate::types::BadgesType;
 use crate::Pallet as Passport;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
 use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 use pallet_community::types::{
 	Category, CommunityMetaData, CommunityType, Customs, Languages, Religions, Territories,
 	Traditions, Values,
 };
+use sp_runtime::Saturating;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -83,7 +85,7 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 			"Jur is the core community of the Jur ecosystem, which includes all the contributors."
 				.into(),
 		),
-		Some(members),
+		Some(members.clone()),
 		Some(get_community_metadata::<T>()),
 		Category::Public,
 		Some("tag".into()),
@@ -92,11 +94,24 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in members {
+		pallet_community::Pallet::<T>::accept_invitation(
+			RawOrigin::Signed(member).into(),
+			community_id,
+		)
+		.unwrap();
+	}
 
 	community_id
 }
 
 pub fn add_founder<T: Config>(caller: T::AccountId) {
+	// Enough balance to reserve the metadata deposit of the community.
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		&caller,
+		<T as pallet_community::Config>::MetadataDepositPerByte::get()
+			.saturating_mul(100_000u32.into()),
+	);
 	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller).unwrap();
 }
 
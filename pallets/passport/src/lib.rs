@@ -12,6 +12,9 @@
 //! You won’t be able to mint the NFT in case
 //! your wallet is not part of the citizens property of the State.
 //!
+//! Passports follow the membership of their holder: depending on the runtime configuration
+//! a passport is minted when an account joins a community, and suspended or removed when
+//! it leaves.
 //!
 //! ## Interface
 //!
@@ -24,7 +27,12 @@
 
 pub use pallet::*;
 pub mod types;
-use crate::types::{BadgeDetails, BadgesType, PassportDetails};
+use crate::types::{BadgeDetails, BadgesType, PassportDetails, PassportLeavePolicy};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, BoundedVec};
+use pallet_community::{
+	types::{CommunityType, Permissions},
+	CommunityPermissions, OnCitizenshipRevoked, OnCommunityDissolved, OnMemberJoined, OnMemberLeft,
+};
 use primitives::Incrementable;
 use sp_std::vec;
 
@@ -51,7 +59,7 @@ pub mod pallet {
 	use sp_std::vec::Vec;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[cfg(feature = "runtime-benchmarks")]
 	pub trait BenchmarkHelper<PassportId> {
@@ -85,6 +93,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type AddressLimit: Get<u32>;
 
+		/// Whether a passport is minted for the accounts joining a community.
+		#[pallet::constant]
+		type MintPassportOnJoin: Get<bool>;
+
+		/// What happens to the passport of the accounts leaving a community.
+		#[pallet::constant]
+		type LeavePolicy: Get<PassportLeavePolicy>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type Helper: BenchmarkHelper<Self::PassportId>;
@@ -130,6 +146,37 @@ pub mod pallet {
 	pub type NextPassportId<T: Config> =
 		StorageMap<_, Twox64Concat, T::CommunityId, T::PassportId, OptionQuery>;
 
+	/// Store the passports suspended since their holder left the community
+	#[pallet::storage]
+	#[pallet::getter(fn suspended_passports)]
+	pub type SuspendedPassports<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Store the number of passports of a community, the suspended ones are not counted
+	#[pallet::storage]
+	pub type PassportCount<T: Config> =
+		StorageMap<_, Twox64Concat, T::CommunityId, u32, ValueQuery>;
+
+	/// Store the number of passports of a community holding a badge, the suspended ones are
+	/// not counted
+	#[pallet::storage]
+	pub type BadgeHolderCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CommunityId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::BadgeNameLimit>,
+		u32,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -143,6 +190,12 @@ pub mod pallet {
 		IssuedBadge(Vec<u8>),
 		/// Migrated Passport [passport]
 		MigratedPassport(T::PassportId),
+		/// Suspended the passport of a leaving member [community, account]
+		SuspendedPassport(T::CommunityId, T::AccountId),
+		/// Reinstated the passport of a returning member [community, account]
+		ReinstatedPassport(T::CommunityId, T::AccountId),
+		/// Removed the passport of a leaving member [community, account]
+		RemovedPassport(T::CommunityId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -165,6 +218,14 @@ pub mod pallet {
 		BadgeAlreadyIssued,
 		/// Community id is not valid.
 		InvalidCommunityId,
+		/// Account is banned from the community.
+		Banned,
+		/// Account is not a citizen of the State.
+		NotCitizen,
+		/// Passport is suspended.
+		PassportSuspended,
+		/// Membership dues of the account are lapsed.
+		DuesLapsed,
 	}
 
 	#[pallet::hooks]
@@ -185,36 +246,10 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::mint())]
 		pub fn mint(origin: OriginFor<T>, community_id: T::CommunityId) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-			let community = pallet_community::Communities::<T>::get(community_id)
-				.ok_or(Error::<T>::CommunityDoesNotExist)?;
-
-			ensure!(
-				origin == community.founder || community.members.contains(&origin),
-				Error::<T>::MemberDoesNotExist
-			);
-
-			let maybe_passport = Passports::<T>::get(community_id, &origin);
-			ensure!(maybe_passport.is_none(), Error::<T>::PassportAlreadyMinted);
-
-			let mut passport_id =
-				NextPassportId::<T>::get(community_id).unwrap_or(T::PassportId::initial_value());
-
-			// Adding this check to reserve the slots for community
-			if community_id == T::CommunityId::initial_value()
-				&& passport_id < T::PassportId::jur_community_reserve_slots()
-			{
-				passport_id = T::PassportId::jur_community_reserve_slots();
-			}
-
-			let passport_details =
-				PassportDetails { id: passport_id, address: None, badges: vec![] };
 
-			<Passports<T>>::insert(community_id, &origin, passport_details);
+			Self::ensure_can_mint(community_id, &origin)?;
+			Self::do_mint(community_id, &origin);
 
-			let next_id = passport_id.increment();
-			NextPassportId::<T>::insert(community_id, next_id);
-
-			Self::deposit_event(Event::MintedPassport(passport_id));
 			Ok(())
 		}
 
@@ -240,7 +275,8 @@ pub mod pallet {
 				.ok_or(Error::<T>::CommunityDoesNotExist)?;
 
 			ensure!(
-				origin == community.founder || community.members.contains(&origin),
+				origin == community.founder
+					|| pallet_community::Pallet::<T>::is_member(community_id, &origin),
 				Error::<T>::MemberDoesNotExist
 			);
 
@@ -260,7 +296,8 @@ pub mod pallet {
 
 		/// Add badge to the community badge directory.
 		///
-		/// The origin must be Signed and the founder of the community.
+		/// The origin must be Signed and hold the `ISSUE_BADGES` permission in the community, or
+		/// be the community itself.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community.
@@ -281,11 +318,21 @@ pub mod pallet {
 			description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
 			address: BoundedVec<u8, T::AddressLimit>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let community = pallet_community::Communities::<T>::get(community_id)
-				.ok_or(Error::<T>::CommunityDoesNotExist)?;
+			let origin =
+				pallet_community::Pallet::<T>::ensure_community_origin(origin, community_id)?;
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
 
-			ensure!(origin == community.founder, Error::<T>::NotAllowed);
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::ISSUE_BADGES
+				),
+				Error::<T>::NotAllowed
+			);
 
 			let maybe_badge = Badges::<T>::get(community_id, &name);
 			ensure!(maybe_badge.is_none(), Error::<T>::BadgeAlreadyExist);
@@ -300,7 +347,8 @@ pub mod pallet {
 
 		/// Issue the badge to the members.
 		///
-		/// The origin must be Signed and the community founder of the community.
+		/// The origin must be Signed and hold the `ISSUE_BADGES` permission in the community, or
+		/// be the community itself.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community.
@@ -317,15 +365,55 @@ pub mod pallet {
 			name: BoundedVec<u8, T::BadgeNameLimit>,
 			members: Vec<T::AccountId>,
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			let community = pallet_community::Communities::<T>::get(community_id)
-				.ok_or(Error::<T>::CommunityDoesNotExist)?;
+			let origin =
+				pallet_community::Pallet::<T>::ensure_community_origin(origin, community_id)?;
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
 
-			// Ensuring the badge issuer should be the founder of the community
-			ensure!(origin == community.founder, Error::<T>::NotAllowed);
+			// Ensuring the badge issuer is allowed to issue badges in the community
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::ISSUE_BADGES
+				),
+				Error::<T>::NotAllowed
+			);
 
 			// checking the badge is available in the badge directory or not
-			<Badges<T>>::get(community_id, &name).ok_or(Error::<T>::BadgeNotAvailable)?;
+			let badge =
+				<Badges<T>>::get(community_id, &name).ok_or(Error::<T>::BadgeNotAvailable)?;
+
+			// The visa of a State is only issued to its citizens
+			if badge.badge_type == BadgesType::Visa
+				&& pallet_community::Pallet::<T>::state_of(community_id).is_ok()
+			{
+				ensure!(
+					members
+						.iter()
+						.all(|member| pallet_community::Pallet::<T>::is_citizen(
+							community_id,
+							member
+						)),
+					Error::<T>::NotCitizen
+				);
+			}
+
+			ensure!(
+				!members
+					.iter()
+					.any(|member| SuspendedPassports::<T>::contains_key(community_id, member)),
+				Error::<T>::PassportSuspended
+			);
+
+			ensure!(
+				!members
+					.iter()
+					.any(|member| pallet_community::Pallet::<T>::is_lapsed(community_id, member)),
+				Error::<T>::DuesLapsed
+			);
 
 			// Ensuring the members should have the passport and dont have the same badge
 			ensure!(
@@ -347,24 +435,7 @@ pub mod pallet {
 
 			// Issuing the badge to the members
 			for member in members {
-				Passports::<T>::try_mutate(
-					community_id,
-					member,
-					|passport_details| -> DispatchResult {
-						let passport = passport_details
-							.as_mut()
-							.ok_or(Error::<T>::PassportNotAvailable)?;
-
-						let mut badges = passport.badges.clone();
-
-						if !badges.contains(&name) {
-							badges.push(name.clone());
-							passport.badges = badges;
-						}
-
-						Ok(())
-					},
-				)?;
+				Self::do_issue_badge(community_id, &member, &name)?;
 			}
 
 			Self::deposit_event(Event::IssuedBadge(name.to_vec()));
@@ -410,25 +481,20 @@ pub mod pallet {
 			);
 
 			// Add the user as community member.
-			pallet_community::Communities::<T>::try_mutate(
-				community_id,
-				|maybe_community| -> DispatchResult {
-					let community = maybe_community
-						.as_mut()
-						.ok_or(Error::<T>::CommunityDoesNotExist)?;
-
-					let mut community_members = community.members.clone();
-
-					// If user not part of community then adding to community
-					if !community_members.contains(&account_id) {
-						community_members.push(account_id.clone());
-					}
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
 
-					community.members = community_members;
+			ensure!(
+				!pallet_community::Pallet::<T>::is_banned(community_id, &account_id),
+				Error::<T>::Banned
+			);
 
-					Ok(())
-				},
-			)?;
+			// If user not part of community then adding to community
+			if !pallet_community::Pallet::<T>::is_member(community_id, &account_id) {
+				pallet_community::Pallet::<T>::do_add_member(community_id, &account_id);
+			}
 
 			// Validate the badges in the community badges library.
 			ensure!(
@@ -445,10 +511,239 @@ pub mod pallet {
 			let passport_details =
 				PassportDetails { id: passport_id, address: Some(address), badges };
 
-			<Passports<T>>::insert(community_id, &account_id, passport_details);
+			<Passports<T>>::insert(community_id, &account_id, &passport_details);
+			Self::count_passport(community_id, &passport_details.badges, true);
 
 			Self::deposit_event(Event::MigratedPassport(passport_id));
 			Ok(())
 		}
 	}
 }
+
+impl<T: Config> Pallet<T> {
+	/// The passport of the account honoured by the community.
+	///
+	/// Falls back to the passport of a parent community when the community inherits
+	/// the passports of its parent.
+	pub fn passport_of(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+	) -> Option<PassportDetails<T::PassportId, T::BadgeNameLimit, T::AddressLimit>> {
+		Passports::<T>::get(community_id, who).or_else(|| {
+			let passport_community =
+				pallet_community::Pallet::<T>::passport_community(community_id);
+			Passports::<T>::get(passport_community, who)
+		})
+	}
+
+	/// The passport of the account honoured by the community, unless it is suspended.
+	pub fn active_passport_of(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+	) -> Option<PassportDetails<T::PassportId, T::BadgeNameLimit, T::AddressLimit>> {
+		let active = |community_id: T::CommunityId| {
+			Passports::<T>::get(community_id, who)
+				.filter(|_| !SuspendedPassports::<T>::contains_key(community_id, who))
+		};
+
+		active(community_id)
+			.or_else(|| active(pallet_community::Pallet::<T>::passport_community(community_id)))
+	}
+
+	/// Ensure the account may mint a passport of the community.
+	fn ensure_can_mint(community_id: T::CommunityId, who: &T::AccountId) -> DispatchResult {
+		let community = pallet_community::Communities::<T>::get(community_id)
+			.ok_or(Error::<T>::CommunityDoesNotExist)?;
+
+		ensure!(
+			*who == community.founder
+				|| pallet_community::Pallet::<T>::is_member(community_id, who),
+			Error::<T>::MemberDoesNotExist
+		);
+
+		ensure!(!pallet_community::Pallet::<T>::is_banned(community_id, who), Error::<T>::Banned);
+
+		// Only the citizens of a State can redeem its passport.
+		if let Some(CommunityType::State(_)) = community.community_type {
+			ensure!(
+				*who == community.founder
+					|| pallet_community::Pallet::<T>::is_citizen(community_id, who),
+				Error::<T>::NotCitizen
+			);
+		}
+
+		// An inherited passport of the parent community counts as well.
+		let maybe_passport = Self::passport_of(community_id, who);
+		ensure!(maybe_passport.is_none(), Error::<T>::PassportAlreadyMinted);
+
+		Ok(())
+	}
+
+	/// Mint a new passport of the community for the account.
+	fn do_mint(community_id: T::CommunityId, who: &T::AccountId) {
+		let mut passport_id =
+			NextPassportId::<T>::get(community_id).unwrap_or(T::PassportId::initial_value());
+
+		// Adding this check to reserve the slots for community
+		if community_id == T::CommunityId::initial_value()
+			&& passport_id < T::PassportId::jur_community_reserve_slots()
+		{
+			passport_id = T::PassportId::jur_community_reserve_slots();
+		}
+
+		let passport_details = PassportDetails { id: passport_id, address: None, badges: vec![] };
+
+		<Passports<T>>::insert(community_id, who, passport_details);
+		Self::count_passport(community_id, &[], true);
+
+		let next_id = passport_id.increment();
+		NextPassportId::<T>::insert(community_id, next_id);
+
+		Self::deposit_event(Event::MintedPassport(passport_id));
+	}
+
+	/// Issue the badge to the passport of the account, unless the passport already holds it.
+	pub fn do_issue_badge(
+		community_id: T::CommunityId,
+		who: &T::AccountId,
+		badge: &BoundedVec<u8, T::BadgeNameLimit>,
+	) -> DispatchResult {
+		Passports::<T>::try_mutate(community_id, who, |passport_details| -> DispatchResult {
+			let passport = passport_details
+				.as_mut()
+				.ok_or(Error::<T>::PassportNotAvailable)?;
+
+			if !passport.badges.contains(badge) {
+				passport.badges.push(badge.clone());
+
+				if !SuspendedPassports::<T>::contains_key(community_id, who) {
+					BadgeHolderCount::<T>::mutate(community_id, badge, |count| {
+						*count = count.saturating_add(1)
+					});
+				}
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Add a passport with its badges to the counts of the community, or remove it.
+	fn count_passport(
+		community_id: T::CommunityId,
+		badges: &[BoundedVec<u8, T::BadgeNameLimit>],
+		active: bool,
+	) {
+		let update = |count: &mut u32| {
+			*count = if active { count.saturating_add(1) } else { count.saturating_sub(1) }
+		};
+
+		PassportCount::<T>::mutate(community_id, |count| update(count));
+		for badge in badges {
+			BadgeHolderCount::<T>::mutate(community_id, badge, |count| update(count));
+		}
+	}
+}
+
+impl<T: Config> OnMemberJoined<T::CommunityId, T::AccountId> for Pallet<T> {
+	fn on_member_joined(community_id: T::CommunityId, who: &T::AccountId) {
+		if SuspendedPassports::<T>::take(community_id, who).is_some() {
+			if let Some(passport) = Passports::<T>::get(community_id, who) {
+				Self::count_passport(community_id, &passport.badges, true);
+			}
+			Self::deposit_event(Event::ReinstatedPassport(community_id, who.clone()));
+			return;
+		}
+
+		// Accounts which may not hold a passport, e.g. non-citizens of a State, are skipped.
+		if T::MintPassportOnJoin::get() && Self::ensure_can_mint(community_id, who).is_ok() {
+			Self::do_mint(community_id, who);
+		}
+	}
+}
+
+impl<T: Config> OnMemberLeft<T::CommunityId, T::AccountId> for Pallet<T> {
+	fn on_member_left(community_id: T::CommunityId, who: &T::AccountId) {
+		let passport = match Passports::<T>::get(community_id, who) {
+			Some(passport) => passport,
+			None => return,
+		};
+		let suspended = SuspendedPassports::<T>::contains_key(community_id, who);
+
+		match T::LeavePolicy::get() {
+			PassportLeavePolicy::Keep => {},
+			PassportLeavePolicy::Suspend => {
+				if !suspended {
+					Self::count_passport(community_id, &passport.badges, false);
+				}
+				SuspendedPassports::<T>::insert(
+					community_id,
+					who,
+					frame_system::Pallet::<T>::block_number(),
+				);
+				Self::deposit_event(Event::SuspendedPassport(community_id, who.clone()));
+			},
+			PassportLeavePolicy::Remove => {
+				if !suspended {
+					Self::count_passport(community_id, &passport.badges, false);
+				}
+				Passports::<T>::remove(community_id, who);
+				Self::deposit_event(Event::RemovedPassport(community_id, who.clone()));
+			},
+		}
+	}
+}
+
+impl<T: Config> OnCommunityDissolved<T::CommunityId> for Pallet<T> {
+	fn on_community_dissolved(community_id: T::CommunityId, limit: u32) -> u32 {
+		let mut removed = Passports::<T>::drain_prefix(community_id)
+			.take(limit as usize)
+			.count() as u32;
+
+		if removed < limit {
+			removed += Badges::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			removed += SuspendedPassports::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			removed += BadgeHolderCount::<T>::drain_prefix(community_id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32;
+		}
+
+		if removed < limit {
+			NextPassportId::<T>::remove(community_id);
+			PassportCount::<T>::remove(community_id);
+		}
+
+		removed
+	}
+}
+
+impl<T: Config> OnCitizenshipRevoked<T::CommunityId, T::AccountId> for Pallet<T> {
+	fn on_citizenship_revoked(community_id: T::CommunityId, who: &T::AccountId) {
+		let suspended = SuspendedPassports::<T>::contains_key(community_id, who);
+
+		Passports::<T>::mutate(community_id, who, |maybe_passport| {
+			if let Some(passport) = maybe_passport {
+				// The visas of a State are withdrawn together with the citizenship.
+				passport.badges.retain(|name| {
+					let is_visa = Badges::<T>::get(community_id, name)
+						.map_or(false, |badge| badge.badge_type == BadgesType::Visa);
+					if is_visa && !suspended {
+						BadgeHolderCount::<T>::mutate(community_id, name, |count| {
+							*count = count.saturating_sub(1)
+						});
+					}
+					!is_visa
+				});
+			}
+		});
+	}
+}
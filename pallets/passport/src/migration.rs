@@ -18,10 +18,9 @@ pub mod v1 {
 	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
 	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
 		fn on_runtime_upgrade() -> Weight {
-			let current_version = Pallet::<T>::current_storage_version();
 			let onchain_version = Pallet::<T>::on_chain_storage_version();
 
-			if onchain_version == 0 && current_version == 1 {
+			if onchain_version == 0 {
 				let mut translated = 0u64;
 				Passports::<T>::translate::<OldPassportDetails<T::PassportId, T::AddressLimit>, _>(
 					|_key, _key2, old_value| {
@@ -35,12 +34,11 @@ pub mod v1 {
 					},
 				);
 
-				current_version.put::<Pallet<T>>();
+				StorageVersion::new(1).put::<Pallet<T>>();
 				log::info!(
 					target: LOG_TARGET,
-					"Passport Upgraded {} pools, storage to version {:?}",
-					translated,
-					current_version
+					"Passport Upgraded {} pools, storage to version 1",
+					translated
 				);
 				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
 			} else {
@@ -73,14 +71,86 @@ pub mod v1 {
 				"the community count before and after the migration should be the same"
 			);
 
-			let current_version = Pallet::<T>::current_storage_version();
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Count the passports of every community and the passports holding each badge.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
 			let onchain_version = Pallet::<T>::on_chain_storage_version();
 
-			frame_support::ensure!(current_version == 1, "must_upgrade");
-			assert_eq!(
-				current_version, onchain_version,
-				"after migration, the current_version and onchain_version should be the same"
+			if onchain_version == 1 {
+				let mut counted = 0u64;
+				let mut writes = 0u64;
+
+				for (community_id, who, passport) in Passports::<T>::iter() {
+					counted.saturating_inc();
+
+					// The suspended passports are counted again once reinstated.
+					if SuspendedPassports::<T>::contains_key(community_id, &who) {
+						continue;
+					}
+
+					PassportCount::<T>::mutate(community_id, |count| {
+						*count = count.saturating_add(1)
+					});
+					for badge in passport.badges.iter() {
+						BadgeHolderCount::<T>::mutate(community_id, badge, |count| {
+							*count = count.saturating_add(1)
+						});
+					}
+					writes = writes.saturating_add(passport.badges.len() as u64 + 1);
+				}
+
+				StorageVersion::new(2).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Passport counted {} passports, storage to version 2",
+					counted
+				);
+				T::DbWeight::get().reads_writes(counted * 2 + writes + 1, writes + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Passport Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"must upgrade linearly"
 			);
+			let active_count = Passports::<T>::iter_keys()
+				.filter(|(community_id, who)| {
+					!SuspendedPassports::<T>::contains_key(community_id, who)
+				})
+				.count();
+			Ok((active_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(active_count: Vec<u8>) -> Result<(), &'static str> {
+			let active_count: u32 = Decode::decode(&mut active_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = PassportCount::<T>::iter_values().sum::<u32>();
+			assert_eq!(active_count, post_count, "every active passport should be counted");
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 2, "must_upgrade");
 
 			Ok(())
 		}
@@ -92,7 +162,11 @@ pub mod v1 {
 mod test {
 	use super::*;
 	use crate::mock::{Test as T, *};
-	use frame_support::pallet_prelude::StorageVersion;
+	use frame_support::{
+		assert_ok,
+		pallet_prelude::{ConstU32, StorageVersion},
+		BoundedVec,
+	};
 
 	#[test]
 	fn migration_works() {
@@ -114,4 +188,32 @@ mod test {
 			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
 		});
 	}
+
+	#[test]
+	fn migration_to_v2_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			crate::tests::mint_passport();
+			crate::tests::add_badge();
+			let badge_name: BoundedVec<u8, ConstU32<20>> =
+				BoundedVec::truncate_from(b"JUR Meetup".to_vec());
+			assert_ok!(Passport::issue_badge(
+				RuntimeOrigin::signed(1),
+				1,
+				badge_name.clone(),
+				vec![2]
+			));
+			let _ = PassportCount::<T>::clear(u32::MAX, None);
+			let _ = BadgeHolderCount::<T>::clear(u32::MAX, None);
+
+			let state = v2::MigrateToV2::<T>::pre_upgrade().unwrap();
+			let _w = v2::MigrateToV2::<T>::on_runtime_upgrade();
+			v2::MigrateToV2::<T>::post_upgrade(state).unwrap();
+
+			assert_eq!(PassportCount::<T>::get(1), 1);
+			assert_eq!(BadgeHolderCount::<T>::get(1, badge_name), 1);
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+		});
+	}
 }
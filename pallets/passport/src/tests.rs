@@ -1,11 +1,16 @@
-use crate::{mock::*, types::BadgesType, Error, Passports};
+use crate::{
+	mock::*,
+	types::{BadgesType, PassportLeavePolicy},
+	BadgeHolderCount, Badges, Error, NextPassportId, PassportCount, Passports, SuspendedPassports,
+};
 use frame_support::pallet_prelude::ConstU32;
 use frame_support::BoundedVec;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 use pallet_community::types::{
-	Category, CommunityMetaData, CommunityType, Customs, Languages, Religions, Territories,
-	Traditions, Values,
+	Category, CommunityMetaData, CommunityOrigin, CommunityType, Customs, DuesAmount, Languages,
+	Permissions, RecurringDue, Religions, State, Territories, Traditions, Values,
 };
+use sp_runtime::DispatchError;
 
 fn get_community_metadata() -> CommunityMetaData<ConstU32<250>> {
 	let custom_one: Vec<u8> =
@@ -64,6 +69,7 @@ pub fn add_admin() {
 }
 
 fn create_community() {
+	let community_id = pallet_community::NextCommunityId::<Test>::get().unwrap_or(1);
 	Community::create_community(
 		RuntimeOrigin::signed(1),
 		// hash of IPFS path of dummy logo
@@ -82,15 +88,43 @@ fn create_community() {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in [2] {
+		Community::accept_invitation(RuntimeOrigin::signed(member), community_id).unwrap();
+	}
+}
+
+fn create_state() {
+	let community_id = pallet_community::NextCommunityId::<Test>::get().unwrap_or(1);
+	Community::create_community(
+		RuntimeOrigin::signed(1),
+		None,
+		"Jur State".into(),
+		None,
+		Some(vec![1, 2, 3]),
+		None,
+		Category::Public,
+		None,
+		None,
+		None,
+		Some(CommunityType::State(State {
+			constitution: Default::default(),
+			government: vec![],
+			citizens: vec![3],
+		})),
+	)
+	.unwrap();
+	for member in [2] {
+		Community::accept_invitation(RuntimeOrigin::signed(member), community_id).unwrap();
+	}
 }
 
-fn mint_passport() {
+pub fn mint_passport() {
 	add_founder();
 	create_community();
 	Passport::mint(RuntimeOrigin::signed(2), 1).unwrap();
 }
 
-fn add_badge() {
+pub fn add_badge() {
 	let badge_name: Vec<u8> = "JUR Meetup".into();
 	let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
 
@@ -382,6 +416,44 @@ fn add_badge_not_work_for_invalid_founder() {
 	});
 }
 
+#[test]
+fn add_badge_works_for_delegated_role() {
+	new_test_ext().execute_with(|| {
+		mint_passport();
+
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Badge Issuer".into(),
+			Permissions::ISSUE_BADGES
+		));
+
+		let badge_name: Vec<u8> = "JUR Meetup".into();
+		let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
+
+		let badge_description: Vec<u8> =
+			"JUR Meetup is the get together time for the jur community".into();
+		let bounded_badge_description: BoundedVec<u8, ConstU32<250>> =
+			badge_description.try_into().unwrap();
+
+		let badge_address: Vec<u8> =
+			"abcdreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into();
+		let bounded_badge_address: BoundedVec<u8, ConstU32<60>> = badge_address.try_into().unwrap();
+
+		assert_ok!(Passport::add_badge(
+			RuntimeOrigin::signed(2),
+			1,
+			bounded_badge_name.clone(),
+			BadgesType::Participation,
+			bounded_badge_description,
+			bounded_badge_address
+		));
+
+		assert_ok!(Passport::issue_badge(RuntimeOrigin::signed(2), 1, bounded_badge_name, vec![2]));
+	});
+}
+
 #[test]
 fn add_badge_not_work_for_badge_already_exist() {
 	new_test_ext().execute_with(|| {
@@ -452,6 +524,54 @@ fn issue_badge() {
 	});
 }
 
+#[test]
+fn badge_works_for_community_origin() {
+	new_test_ext().execute_with(|| {
+		mint_passport();
+
+		let badge_name: Vec<u8> = "JUR Meetup".into();
+		let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
+
+		let badge_description: Vec<u8> =
+			"JUR Meetup is the get together time for the jur community".into();
+		let bounded_badge_description: BoundedVec<u8, ConstU32<250>> =
+			badge_description.try_into().unwrap();
+
+		let badge_address: Vec<u8> =
+			"abcdreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into();
+		let bounded_badge_address: BoundedVec<u8, ConstU32<60>> = badge_address.try_into().unwrap();
+
+		assert_noop!(
+			Passport::add_badge(
+				RuntimeOrigin::from(CommunityOrigin(2)),
+				1,
+				bounded_badge_name.clone(),
+				BadgesType::Participation,
+				bounded_badge_description.clone(),
+				bounded_badge_address.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Passport::add_badge(
+			RuntimeOrigin::from(CommunityOrigin(1)),
+			1,
+			bounded_badge_name.clone(),
+			BadgesType::Participation,
+			bounded_badge_description,
+			bounded_badge_address
+		));
+		assert!(Badges::<Test>::contains_key(1, &bounded_badge_name));
+
+		assert_ok!(Passport::issue_badge(
+			RuntimeOrigin::from(CommunityOrigin(1)),
+			1,
+			bounded_badge_name,
+			vec![2]
+		));
+	});
+}
+
 #[test]
 fn issue_badge_not_work_invalid_community() {
 	new_test_ext().execute_with(|| {
@@ -775,3 +895,213 @@ fn passport_migration_not_works_for_invalid_badge_name() {
 		);
 	});
 }
+
+#[test]
+fn passports_are_removed_when_community_is_dissolved() {
+	new_test_ext().execute_with(|| {
+		mint_passport();
+		add_badge();
+		assert!(Passports::<Test>::get(1, 2).is_some());
+
+		assert_ok!(Community::dissolve_community(RuntimeOrigin::signed(1), 1));
+		Community::on_idle(1, Weight::MAX);
+
+		assert!(Passports::<Test>::get(1, 2).is_none());
+		assert_eq!(Badges::<Test>::iter_prefix(1).count(), 0);
+		assert!(NextPassportId::<Test>::get(1).is_none());
+	});
+}
+
+#[test]
+fn passport_migration_not_works_for_banned_account() {
+	new_test_ext().execute_with(|| {
+		add_admin();
+		add_founder();
+		create_community();
+		add_badge();
+
+		assert_ok!(Community::ban_member(RuntimeOrigin::signed(1), 1, 11, None, None));
+
+		let passport_address: Vec<u8> =
+			"abcdreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into();
+		let bounded_passport_address: BoundedVec<u8, ConstU32<60>> =
+			passport_address.try_into().unwrap();
+
+		assert_noop!(
+			Passport::migrate_passport(
+				RuntimeOrigin::signed(2),
+				1,
+				11,
+				10,
+				bounded_passport_address,
+				vec![]
+			),
+			Error::<Test>::Banned
+		);
+	});
+}
+
+#[test]
+fn state_passport_and_visa_require_citizenship() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_state();
+
+		assert_noop!(Passport::mint(RuntimeOrigin::signed(2), 1), Error::<Test>::NotCitizen);
+		assert_ok!(Passport::mint(RuntimeOrigin::signed(3), 1));
+
+		let badge_name: Vec<u8> = "Visa".into();
+		let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
+
+		assert_ok!(Passport::add_badge(
+			RuntimeOrigin::signed(1),
+			1,
+			bounded_badge_name.clone(),
+			BadgesType::Visa,
+			Default::default(),
+			Default::default(),
+		));
+
+		assert_noop!(
+			Passport::issue_badge(RuntimeOrigin::signed(1), 1, bounded_badge_name.clone(), vec![2]),
+			Error::<Test>::NotCitizen
+		);
+		assert_ok!(Passport::issue_badge(
+			RuntimeOrigin::signed(1),
+			1,
+			bounded_badge_name.clone(),
+			vec![3]
+		));
+		assert_eq!(Passports::<Test>::get(1, 3).unwrap().badges, vec![bounded_badge_name]);
+
+		// Revoking the citizenship withdraws the visa.
+		assert_ok!(Community::revoke_citizenship(RuntimeOrigin::signed(1), 1, 3));
+		assert!(Passports::<Test>::get(1, 3).unwrap().badges.is_empty());
+	});
+}
+
+#[test]
+fn sub_community_honours_inherited_passport() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		Community::create_community(
+			RuntimeOrigin::signed(1),
+			None,
+			"Jur Sub".into(),
+			None,
+			Some(vec![1, 2]),
+			None,
+			Category::Public,
+			None,
+			None,
+			None,
+			Some(CommunityType::Community),
+		)
+		.unwrap();
+		assert_ok!(Community::accept_invitation(RuntimeOrigin::signed(2), 2));
+
+		assert_ok!(Passport::mint(RuntimeOrigin::signed(2), 1));
+		assert!(Passport::passport_of(2, &2).is_none());
+
+		assert_ok!(Community::request_parent(RuntimeOrigin::signed(1), 2, 1, false, true));
+		assert_ok!(Community::approve_sub_community(RuntimeOrigin::signed(1), 1, 2));
+
+		assert_eq!(Passport::passport_of(2, &2), Passports::<Test>::get(1, 2));
+		assert_noop!(
+			Passport::mint(RuntimeOrigin::signed(2), 2),
+			Error::<Test>::PassportAlreadyMinted
+		);
+	});
+}
+
+#[test]
+fn passport_minted_on_join() {
+	new_test_ext().execute_with(|| {
+		MintPassportOnJoin::set(true);
+		add_founder();
+		create_community();
+
+		assert!(Passports::<Test>::get(1, 2).is_some());
+		assert!(Passports::<Test>::get(1, 3).is_none());
+
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(3), 1));
+		assert!(Passports::<Test>::get(1, 3).is_some());
+	});
+}
+
+#[test]
+fn passport_suspended_on_leave_and_reinstated_on_join() {
+	new_test_ext().execute_with(|| {
+		LeavePolicy::set(PassportLeavePolicy::Suspend);
+		mint_passport();
+		add_badge();
+
+		assert_ok!(Community::leave_community(RuntimeOrigin::signed(2), 1));
+		assert!(Passports::<Test>::get(1, 2).is_some());
+		assert!(SuspendedPassports::<Test>::contains_key(1, 2));
+
+		let badge_name: Vec<u8> = "JUR Meetup".into();
+		let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
+		assert_noop!(
+			Passport::issue_badge(RuntimeOrigin::signed(1), 1, bounded_badge_name.clone(), vec![2]),
+			Error::<Test>::PassportSuspended
+		);
+
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(2), 1));
+		assert!(!SuspendedPassports::<Test>::contains_key(1, 2));
+		assert_ok!(Passport::issue_badge(
+			RuntimeOrigin::signed(1),
+			1,
+			bounded_badge_name.clone(),
+			vec![2]
+		));
+		assert_eq!(PassportCount::<Test>::get(1), 1);
+		assert_eq!(BadgeHolderCount::<Test>::get(1, &bounded_badge_name), 1);
+
+		// The suspended passports are not counted.
+		assert_ok!(Community::leave_community(RuntimeOrigin::signed(2), 1));
+		assert_eq!(PassportCount::<Test>::get(1), 0);
+		assert_eq!(BadgeHolderCount::<Test>::get(1, &bounded_badge_name), 0);
+
+		assert_ok!(Community::join_community(RuntimeOrigin::signed(2), 1));
+		assert_eq!(PassportCount::<Test>::get(1), 1);
+		assert_eq!(BadgeHolderCount::<Test>::get(1, &bounded_badge_name), 1);
+	});
+}
+
+#[test]
+fn passport_removed_on_leave() {
+	new_test_ext().execute_with(|| {
+		LeavePolicy::set(PassportLeavePolicy::Remove);
+		mint_passport();
+
+		assert_eq!(PassportCount::<Test>::get(1), 1);
+
+		assert_ok!(Community::remove_member(RuntimeOrigin::signed(1), 2, 1));
+		assert!(Passports::<Test>::get(1, 2).is_none());
+		assert_eq!(PassportCount::<Test>::get(1), 0);
+	});
+}
+
+#[test]
+fn issue_badge_not_works_for_lapsed_member() {
+	new_test_ext().execute_with(|| {
+		mint_passport();
+		add_badge();
+		assert_ok!(Community::set_membership_dues(
+			RuntimeOrigin::signed(1),
+			1,
+			None,
+			Some(RecurringDue { amount: DuesAmount::Native(10), period: 1 })
+		));
+		System::set_block_number(3);
+
+		let badge_name: Vec<u8> = "JUR Meetup".into();
+		let bounded_badge_name: BoundedVec<u8, ConstU32<20>> = badge_name.try_into().unwrap();
+		assert_noop!(
+			Passport::issue_badge(RuntimeOrigin::signed(1), 1, bounded_badge_name, vec![2]),
+			Error::<Test>::DuesLapsed
+		);
+	});
+}
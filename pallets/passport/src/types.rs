@@ -20,6 +20,17 @@ pub struct BadgeDetails<DescriptionLimit: Get<u32>, AddressLimit: Get<u32>> {
 	pub address: BoundedVec<u8, AddressLimit>,
 }
 
+/// What happens to the passport of an account leaving the community.
+#[derive(Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, Encode, Decode)]
+pub enum PassportLeavePolicy {
+	/// The passport stays as it is.
+	Keep,
+	/// The passport is suspended until the account joins the community again.
+	Suspend,
+	/// The passport is removed together with its badges.
+	Remove,
+}
+
 #[derive(Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, Encode, Decode)]
 pub enum BadgesType {
 	/// Participation badge.
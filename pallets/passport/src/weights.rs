@@ -45,14 +45,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Passport::NextPassportId` (r:1 w:1)
 	/// Proof: `Passport::NextPassportId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `806`
 		//  Estimated: `4271`
 		// Minimum execution time: 23_000_000 picoseconds.
 		Weight::from_parts(24_000_000, 4271)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -86,14 +88,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Passport::Passports` (r:1 w:1)
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:1 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn issue_badge() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1134`
 		//  Estimated: `4599`
 		// Minimum execution time: 30_000_000 picoseconds.
 		Weight::from_parts(31_000_000, 4599)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Whitelist::Admins` (r:1 w:0)
 	/// Proof: `Whitelist::Admins` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -103,14 +109,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Passport::Passports` (r:1 w:1)
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn migrate_passport() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1099`
 		//  Estimated: `4564`
 		// Minimum execution time: 30_000_000 picoseconds.
 		Weight::from_parts(31_000_000, 4564)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
@@ -122,14 +132,16 @@ impl WeightInfo for () {
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Passport::NextPassportId` (r:1 w:1)
 	/// Proof: `Passport::NextPassportId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `806`
 		//  Estimated: `4271`
 		// Minimum execution time: 23_000_000 picoseconds.
 		Weight::from_parts(24_000_000, 4271)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -163,14 +175,18 @@ impl WeightInfo for () {
 	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Passport::Passports` (r:1 w:1)
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:1 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn issue_badge() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1134`
 		//  Estimated: `4599`
 		// Minimum execution time: 30_000_000 picoseconds.
 		Weight::from_parts(31_000_000, 4599)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Whitelist::Admins` (r:1 w:0)
 	/// Proof: `Whitelist::Admins` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -180,13 +196,17 @@ impl WeightInfo for () {
 	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Passport::Passports` (r:1 w:1)
 	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:1 w:1)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:1 w:1)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn migrate_passport() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1099`
 		//  Estimated: `4564`
 		// Minimum execution time: 30_000_000 picoseconds.
 		Weight::from_parts(31_000_000, 4564)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
\ No newline at end of file
@@ -5,11 +5,14 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Proposal;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{fungibles, Currency, Get};
 use frame_system::RawOrigin;
 use pallet_community::types::{
-	Category, CommunityMetaData, CommunityType, Customs, Languages, Religions, Territories,
-	Traditions, Values,
+	Category, CommunityMetaData, CommunityType, ConstitutionAmendment, Customs, Languages,
+	Religions, State, Territories, Traditions, Values,
 };
+use pallet_community::BenchmarkHelper as CommunityBenchmarkHelper;
+use sp_runtime::Saturating;
 use sp_std::vec;
 
 const SEED: u32 = 0;
@@ -67,6 +70,12 @@ fn get_community_metadata<T: Config>() -> CommunityMetaData<T::StringLimit> {
 }
 
 pub fn add_founder<T: Config>(caller: T::AccountId) {
+	// Enough balance to reserve the metadata deposit of the community.
+	pallet_balances::Pallet::<T>::make_free_balance_be(
+		&caller,
+		<T as pallet_community::Config>::MetadataDepositPerByte::get()
+			.saturating_mul(100_000u32.into()),
+	);
 	pallet_whitelist::Pallet::<T>::add_founder(RawOrigin::Root.into(), caller).unwrap();
 }
 
@@ -74,7 +83,7 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 	let community_id =
 		pallet_community::NextCommunityId::<T>::get().unwrap_or(T::CommunityId::initial_value());
 
-	let members = Some(vec![account("sub", 1, SEED)]);
+	let members = vec![account("sub", 1, SEED)];
 
 	pallet_community::Pallet::<T>::create_community(
 		RawOrigin::Signed(caller).into(),
@@ -85,7 +94,7 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 			"Jur is the core community of the Jur ecosystem, which includes all the contributors."
 				.into(),
 		),
-		members,
+		Some(members.clone()),
 		Some(get_community_metadata::<T>()),
 		Category::Public,
 		Some("tag".into()),
@@ -94,6 +103,50 @@ fn create_community<T: Config>(caller: T::AccountId) -> T::CommunityId {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in members {
+		pallet_community::Pallet::<T>::accept_invitation(
+			RawOrigin::Signed(member).into(),
+			community_id,
+		)
+		.unwrap();
+	}
+
+	community_id
+}
+
+fn create_state<T: Config>(caller: T::AccountId) -> T::CommunityId {
+	let community_id =
+		pallet_community::NextCommunityId::<T>::get().unwrap_or(T::CommunityId::initial_value());
+
+	let members: Vec<T::AccountId> = vec![account("sub", 1, SEED)];
+
+	pallet_community::Pallet::<T>::create_community(
+		RawOrigin::Signed(caller).into(),
+		None,
+		"Jur State".as_bytes().to_vec(),
+		None,
+		Some(members.clone()),
+		None,
+		Category::Public,
+		None,
+		None,
+		None,
+		Some(CommunityType::State(State {
+			constitution: vec![b"Article one".to_vec().try_into().unwrap()]
+				.try_into()
+				.unwrap(),
+			government: vec![],
+			citizens: vec![],
+		})),
+	)
+	.unwrap();
+	for member in members {
+		pallet_community::Pallet::<T>::accept_invitation(
+			RawOrigin::Signed(member).into(),
+			community_id,
+		)
+		.unwrap();
+	}
 
 	community_id
 }
@@ -124,6 +177,10 @@ fn add_proposal<T: Config>(caller: T::AccountId) -> (T::CommunityId, T::Proposal
 		],
 		false,
 		5,
+		None,
+		VotingMode::OneMemberOneVote,
+		VoterEligibility::AnyMember,
+		true,
 	)
 	.unwrap();
 
@@ -132,6 +189,57 @@ fn add_proposal<T: Config>(caller: T::AccountId) -> (T::CommunityId, T::Proposal
 	(community_id, proposal_id, choice_id)
 }
 
+/// Create a proposal weighted by a new asset held by `member`.
+fn add_asset_stake<T: Config>(
+	caller: T::AccountId,
+	member: T::AccountId,
+	community_id: T::CommunityId,
+	asset: AssetIdOf<T>,
+) -> (BoundedVec<u8, <T as pallet::Config>::LabelLimit>, T::Balance) {
+	let amount: T::Balance = 1_000_000u32.into();
+
+	<T::Assets as fungibles::Create<T::AccountId>>::create(
+		asset.clone(),
+		caller.clone(),
+		true,
+		1u32.into(),
+	)
+	.unwrap();
+	<T::Assets as fungibles::Mutate<T::AccountId>>::mint_into(
+		asset.clone(),
+		&member,
+		1_000_000_000u32.into(),
+	)
+	.unwrap();
+	Proposal::<T>::set_voting_asset(
+		RawOrigin::Signed(caller.clone()).into(),
+		community_id,
+		Some(asset.clone()),
+	)
+	.unwrap();
+
+	let proposal_name: Vec<u8> = "Jur treasury proposal".into();
+	let proposal_description: Vec<u8> = "Description of Jur treasury proposal".into();
+
+	Proposal::<T>::create_proposal(
+		RawOrigin::Signed(caller).into(),
+		community_id,
+		proposal_name.try_into().unwrap(),
+		proposal_description.try_into().unwrap(),
+		vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+		false,
+		5,
+		None,
+		VotingMode::Asset { asset_id: asset, conviction: true },
+		VoterEligibility::AnyMember,
+		true,
+	)
+	.unwrap();
+
+	let choice: Vec<u8> = "Yes".into();
+	(choice.try_into().unwrap(), amount)
+}
+
 benchmarks! {
 	create_proposal {
 		let caller: T::AccountId = whitelisted_caller();
@@ -158,7 +266,11 @@ benchmarks! {
 			"Rust".as_bytes().to_vec()
 		],
 		false,
-		5
+		5,
+		None,
+		VotingMode::OneMemberOneVote,
+		VoterEligibility::AnyMember,
+		false
 	)
 	verify {
 		assert_last_event::<T>(Event::<T>::CreatedProposal(<T as pallet::Config>::Helper::proposal(1)).into());
@@ -178,5 +290,278 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::VoteCasted(proposal_id).into());
 	}
 
+	propose_constitution_amendment {
+		let caller: T::AccountId = whitelisted_caller();
+		add_founder::<T>(caller.clone());
+		let community_id = create_state::<T>(caller.clone());
+
+		let proposal_name: Vec<u8> = "Jur constitution amendment".into();
+		let bounded_proposal_name: BoundedVec<u8, <T as pallet::Config>::NameLimit> =
+		proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur constitution amendment".into();
+		let bounded_proposal_description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit> =
+		proposal_description.try_into().unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller),
+		community_id,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		ConstitutionAmendment::AddArticle(b"Article two".to_vec().try_into().unwrap()),
+		5
+	)
+	verify {
+		assert!(ConstitutionAmendments::<T>::contains_key(<T as pallet::Config>::Helper::proposal(1)));
+	}
+
+	create_nation_wide_proposal {
+		let caller: T::AccountId = whitelisted_caller();
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+
+		let proposal_name: Vec<u8> = "Jur nation-wide proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, <T as pallet::Config>::NameLimit> =
+		proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur nation-wide proposal".into();
+		let bounded_proposal_description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit> =
+		proposal_description.try_into().unwrap();
+
+	}: _(
+		RawOrigin::Signed(caller),
+		community_id,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		vec![
+			"English".as_bytes().to_vec(),
+			"Ghukliak".as_bytes().to_vec(),
+			"官话".as_bytes().to_vec(),
+			"Rust".as_bytes().to_vec()
+		],
+		false,
+		5,
+		None,
+		VotingMode::OneMemberOneVote,
+		VoterEligibility::AnyMember,
+		false
+	)
+	verify {
+		assert!(NationWideProposals::<T>::contains_key(<T as pallet::Config>::Helper::proposal(1)));
+	}
+
+	service_expiry_block {
+		let now = frame_system::Pallet::<T>::block_number();
+	}: {
+		ProposalExpiryQueue::<T>::service(now, 1, |_| {});
+	}
+	verify {
+		assert_eq!(ProposalExpiryCursor::<T>::get(), Some(now.saturating_add(1u32.into())));
+	}
+
+	close_proposal {
+		let caller: T::AccountId = whitelisted_caller();
+		add_founder::<T>(caller.clone());
+		let community_id = create_state::<T>(caller.clone());
+		let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+		let proposal_name: Vec<u8> = "Jur constitution amendment".into();
+		let bounded_proposal_name: BoundedVec<u8, <T as pallet::Config>::NameLimit> =
+		proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur constitution amendment".into();
+		let bounded_proposal_description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit> =
+		proposal_description.try_into().unwrap();
+
+		Proposal::<T>::propose_constitution_amendment(
+			RawOrigin::Signed(caller.clone()).into(),
+			community_id,
+			bounded_proposal_name,
+			bounded_proposal_description,
+			ConstitutionAmendment::AddArticle(b"Article two".to_vec().try_into().unwrap()),
+			5
+		)?;
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, <T as pallet::Config>::LabelLimit> =
+		choice.try_into().unwrap();
+
+		Proposal::<T>::cast_vote(RawOrigin::Signed(caller).into(), community_id, proposal_id, bounded_choice)?;
+	}: {
+		Proposal::<T>::do_close_proposal(proposal_id, community_id)?;
+	}
+	verify {
+		assert_last_event::<T>(Event::<T>::ConstitutionAmendmentApplied(proposal_id, Ok(())).into());
+	}
+
+	set_proposal_threshold {
+		let caller: T::AccountId = whitelisted_caller();
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+
+		let threshold = ProposalThreshold {
+			approval: ApprovalThreshold::SuperMajority(Perbill::from_percent(66)),
+			quorum: Some(Perbill::from_percent(50)),
+		};
+
+	}: _(RawOrigin::Signed(caller), community_id, Some(threshold))
+	verify {
+		assert_last_event::<T>(Event::<T>::UpdatedProposalThreshold(community_id).into());
+	}
+
+	cast_weighted_vote {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+		let asset = <T as pallet_community::Config>::Helper::asset(1);
+		let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+		let (choice, amount) = add_asset_stake::<T>(caller.clone(), member.clone(), community_id, asset);
+
+	}: _(RawOrigin::Signed(member.clone()), community_id, proposal_id, choice, amount, Conviction::Locked6x)
+	verify {
+		assert_last_event::<T>(Event::<T>::VoteStakeLocked(proposal_id, member, amount).into());
+	}
+
+	unlock_vote {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+		let asset = <T as pallet_community::Config>::Helper::asset(1);
+		let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+		let (choice, amount) = add_asset_stake::<T>(caller, member.clone(), community_id, asset);
+
+		Proposal::<T>::cast_weighted_vote(
+			RawOrigin::Signed(member.clone()).into(),
+			community_id,
+			proposal_id,
+			choice,
+			amount,
+			Conviction::None
+		)?;
+		Proposal::<T>::do_close_proposal(proposal_id, community_id)?;
+		let (_, ends_at) = WeightedProposals::<T>::get(proposal_id).unwrap();
+		frame_system::Pallet::<T>::set_block_number(ends_at);
+
+	}: _(RawOrigin::Signed(member.clone()), proposal_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::VoteStakeUnlocked(proposal_id, member, amount).into());
+	}
+
+	change_vote {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+		let (community_id, proposal_id, _) = add_proposal::<T>(caller);
+
+		let choice: Vec<u8> = "India".into();
+		Proposal::<T>::cast_vote(
+			RawOrigin::Signed(member.clone()).into(),
+			community_id,
+			proposal_id,
+			choice.try_into().unwrap()
+		)?;
+
+		let choice: Vec<u8> = "Germany".into();
+		let bounded_choice: BoundedVec<u8, <T as pallet::Config>::LabelLimit> =
+		choice.try_into().unwrap();
+
+	}: _(RawOrigin::Signed(member), community_id, proposal_id, bounded_choice)
+	verify {
+		assert_last_event::<T>(Event::<T>::VoteChanged(proposal_id).into());
+	}
+
+	retract_vote {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("sub", 1, SEED);
+		let asset = <T as pallet_community::Config>::Helper::asset(1);
+		let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+		let (choice, amount) = add_asset_stake::<T>(caller, member.clone(), community_id, asset);
+
+		Proposal::<T>::cast_weighted_vote(
+			RawOrigin::Signed(member.clone()).into(),
+			community_id,
+			proposal_id,
+			choice,
+			amount,
+			Conviction::Locked6x
+		)?;
+
+	}: _(RawOrigin::Signed(member.clone()), community_id, proposal_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::VoteRetracted(proposal_id).into());
+		assert!(!StakedVotes::<T>::contains_key(member, proposal_id));
+	}
+
+	set_voting_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+		let asset = <T as pallet_community::Config>::Helper::asset(1);
+
+	}: _(RawOrigin::Signed(caller), community_id, Some(asset))
+	verify {
+		assert_last_event::<T>(Event::<T>::UpdatedVotingAsset(community_id).into());
+	}
+
+	propose_call {
+		let caller: T::AccountId = whitelisted_caller();
+		add_founder::<T>(caller.clone());
+		let community_id = create_community::<T>(caller.clone());
+
+		let threshold = ProposalThreshold {
+			approval: ApprovalThreshold::SimpleMajority,
+			quorum: Some(Perbill::from_percent(50)),
+		};
+		Proposal::<T>::set_proposal_threshold(
+			RawOrigin::Signed(caller.clone()).into(),
+			community_id,
+			Some(threshold)
+		)?;
+		let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+		let proposal_name: Vec<u8> = "Jur community remark".into();
+		let bounded_proposal_name: BoundedVec<u8, <T as pallet::Config>::NameLimit> =
+		proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur community remark".into();
+		let bounded_proposal_description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit> =
+		proposal_description.try_into().unwrap();
+
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![0; 64] }.into();
+
+	}: _(
+		RawOrigin::Signed(caller),
+		community_id,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		Box::new(call),
+		Some(10u32.into()),
+		5
+	)
+	verify {
+		assert!(ProposalCalls::<T>::contains_key(proposal_id));
+	}
+
+	enact_proposal {
+		let community_id = <T as pallet_community::Config>::Helper::community(1);
+		let proposal_id = <T as pallet::Config>::Helper::proposal(1);
+
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![0; 64] }.into();
+		let encoded_call: BoundedVec<u8, <T as pallet::Config>::MaxCallLength> =
+		call.encode().try_into().unwrap();
+		ProposalCalls::<T>::insert(proposal_id, (encoded_call, BlockNumberFor::<T>::zero()));
+	}: {
+		Proposal::<T>::enact_proposal(proposal_id, community_id, Weight::MAX);
+	}
+	verify {
+		assert!(!ProposalCalls::<T>::contains_key(proposal_id));
+	}
+
 	impl_benchmark_test_suite!(Proposal, crate::mock::new_test_ext(), crate::mock::Test);
 }
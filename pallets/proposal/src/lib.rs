@@ -17,21 +17,63 @@
 //!    - if it’s historical or not
 //!    - the ask/question to the other Members
 //! * A member can vote on an existing proposal
+//! * A member of a State can propose an amendment to its constitution, which is applied once
+//!   the proposal is accepted
+//! * A nation-wide proposal lets the members of all the sub-communities vote as well
+//! * A proposal can require a supermajority or a minimum turnout of the members, with defaults
+//!   set by the founder of the community
+//! * Votes can be weighted by locked JUR or by an asset designated by the community, with an
+//!   optional conviction multiplier in exchange for a longer lock
+//! * A proposal can be restricted to the passport holders or to the holders of given badges
+//! * A proposal can let its voters change or retract their vote until it ends
+//! * A proposal can carry a call, dispatched from the origin of the community once the
+//!   proposal is accepted
 //!
 //! ## Interface
 //!
 //! * `create_proposal`
 //! * `cast_vote`
+//! * `propose_constitution_amendment`
+//! * `create_nation_wide_proposal`
+//! * `set_proposal_threshold`
+//! * `cast_weighted_vote`
+//! * `unlock_vote`
+//! * `set_voting_asset`
+//! * `change_vote`
+//! * `retract_vote`
+//! * `propose_call`
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
-mod types;
-use crate::types::{Choice, Proposal, ProposalResultStatus, Vote};
-use frame_support::{dispatch::DispatchResultWithPostInfo, BoundedVec};
-use primitives::{Incrementable, BLOCKS_PER_DAY, PROPOSAL_DURATION_LIMIT};
-use sp_std::vec::Vec;
+pub mod types;
+use crate::types::{
+	ApprovalThreshold, Choice, Conviction, Proposal, ProposalResultStatus, ProposalTally,
+	ProposalThreshold, StakedVote, Vote, VoterEligibility, VotingMode,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{
+		extract_actual_weight, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo,
+		PostDispatchInfo,
+	},
+	ensure,
+	traits::{fungibles, tokens::Preservation, LockIdentifier, LockableCurrency, WithdrawReasons},
+	weights::Weight,
+	BoundedVec, PalletId,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_community::{
+	types::{AssetBalanceOf, AssetIdOf, CommunityOrigin, ConstitutionAmendmentOf, Permissions},
+	CommunityPermissions, OnCommunityDissolved,
+};
+use primitives::{expiry::ExpiryQueue, Incrementable, BLOCKS_PER_DAY, PROPOSAL_DURATION_LIMIT};
+use sp_runtime::{
+	traits::{AccountIdConversion, Dispatchable, One, SaturatedConversion, Saturating, Zero},
+	DispatchError, Perbill,
+};
+use sp_std::{boxed::Box, vec, vec::Vec};
 
 #[cfg(test)]
 mod mock;
@@ -41,15 +83,47 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
+const LOG_TARGET: &str = "runtime::proposal";
+
+/// Identifier of the lock on the JUR staked by weighted votes.
+const VOTE_LOCK_ID: LockIdentifier = *b"proposal";
+
+/// The storage items removed together with a proposal of a dissolved community.
+const PROPOSAL_CLEANUP_ITEMS: u32 = 10;
+
+/// The blocks searched for room to schedule the call of an accepted proposal.
+const ENACTMENT_SCHEDULE_BLOCKS: u32 = 10;
+
+/// Voting mode of a proposal.
+pub type VotingModeOf<T> = VotingMode<AssetIdOf<T>>;
+
+/// Members allowed to vote on a proposal.
+pub type VoterEligibilityOf<T> = VoterEligibility<
+	<T as pallet_passport::Config>::BadgeNameLimit,
+	<T as Config>::MaxEligibilityBadges,
+>;
+
+/// Stake locked by a weighted vote.
+pub type StakedVoteOf<T> = StakedVote<
+	<T as pallet_community::Config>::CommunityId,
+	AssetIdOf<T>,
+	<T as pallet_balances::Config>::Balance,
+	BlockNumberFor<T>,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[cfg(feature = "runtime-benchmarks")]
 	pub trait BenchmarkHelper<ProposalId, ChoiceId> {
 		fn proposal(i: u32) -> ProposalId;
@@ -68,7 +142,9 @@ pub mod pallet {
 	/// Configure the pallet by specifying the parameters and types on which it
 	/// depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_community::Config {
+	pub trait Config:
+		frame_system::Config + pallet_community::Config + pallet_passport::Config
+	{
 		/// Because this pallet emits events, it depends on the runtime's
 		/// definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -95,6 +171,55 @@ pub mod pallet {
 		#[pallet::constant]
 		type AccountLimit: Get<u32>;
 
+		/// The maximum number of proposals which can expire in the same block.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// The maximum number of expired proposals closed in `on_initialize`, the remaining ones
+		/// are closed in `on_idle` or in the next blocks. The calls of the accepted proposals
+		/// are dispatched within the same limit and `MaxEnactmentWeight`.
+		#[pallet::constant]
+		type MaxClosedPerBlock: Get<u32>;
+
+		/// The number of blocks of a conviction period, for which the stakes of weighted votes
+		/// stay locked after the end of the proposal.
+		#[pallet::constant]
+		type ConvictionPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The pallet id from which the account holding the asset stakes of the votes is
+		/// derived.
+		#[pallet::constant]
+		type StakePalletId: Get<PalletId>;
+
+		/// The maximum number of badges in the eligibility rule of a proposal.
+		#[pallet::constant]
+		type MaxEligibilityBadges: Get<u32>;
+
+		/// The overarching origin, from which the calls of the accepted proposals are
+		/// dispatched.
+		type RuntimeOrigin: From<pallet_community::Origin<Self>>;
+
+		/// The overarching call type, carried by the executable proposals.
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// The maximum length of the encoded call of a proposal.
+		#[pallet::constant]
+		type MaxCallLength: Get<u32>;
+
+		/// The maximum weight of the call of a proposal.
+		#[pallet::constant]
+		type MaxCallWeight: Get<Weight>;
+
+		/// The maximum weight of the calls of the accepted proposals dispatched in a block, the
+		/// remaining calls are dispatched in the next blocks.
+		#[pallet::constant]
+		type MaxEnactmentWeight: Get<Weight>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type Helper: BenchmarkHelper<Self::ProposalId, Self::ChoiceId>;
@@ -104,6 +229,7 @@ pub mod pallet {
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -125,10 +251,21 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Store the proposals which expire at a given block
 	#[pallet::storage]
 	#[pallet::getter(fn proposal_expire)]
-	pub type ProposalExpireTime<T: Config> =
-		StorageMap<_, Identity, BlockNumberFor<T>, (T::ProposalId, T::CommunityId), OptionQuery>;
+	pub type ProposalExpireTime<T: Config> = StorageMap<
+		_,
+		Identity,
+		BlockNumberFor<T>,
+		BoundedVec<(T::ProposalId, T::CommunityId), T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Store the first block whose expired proposals have not all been closed yet
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_expiry_cursor)]
+	pub type ProposalExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
 	/// Store Choices for a particular proposal
 	#[pallet::storage]
@@ -162,16 +299,129 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type NextChoiceId<T: Config> = StorageValue<_, T::ChoiceId, OptionQuery>;
 
-	/// Store the `Proposal Result`
+	/// Store the `Proposal Result` with the winning choice and the votes of every choice
 	#[pallet::storage]
-	pub(super) type ProposalResult<T: Config> = StorageMap<
+	pub(super) type ProposalResult<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, ProposalTally<T::ChoiceId>, OptionQuery>;
+
+	/// Store the constitution amendment applied when a proposal gets accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn constitution_amendments)]
+	pub type ConstitutionAmendments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, ConstitutionAmendmentOf<T>, OptionQuery>;
+
+	/// Store the proposals open to the members of the sub-communities
+	#[pallet::storage]
+	#[pallet::getter(fn nation_wide_proposals)]
+	pub type NationWideProposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, (), OptionQuery>;
+
+	/// Store the threshold of a proposal with the number of members entitled to vote on it
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_thresholds)]
+	pub type ProposalThresholds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, (ProposalThreshold, u32), OptionQuery>;
+
+	/// Store the default threshold of the proposals of a community
+	#[pallet::storage]
+	#[pallet::getter(fn community_thresholds)]
+	pub type CommunityThresholds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, ProposalThreshold, OptionQuery>;
+
+	/// Store the voting mode of the weighted proposals with the block at which their voting ends
+	#[pallet::storage]
+	#[pallet::getter(fn weighted_proposals)]
+	pub type WeightedProposals<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		(VotingModeOf<T>, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	/// Store the asset designated by a community for weighted votes
+	#[pallet::storage]
+	#[pallet::getter(fn community_voting_assets)]
+	pub type CommunityVotingAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CommunityId, AssetIdOf<T>, OptionQuery>;
+
+	/// Store the stakes locked by the weighted votes of an account
+	#[pallet::storage]
+	#[pallet::getter(fn staked_votes)]
+	pub type StakedVotes<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
 		T::ProposalId,
-		(ProposalResultStatus, Vote<BlockNumberFor<T>, T::AccountId, T::AccountLimit>),
+		StakedVoteOf<T>,
 		OptionQuery,
 	>;
 
+	/// Store the members allowed to vote on the proposals restricted by an eligibility rule
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_eligibility)]
+	pub type ProposalEligibility<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, VoterEligibilityOf<T>, OptionQuery>;
+
+	/// Store the proposals whose voters can change or retract their vote
+	#[pallet::storage]
+	#[pallet::getter(fn revisable_proposals)]
+	pub type RevisableProposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, (), OptionQuery>;
+
+	/// Store the choice and the weight of the votes on the revisable proposals
+	#[pallet::storage]
+	#[pallet::getter(fn cast_votes)]
+	pub type CastVotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::ChoiceId, u128),
+		OptionQuery,
+	>;
+
+	/// Store the encoded call dispatched by the community once a proposal gets accepted, with
+	/// the number of blocks between the acceptance and the dispatch
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_calls)]
+	pub type ProposalCalls<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		(BoundedVec<u8, T::MaxCallLength>, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	/// Store the accepted proposals whose call is dispatched at a given block
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_enactments)]
+	pub type ProposalEnactments<T: Config> = StorageMap<
+		_,
+		Identity,
+		BlockNumberFor<T>,
+		BoundedVec<(T::ProposalId, T::CommunityId), T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Store the first block whose accepted proposals have not all been enacted yet
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_enactment_cursor)]
+	pub type ProposalEnactmentCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Store the proposal whose votes are being removed after the dissolution of its
+	/// community, with the cursor to resume from
+	#[pallet::storage]
+	pub type DissolveCursor<T: Config> = StorageValue<_, (T::ProposalId, Vec<u8>), OptionQuery>;
+
+	/// Store the total JUR locked by the weighted votes of an account
+	#[pallet::storage]
+	#[pallet::getter(fn locked_vote_balance)]
+	pub type LockedVoteBalance<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -181,6 +431,28 @@ pub mod pallet {
 		VoteCasted(T::ProposalId),
 		/// Proposal state changed [Proposal Id]
 		ProposalStateChanged(T::ProposalId),
+		/// Voting on a proposal ended [Proposal Id, Result Status, Winning Choice Id]
+		ProposalResultDecided(T::ProposalId, ProposalResultStatus, Option<T::ChoiceId>),
+		/// Constitution amendment of an accepted proposal applied [Proposal Id, Result]
+		ConstitutionAmendmentApplied(T::ProposalId, DispatchResult),
+		/// Expired proposal could not be closed [Proposal Id, Error]
+		ProposalCloseFailed(T::ProposalId, DispatchError),
+		/// Default proposal threshold of a community updated [Community Id]
+		UpdatedProposalThreshold(T::CommunityId),
+		/// Stake of a weighted vote locked [Proposal Id, Voter, Amount]
+		VoteStakeLocked(T::ProposalId, T::AccountId, T::Balance),
+		/// Stake of a weighted vote unlocked [Proposal Id, Voter, Amount]
+		VoteStakeUnlocked(T::ProposalId, T::AccountId, T::Balance),
+		/// Asset designated for weighted votes of a community updated [Community Id]
+		UpdatedVotingAsset(T::CommunityId),
+		/// Vote moved to another choice [Proposal Id]
+		VoteChanged(T::ProposalId),
+		/// Vote withdrawn [Proposal Id]
+		VoteRetracted(T::ProposalId),
+		/// Call of an accepted proposal scheduled [Proposal Id, Dispatch Block]
+		ProposalEnactmentScheduled(T::ProposalId, BlockNumberFor<T>),
+		/// Call of an accepted proposal dispatched [Proposal Id, Result]
+		ProposalEnacted(T::ProposalId, DispatchResult),
 	}
 
 	#[pallet::error]
@@ -209,62 +481,137 @@ pub mod pallet {
 		InvalidProposalDuration,
 		/// Invalid Choices given during creating proposal.
 		InvalidChoicesGiven,
+		/// Membership dues of the account are lapsed.
+		DuesLapsed,
+		/// Too many proposals expire in the same block.
+		TooManyExpiries,
+		/// A supermajority must be more than half of the votes.
+		InvalidThreshold,
+		/// Nation-wide proposals are decided without a quorum.
+		QuorumNotAllowed,
+		/// Calls are only proposed when the proposal threshold of the community has a quorum.
+		QuorumRequired,
+		/// The votes on the proposal are weighted.
+		WeightedVoteRequired,
+		/// The votes on the proposal are not weighted.
+		NotWeightedProposal,
+		/// The proposal does not allow conviction.
+		ConvictionNotAllowed,
+		/// The stake of a weighted vote can't be zero.
+		InvalidStake,
+		/// Not enough free balance to lock the stake.
+		InsufficientBalance,
+		/// The asset is not designated for the votes of the community.
+		AssetNotDesignated,
+		/// No stake locked by the vote.
+		StakeNotFound,
+		/// The stake is still locked.
+		StakeStillLocked,
+		/// The account is not eligible to vote on the proposal.
+		NotEligible,
+		/// The badge does not exist in the community.
+		BadgeDoesNotExist,
+		/// A badge rule needs at least one badge.
+		InvalidEligibility,
+		/// The votes on the proposal can't be changed.
+		VoteChangesNotAllowed,
+		/// The account has not voted on the proposal.
+		NotVoted,
+		/// The encoded call is too long.
+		CallTooLong,
+		/// The weight of the call is too high.
+		CallTooHeavy,
+		/// The call can't be decoded anymore.
+		UndecodableCall,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
-			let option_proposal_expire = ProposalExpireTime::<T>::get(block_number);
+			let (visited, closed) = ProposalExpiryQueue::<T>::service(
+				block_number,
+				T::MaxClosedPerBlock::get(),
+				|(proposal_id, community_id)| Self::expire_proposal(proposal_id, community_id),
+			);
 
-			if let Some((proposal_id, community_id)) = option_proposal_expire {
-				Proposals::<T>::try_mutate(
-					community_id,
-					proposal_id,
-					|proposal_detail| -> DispatchResult {
-						let proposal_data = proposal_detail
-							.as_mut()
-							.ok_or(Error::<T>::ProposalDoesNotExist)?;
-
-						// Add the proposalResult storage to add the result after the deadline of proposal voting.
-						let voters_count = &proposal_data.voter_accounts.len();
-
-						// find all the choice id's for the current proposal.
-						// iterate for all the choice id's and get the total no of votes for it.
-						let choice_ids =
-							Choices::<T>::get(proposal_id).ok_or(Error::<T>::ChoiceDoesNotExist)?;
-
-						// fetching the vote information for both choices.
-						let yes_vote_info =
-							Votes::<T>::get(choice_ids[0].id).ok_or(Error::<T>::VotesNotFound)?;
-						let no_vote_info =
-							Votes::<T>::get(choice_ids[1].id).ok_or(Error::<T>::VotesNotFound)?;
-
-						// Inserting the proposal result according to the voting.
-						// If 51% or more then from all voters voted in favour of proposal
-						// then proposal is Accepted.
-						// Otherwise the proposal is rejected.
-						if yes_vote_info.vote_count > (*voters_count as u64) / 2 {
-							ProposalResult::<T>::insert(
-								proposal_id,
-								(ProposalResultStatus::Accepted, yes_vote_info),
-							);
-						} else {
-							ProposalResult::<T>::insert(
-								proposal_id,
-								(ProposalResultStatus::Rejected, no_vote_info),
-							);
-						}
-
-						proposal_data.status = false;
-
-						Self::deposit_event(Event::<T>::ProposalStateChanged(proposal_id));
-
-						Ok(())
-					},
-				)
-				.expect("Proposal not found");
+			// The first call of the block is always dispatched, so that the queue moves on.
+			let max_weight = T::MaxEnactmentWeight::get();
+			let mut call_weight = Weight::zero();
+			let mut dispatched = false;
+			let mut deferred = 0_u32;
+			let (enactment_visited, enacted) = ProposalEnactmentQueue::<T>::try_service(
+				block_number,
+				T::MaxClosedPerBlock::get(),
+				|(proposal_id, community_id)| {
+					let remaining = if dispatched {
+						max_weight.saturating_sub(call_weight)
+					} else {
+						Weight::MAX
+					};
+
+					match Self::enact_proposal(proposal_id, community_id, remaining) {
+						Some(weight) => {
+							dispatched = true;
+							call_weight.saturating_accrue(weight);
+							Ok(())
+						},
+						None => {
+							deferred.saturating_inc();
+							Err((proposal_id, community_id))
+						},
+					}
+				},
+			);
+
+			Self::expiry_weight(visited, closed)
+				.saturating_add(Self::enactment_weight(
+					enactment_visited,
+					enacted.saturating_add(deferred),
+				))
+				.saturating_add(call_weight)
+		}
+
+		fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let base_weight = Self::expiry_weight(0, 0);
+			let item_weight = Self::expiry_weight(1, 1).saturating_sub(base_weight);
+
+			if remaining_weight.any_lt(base_weight.saturating_add(item_weight)) {
+				return Weight::zero();
 			}
-			Weight::zero()
+
+			// Close the proposals carried over from the previous blocks.
+			let limit = remaining_weight
+				.saturating_sub(base_weight)
+				.ref_time()
+				.checked_div(item_weight.ref_time())
+				.unwrap_or(u64::MAX)
+				.min(u32::MAX as u64) as u32;
+
+			let (visited, closed) = ProposalExpiryQueue::<T>::service(
+				block_number,
+				limit,
+				|(proposal_id, community_id)| Self::expire_proposal(proposal_id, community_id),
+			);
+
+			Self::expiry_weight(visited, closed)
+		}
+
+		fn integrity_test() {
+			// A proposal of a dissolved community is removed in one go once its votes are.
+			assert!(
+				<T as pallet_community::Config>::DissolveCleanupLimit::get()
+					>= PROPOSAL_CLEANUP_ITEMS,
+				"`DissolveCleanupLimit` must cover the removal of a proposal"
+			);
+			assert!(
+				T::MaxCallWeight::get().all_lte(T::MaxEnactmentWeight::get()),
+				"`MaxEnactmentWeight` must cover the weight of a call"
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
 		}
 	}
 
@@ -274,7 +621,7 @@ pub mod pallet {
 		///
 		/// This new proposal has choices with zero votes.
 		///
-		/// The origin must be Signed and the community founder.
+		/// The origin must be Signed and hold the `CREATE_PROPOSALS` permission in the community.
 		///
 		/// Parameters:
 		/// - `community_id`: Id of the community.
@@ -284,6 +631,12 @@ pub mod pallet {
 		/// - `is_historical`: A Proposal can be marked as historical.
 		/// - `proposal_duration`: Voting duration of the proposal.
 		/// 			In case it is flagged as such, the proposal becomes part of the History.
+		/// - `threshold`: Approval and quorum rules of the proposal, the default ones of the
+		/// 			community when not given.
+		/// - `voting_mode`: How the votes on the proposal are weighted.
+		/// - `eligibility`: Members allowed to vote on the proposal.
+		/// - `allow_vote_changes`: Whether the voters can change or retract their vote until
+		/// 			the end of the proposal.
 		///
 		/// Emits `CreatedProposal` event when successful.
 		///
@@ -297,12 +650,25 @@ pub mod pallet {
 			choices: Vec<Vec<u8>>,
 			is_historical: bool,
 			proposal_duration: u32,
+			threshold: Option<ProposalThreshold>,
+			voting_mode: VotingModeOf<T>,
+			eligibility: VoterEligibilityOf<T>,
+			allow_vote_changes: bool,
 		) -> DispatchResultWithPostInfo {
-			let community = pallet_community::Communities::<T>::get(community_id)
-				.ok_or(Error::<T>::CommunityDoesNotExist)?;
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
 
 			let origin = ensure_signed(origin)?;
-			ensure!(origin == community.founder, Error::<T>::NotAllowed);
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::CREATE_PROPOSALS
+				),
+				Error::<T>::NotAllowed
+			);
 
 			ensure!(choices.len() >= 2, Error::<T>::InvalidChoicesGiven);
 			ensure!(
@@ -310,6 +676,23 @@ pub mod pallet {
 				Error::<T>::InvalidProposalDuration
 			);
 
+			let threshold = match threshold {
+				Some(threshold) => {
+					Self::ensure_valid_threshold(&threshold)?;
+					threshold
+				},
+				None => CommunityThresholds::<T>::get(community_id).unwrap_or_default(),
+			};
+
+			if let VotingMode::Asset { asset_id, .. } = &voting_mode {
+				ensure!(
+					CommunityVotingAssets::<T>::get(community_id).as_ref() == Some(asset_id),
+					Error::<T>::AssetNotDesignated
+				);
+			}
+
+			Self::ensure_valid_eligibility(community_id, &eligibility)?;
+
 			Self::do_create_proposal(
 				origin,
 				community_id,
@@ -318,6 +701,10 @@ pub mod pallet {
 				choices,
 				is_historical,
 				proposal_duration,
+				threshold,
+				voting_mode,
+				eligibility,
+				allow_vote_changes,
 			)
 		}
 
@@ -333,88 +720,1044 @@ pub mod pallet {
 		/// Emits `cast_vote` event when successful.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::cast_vote())]
+		#[pallet::weight(<T as Config>::WeightInfo::cast_vote()
+			.saturating_add(pallet_community::Pallet::<T>::descendants_walk_weight(2)))]
 		pub fn cast_vote(
 			origin: OriginFor<T>,
 			community_id: T::CommunityId,
 			proposal_id: T::ProposalId,
 			choice: BoundedVec<u8, T::LabelLimit>,
 		) -> DispatchResultWithPostInfo {
-			let community = pallet_community::Communities::<T>::get(community_id)
-				.ok_or(Error::<T>::CommunityDoesNotExist)?;
 			let origin = ensure_signed(origin)?;
+			ensure!(
+				!WeightedProposals::<T>::contains_key(proposal_id),
+				Error::<T>::WeightedVoteRequired
+			);
 
-			ensure!(community.members.contains(&origin), Error::<T>::NotAllowed);
+			let walks_descendants = Self::walks_descendants(community_id, proposal_id, &origin);
+			Self::do_cast_vote(&origin, community_id, proposal_id, choice, 1)?;
 
-			let proposal = Proposals::<T>::get(community_id, proposal_id)
-				.ok_or(Error::<T>::ProposalDoesNotExist)?;
+			if walks_descendants {
+				Ok(().into())
+			} else {
+				Ok(Some(<T as Config>::WeightInfo::cast_vote()).into())
+			}
+		}
 
-			ensure!(Choices::<T>::contains_key(proposal_id), Error::<T>::NoChoiceAvailable);
+		/// Propose an amendment to the constitution of a State.
+		///
+		/// A Yes/No proposal is created and the amendment is applied once it gets accepted.
+		///
+		/// The origin must be Signed and hold the `CREATE_PROPOSALS` permission in the community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the State community.
+		/// - `name`: name/title of the proposal.
+		/// - `description`: description of the proposal.
+		/// - `amendment`: The change to the constitution.
+		/// - `proposal_duration`: Voting duration of the proposal.
+		///
+		/// Emits `CreatedProposal` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_constitution_amendment())]
+		pub fn propose_constitution_amendment(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
+			description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+			amendment: ConstitutionAmendmentOf<T>,
+			proposal_duration: u32,
+		) -> DispatchResultWithPostInfo {
+			pallet_community::Pallet::<T>::state_of(community_id)?;
+
+			let origin = ensure_signed(origin)?;
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::CREATE_PROPOSALS
+				),
+				Error::<T>::NotAllowed
+			);
+
+			ensure!(
+				(1..=PROPOSAL_DURATION_LIMIT).contains(&proposal_duration),
+				Error::<T>::InvalidProposalDuration
+			);
+
+			let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+			Self::do_create_proposal(
+				origin,
+				community_id,
+				name,
+				description,
+				vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+				false,
+				proposal_duration,
+				CommunityThresholds::<T>::get(community_id).unwrap_or_default(),
+				VotingMode::OneMemberOneVote,
+				VoterEligibility::AnyMember,
+				false,
+			)?;
+
+			ConstitutionAmendments::<T>::insert(proposal_id, amendment);
+
+			Ok(().into())
+		}
+
+		/// Create a new proposal open to the members of the community and of all its
+		/// sub-communities.
+		///
+		/// The voters of a whole hierarchy can't be counted exactly, since an account can be a
+		/// member of several sub-communities or inherit the membership of a parent. The proposal
+		/// is therefore decided without a quorum, also when the default threshold of the
+		/// community has one.
+		///
+		/// The origin must be Signed and hold the `CREATE_PROPOSALS` permission in the community.
+		///
+		/// Parameters are the same as for `create_proposal`, except that `threshold` can't
+		/// have a quorum.
+		///
+		/// Emits `CreatedProposal` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_nation_wide_proposal())]
+		pub fn create_nation_wide_proposal(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
+			description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+			choices: Vec<Vec<u8>>,
+			is_historical: bool,
+			proposal_duration: u32,
+			threshold: Option<ProposalThreshold>,
+			voting_mode: VotingModeOf<T>,
+			eligibility: VoterEligibilityOf<T>,
+			allow_vote_changes: bool,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				threshold
+					.as_ref()
+					.map_or(true, |threshold| threshold.quorum.is_none()),
+				Error::<T>::QuorumNotAllowed
+			);
+
+			let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+			Self::create_proposal(
+				origin,
+				community_id,
+				name,
+				description,
+				choices,
+				is_historical,
+				proposal_duration,
+				threshold,
+				voting_mode,
+				eligibility,
+				allow_vote_changes,
+			)?;
+
+			NationWideProposals::<T>::insert(proposal_id, ());
+
+			// The electorate only counts the members of the community itself, so the quorum
+			// of the default threshold is left out.
+			ProposalThresholds::<T>::mutate(proposal_id, |threshold| {
+				if let Some((threshold, _)) = threshold {
+					threshold.quorum = None;
+				}
+			});
+
+			Ok(().into())
+		}
+
+		/// Set the default threshold of the proposals of a community.
+		///
+		/// The default applies to the proposals created without a threshold afterwards.
+		///
+		/// The origin must be Signed and hold the `MANAGE_GOVERNANCE` permission in the community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `threshold`: Approval and quorum rules, or `None` to restore a simple majority
+		/// 			without quorum.
+		///
+		/// Emits `UpdatedProposalThreshold` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_proposal_threshold())]
+		pub fn set_proposal_threshold(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			threshold: Option<ProposalThreshold>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&who,
+					Permissions::MANAGE_GOVERNANCE
+				),
+				Error::<T>::NotAllowed
+			);
+
+			match threshold {
+				Some(threshold) => {
+					Self::ensure_valid_threshold(&threshold)?;
+					CommunityThresholds::<T>::insert(community_id, threshold);
+				},
+				None => CommunityThresholds::<T>::remove(community_id),
+			}
 
-			ensure!(proposal.status, Error::<T>::ProposalNotActive);
+			Self::deposit_event(Event::<T>::UpdatedProposalThreshold(community_id));
 
-			ensure!(!(proposal.voter_accounts).contains(&origin), Error::<T>::DuplicateVote);
+			Ok(())
+		}
 
-			// Get all the choices id from the current proposal and
-			// check if current choice_id is already present or not?
-			let proposal_choices =
-				Choices::<T>::get(proposal_id).ok_or(Error::<T>::NoChoiceAvailable)?;
+		/// Cast a vote weighted by a stake on a weighted proposal.
+		///
+		/// The stake is locked until the end of the proposal, and for the lock periods of the
+		/// conviction after it.
+		///
+		/// The origin must be Signed and the member of the community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `proposal_id`: Id of the proposal.
+		/// - `choice`: Label of the choice.
+		/// - `amount`: Stake of the vote, in JUR or in the asset of the proposal.
+		/// - `conviction`: Multiplier of the stake, when the proposal allows it.
+		///
+		/// Emits `VoteCasted` and `VoteStakeLocked` events when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::cast_weighted_vote()
+			.saturating_add(pallet_community::Pallet::<T>::descendants_walk_weight(2)))]
+		pub fn cast_weighted_vote(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			proposal_id: T::ProposalId,
+			choice: BoundedVec<u8, T::LabelLimit>,
+			amount: T::Balance,
+			conviction: Conviction,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let (voting_mode, ends_at) =
+				WeightedProposals::<T>::get(proposal_id).ok_or(Error::<T>::NotWeightedProposal)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidStake);
+
+			let (asset, allows_conviction) = match voting_mode {
+				VotingMode::OneMemberOneVote => return Err(Error::<T>::NotWeightedProposal.into()),
+				VotingMode::Native { conviction: allows } => (None, allows),
+				VotingMode::Asset { asset_id, conviction: allows } => (Some(asset_id), allows),
+			};
+			ensure!(
+				allows_conviction || conviction == Conviction::None,
+				Error::<T>::ConvictionNotAllowed
+			);
 
-			proposal_choices
-				.into_iter()
-				.find(|choices| choices.label == choice)
-				.ok_or(Error::<T>::ChoiceDoesNotExist)?;
+			let weight = amount
+				.saturated_into::<u128>()
+				.saturating_mul(conviction.multiplier());
+			let walks_descendants = Self::walks_descendants(community_id, proposal_id, &origin);
+			Self::do_cast_vote(&origin, community_id, proposal_id, choice, weight)?;
 
-			let proposal_choices =
-				Choices::<T>::get(proposal_id).ok_or(Error::<T>::NoChoiceAvailable)?;
+			Self::do_lock_stake(&origin, asset.clone(), amount)?;
+			let unlock_at = ends_at.saturating_add(
+				T::ConvictionPeriod::get().saturating_mul(conviction.lock_periods().into()),
+			);
+			StakedVotes::<T>::insert(
+				&origin,
+				proposal_id,
+				StakedVote { community_id, asset, amount, unlock_at },
+			);
 
-			let mut choice_id = proposal_choices[0].id;
+			Self::deposit_event(Event::<T>::VoteStakeLocked(proposal_id, origin, amount));
 
-			if proposal_choices[1].label == choice {
-				choice_id = proposal_choices[1].id;
+			if walks_descendants {
+				Ok(().into())
+			} else {
+				Ok(Some(<T as Config>::WeightInfo::cast_weighted_vote()).into())
 			}
+		}
 
-			// Adding the vote to the storage.
-			Votes::<T>::mutate(choice_id, |optional_vote| -> DispatchResult {
-				let vote = optional_vote.as_mut().ok_or(Error::<T>::VotesNotFound)?;
-				vote.who
-					.try_push(origin.clone())
-					.ok()
-					.ok_or(Error::<T>::AccountLimitReached)?;
-				*optional_vote = Some(Vote {
-					who: vote.who.clone(),
-					vote_count: vote.vote_count + 1,
-					last_voted: <frame_system::Pallet<T>>::block_number(),
-				});
+		/// Unlock the stake of a weighted vote.
+		///
+		/// The proposal must be closed and the lock periods of the conviction elapsed.
+		///
+		/// The origin must be Signed and the voter.
+		///
+		/// Parameters:
+		/// - `proposal_id`: Id of the proposal.
+		///
+		/// Emits `VoteStakeUnlocked` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_vote())]
+		pub fn unlock_vote(origin: OriginFor<T>, proposal_id: T::ProposalId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let stake =
+				StakedVotes::<T>::get(&origin, proposal_id).ok_or(Error::<T>::StakeNotFound)?;
+
+			// An expired proposal carried over to the next blocks is still open.
+			let open = Proposals::<T>::get(stake.community_id, proposal_id)
+				.map_or(false, |proposal| proposal.status);
+			ensure!(
+				!open && frame_system::Pallet::<T>::block_number() >= stake.unlock_at,
+				Error::<T>::StakeStillLocked
+			);
+
+			Self::do_unlock_stake(&origin, stake.asset, stake.amount)?;
+			StakedVotes::<T>::remove(&origin, proposal_id);
+
+			Self::deposit_event(Event::<T>::VoteStakeUnlocked(proposal_id, origin, stake.amount));
+			Ok(())
+		}
+
+		/// Designate the asset used for the weighted votes of a community.
+		///
+		/// The asset applies to the weighted proposals created afterwards.
+		///
+		/// The origin must be Signed and hold the `MANAGE_GOVERNANCE` permission in the community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `asset_id`: Id of the asset, or `None` to only allow votes weighted by JUR.
+		///
+		/// Emits `UpdatedVotingAsset` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_voting_asset())]
+		pub fn set_voting_asset(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			asset_id: Option<AssetIdOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&who,
+					Permissions::MANAGE_GOVERNANCE
+				),
+				Error::<T>::NotAllowed
+			);
+
+			CommunityVotingAssets::<T>::set(community_id, asset_id);
+
+			Self::deposit_event(Event::<T>::UpdatedVotingAsset(community_id));
+
+			Ok(())
+		}
+
+		/// Move a vote to another choice of a proposal.
+		///
+		/// The proposal must allow vote changes and still be active. The weight and the stake of
+		/// the vote are kept.
+		///
+		/// The origin must be Signed and have voted on the proposal.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `proposal_id`: Id of the proposal.
+		/// - `choice`: Label of the new choice.
+		///
+		/// Emits `VoteChanged` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::change_vote())]
+		pub fn change_vote(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			proposal_id: T::ProposalId,
+			choice: BoundedVec<u8, T::LabelLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let (previous_choice_id, weight) =
+				Self::revisable_vote(community_id, proposal_id, &origin)?;
+
+			let choice_id = Self::choice_id_of(proposal_id, &choice)?;
+			ensure!(choice_id != previous_choice_id, Error::<T>::DuplicateVote);
+
+			Self::do_remove_vote(previous_choice_id, &origin, weight)?;
+			Self::do_add_vote(choice_id, &origin, weight)?;
+			CastVotes::<T>::insert(proposal_id, &origin, (choice_id, weight));
+
+			Self::deposit_event(Event::<T>::VoteChanged(proposal_id));
+			Ok(())
+		}
+
+		/// Withdraw a vote on a proposal.
+		///
+		/// The proposal must allow vote changes and still be active. The stake of a weighted
+		/// vote is released right away, whatever its conviction.
+		///
+		/// The origin must be Signed and have voted on the proposal.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `proposal_id`: Id of the proposal.
+		///
+		/// Emits `VoteRetracted` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::retract_vote())]
+		pub fn retract_vote(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			proposal_id: T::ProposalId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let (choice_id, weight) = Self::revisable_vote(community_id, proposal_id, &origin)?;
+
+			Self::do_remove_vote(choice_id, &origin, weight)?;
+			Proposals::<T>::try_mutate(community_id, proposal_id, |proposal| -> DispatchResult {
+				let proposal = proposal.as_mut().ok_or(Error::<T>::ProposalDoesNotExist)?;
+				proposal.voter_accounts.retain(|voter| *voter != origin);
 				Ok(())
 			})?;
+			CastVotes::<T>::remove(proposal_id, &origin);
+
+			if let Some(stake) = StakedVotes::<T>::take(&origin, proposal_id) {
+				Self::do_unlock_stake(&origin, stake.asset, stake.amount)?;
+				Self::deposit_event(Event::<T>::VoteStakeUnlocked(
+					proposal_id,
+					origin.clone(),
+					stake.amount,
+				));
+			}
+
+			Self::deposit_event(Event::<T>::VoteRetracted(proposal_id));
+			Ok(())
+		}
 
-			// Add this account in voter_accounts list.
-			Proposals::<T>::mutate(
+		/// Propose a call dispatched from the origin of the community.
+		///
+		/// A Yes/No proposal is created and the call is dispatched once it gets accepted, after
+		/// the enactment delay and at the earliest in the block following the acceptance.
+		///
+		/// The origin must be Signed and hold the `EXECUTE_CALLS` permission in the community.
+		/// The default proposal threshold of the community must have a quorum, so that a single
+		/// member can't dispatch calls as the community.
+		///
+		/// Parameters:
+		/// - `community_id`: Id of the community.
+		/// - `name`: name/title of the proposal.
+		/// - `description`: description of the proposal.
+		/// - `call`: The call dispatched by the community.
+		/// - `enactment_delay`: Number of blocks between the acceptance and the dispatch.
+		/// - `proposal_duration`: Voting duration of the proposal.
+		///
+		/// Emits `CreatedProposal` event when successful.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_call())]
+		pub fn propose_call(
+			origin: OriginFor<T>,
+			community_id: T::CommunityId,
+			name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
+			description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+			call: Box<<T as Config>::RuntimeCall>,
+			enactment_delay: Option<BlockNumberFor<T>>,
+			proposal_duration: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				pallet_community::Communities::<T>::contains_key(community_id),
+				Error::<T>::CommunityDoesNotExist
+			);
+
+			let origin = ensure_signed(origin)?;
+			ensure!(
+				pallet_community::Pallet::<T>::has_permission(
+					community_id,
+					&origin,
+					Permissions::EXECUTE_CALLS
+				),
+				Error::<T>::NotAllowed
+			);
+
+			let threshold = CommunityThresholds::<T>::get(community_id).unwrap_or_default();
+			ensure!(threshold.quorum.is_some(), Error::<T>::QuorumRequired);
+
+			ensure!(
+				(1..=PROPOSAL_DURATION_LIMIT).contains(&proposal_duration),
+				Error::<T>::InvalidProposalDuration
+			);
+
+			// The call is dispatched in `on_initialize`, its weight has to fit in the budget of the
+			// enactments of a block.
+			ensure!(
+				call.get_dispatch_info()
+					.weight
+					.all_lte(T::MaxCallWeight::get()),
+				Error::<T>::CallTooHeavy
+			);
+			let encoded_call: BoundedVec<u8, T::MaxCallLength> = call
+				.encode()
+				.try_into()
+				.map_err(|_| Error::<T>::CallTooLong)?;
+
+			let proposal_id = NextProposalId::<T>::get().unwrap_or(T::ProposalId::initial_value());
+
+			Self::do_create_proposal(
+				origin,
 				community_id,
-				proposal_id,
-				|proposal_details| -> DispatchResult {
-					let proposal_info = proposal_details
-						.as_mut()
-						.ok_or(Error::<T>::ProposalDoesNotExist)?;
-
-					proposal_info
-						.voter_accounts
-						.try_push(origin.clone())
-						.ok()
-						.ok_or(Error::<T>::AccountLimitReached)?;
-
-					Ok(())
-				},
+				name,
+				description,
+				vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+				false,
+				proposal_duration,
+				threshold,
+				VotingMode::OneMemberOneVote,
+				VoterEligibility::AnyMember,
+				false,
 			)?;
 
-			Self::deposit_event(Event::VoteCasted(proposal_id));
+			ProposalCalls::<T>::insert(
+				proposal_id,
+				(encoded_call, enactment_delay.unwrap_or_else(Zero::zero)),
+			);
+
 			Ok(().into())
 		}
 	}
 }
 
+/// Queue of the proposals expiring at a given block.
+pub type ProposalExpiryQueue<T> = ExpiryQueue<
+	BlockNumberFor<T>,
+	(<T as Config>::ProposalId, <T as pallet_community::Config>::CommunityId),
+	<T as Config>::MaxExpiriesPerBlock,
+	ProposalExpireTime<T>,
+	ProposalExpiryCursor<T>,
+>;
+
+/// Queue of the accepted proposals whose call is dispatched at a given block.
+pub type ProposalEnactmentQueue<T> = ExpiryQueue<
+	BlockNumberFor<T>,
+	(<T as Config>::ProposalId, <T as pallet_community::Config>::CommunityId),
+	<T as Config>::MaxExpiriesPerBlock,
+	ProposalEnactments<T>,
+	ProposalEnactmentCursor<T>,
+>;
+
 impl<T: Config> Pallet<T> {
+	/// Close an expired proposal, reporting a failure instead of halting the block.
+	fn expire_proposal(proposal_id: T::ProposalId, community_id: T::CommunityId) {
+		if let Err(error) = Self::do_close_proposal(proposal_id, community_id) {
+			log::error!(
+				target: LOG_TARGET,
+				"Failed to close the expired proposal {:?}: {:?}",
+				proposal_id,
+				error
+			);
+			Self::deposit_event(Event::<T>::ProposalCloseFailed(proposal_id, error));
+		}
+	}
+
+	/// Close the voting on an expired proposal and record its result.
+	pub fn do_close_proposal(
+		proposal_id: T::ProposalId,
+		community_id: T::CommunityId,
+	) -> DispatchResult {
+		// The proposals of a dissolved community are removed by its clean up.
+		if !pallet_community::Communities::<T>::contains_key(community_id) {
+			return Ok(());
+		}
+
+		let mut proposal = Proposals::<T>::get(community_id, proposal_id)
+			.ok_or(Error::<T>::ProposalDoesNotExist)?;
+		ensure!(proposal.status, Error::<T>::ProposalNotActive);
+
+		let choices = Choices::<T>::get(proposal_id).ok_or(Error::<T>::ChoiceDoesNotExist)?;
+
+		// Proposals created before thresholds existed are decided by a simple majority.
+		let (threshold, electorate) =
+			ProposalThresholds::<T>::take(proposal_id).unwrap_or_default();
+
+		// Tally every choice against the number of voters of the proposal.
+		let tally =
+			Self::do_tally(&choices, proposal.voter_accounts.len() as u64, &threshold, electorate)?;
+
+		// A Yes/No proposal is accepted when its first choice reaches the approval threshold.
+		let accepted = tally.status == ProposalResultStatus::Majority
+			&& tally.winner == choices.first().map(|choice| choice.id);
+
+		proposal.status = false;
+		Proposals::<T>::insert(community_id, proposal_id, proposal);
+
+		Self::deposit_event(Event::<T>::ProposalResultDecided(
+			proposal_id,
+			tally.status.clone(),
+			tally.winner,
+		));
+		ProposalResult::<T>::insert(proposal_id, tally);
+
+		Self::deposit_event(Event::<T>::ProposalStateChanged(proposal_id));
+
+		// Amendments only take effect through an accepted proposal.
+		if let Some(amendment) = ConstitutionAmendments::<T>::take(proposal_id) {
+			if accepted {
+				let result =
+					pallet_community::Pallet::<T>::do_amend_constitution(community_id, amendment);
+				Self::deposit_event(Event::<T>::ConstitutionAmendmentApplied(proposal_id, result));
+			}
+		}
+
+		// Calls are only dispatched for an accepted proposal, in a later block.
+		if let Some((_, delay)) = ProposalCalls::<T>::get(proposal_id) {
+			let enact_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(delay.max(One::one()));
+
+			if !accepted {
+				ProposalCalls::<T>::remove(proposal_id);
+			} else {
+				// A full enactment block defers the call to the next block with room left.
+				match ProposalEnactmentQueue::<T>::schedule_within(
+					enact_at,
+					ENACTMENT_SCHEDULE_BLOCKS,
+					(proposal_id, community_id),
+				) {
+					Ok(enact_at) => Self::deposit_event(Event::<T>::ProposalEnactmentScheduled(
+						proposal_id,
+						enact_at,
+					)),
+					Err(_) => {
+						ProposalCalls::<T>::remove(proposal_id);
+						Self::deposit_event(Event::<T>::ProposalEnacted(
+							proposal_id,
+							Err(Error::<T>::TooManyExpiries.into()),
+						));
+					},
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Dispatch the call of an accepted proposal from the origin of its community, unless its
+	/// weight exceeds `max_weight`.
+	///
+	/// Returns the weight used by the call, or `None` when the call is left for a later block.
+	fn enact_proposal(
+		proposal_id: T::ProposalId,
+		community_id: T::CommunityId,
+		max_weight: Weight,
+	) -> Option<Weight> {
+		// The calls of a dissolved community are removed by its clean up.
+		let (call, _) = match ProposalCalls::<T>::get(proposal_id) {
+			Some(call) => call,
+			None => return Some(Weight::zero()),
+		};
+
+		let decoded = <T as Config>::RuntimeCall::decode(&mut &call[..]);
+		if let Ok(call) = &decoded {
+			if call.get_dispatch_info().weight.any_gt(max_weight) {
+				return None;
+			}
+		}
+		ProposalCalls::<T>::remove(proposal_id);
+
+		let mut weight = Weight::zero();
+		let result = match decoded {
+			Ok(call) => {
+				let info = call.get_dispatch_info();
+				let result = call.dispatch(CommunityOrigin(community_id).into());
+				weight = extract_actual_weight(&result, &info);
+
+				result.map(|_| ()).map_err(|error| error.error)
+			},
+			// The encoding of the calls can change with a runtime upgrade.
+			Err(_) => Err(Error::<T>::UndecodableCall.into()),
+		};
+
+		Self::deposit_event(Event::<T>::ProposalEnacted(proposal_id, result));
+		Some(weight)
+	}
+
+	/// Weight of visiting `visited` blocks of the enactment queue and dispatching the calls of
+	/// `enacted` proposals, without the weight of the calls.
+	fn enactment_weight(visited: u32, enacted: u32) -> Weight {
+		let block_weight = <T as Config>::WeightInfo::service_expiry_block();
+
+		block_weight
+			.saturating_add(block_weight.saturating_mul(visited as u64))
+			.saturating_add(
+				<T as Config>::WeightInfo::enact_proposal().saturating_mul(enacted as u64),
+			)
+	}
+
+	/// Weight of visiting `visited` blocks of the expiry queue and closing `closed` proposals.
+	fn expiry_weight(visited: u32, closed: u32) -> Weight {
+		let block_weight = <T as Config>::WeightInfo::service_expiry_block();
+
+		block_weight
+			.saturating_add(block_weight.saturating_mul(visited as u64))
+			.saturating_add(
+				<T as Config>::WeightInfo::close_proposal().saturating_mul(closed as u64),
+			)
+	}
+
+	/// Ensure every queued expiry refers to an open proposal.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		for (_, expiries) in ProposalExpireTime::<T>::iter() {
+			for (proposal_id, community_id) in expiries {
+				// The proposals of a dissolved community are removed by its clean up.
+				if !pallet_community::Communities::<T>::contains_key(community_id) {
+					continue;
+				}
+
+				let proposal = Proposals::<T>::get(community_id, proposal_id)
+					.ok_or("Queued expiry refers to a missing proposal")?;
+				ensure!(proposal.status, "Queued expiry refers to a closed proposal");
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Whether the vote of the account walks the descendants of the community, which only
+	/// happens for the members of its sub-communities voting on a nation-wide proposal.
+	fn walks_descendants(
+		community_id: T::CommunityId,
+		proposal_id: T::ProposalId,
+		who: &T::AccountId,
+	) -> bool {
+		NationWideProposals::<T>::contains_key(proposal_id)
+			&& !pallet_community::Pallet::<T>::is_member(community_id, who)
+	}
+
+	/// Record the vote of `origin` with the given weight on a choice of a proposal.
+	fn do_cast_vote(
+		origin: &T::AccountId,
+		community_id: T::CommunityId,
+		proposal_id: T::ProposalId,
+		choice: BoundedVec<u8, T::LabelLimit>,
+		weight: u128,
+	) -> DispatchResult {
+		ensure!(
+			pallet_community::Communities::<T>::contains_key(community_id),
+			Error::<T>::CommunityDoesNotExist
+		);
+
+		// Members of the sub-communities can vote on nation-wide proposals.
+		ensure!(
+			pallet_community::Pallet::<T>::is_member(community_id, origin)
+				|| (NationWideProposals::<T>::contains_key(proposal_id)
+					&& pallet_community::Pallet::<T>::is_member_of_descendants(
+						community_id,
+						origin
+					)),
+			Error::<T>::NotAllowed
+		);
+
+		ensure!(
+			!pallet_community::Pallet::<T>::is_lapsed(community_id, origin),
+			Error::<T>::DuesLapsed
+		);
+
+		ensure!(
+			Self::is_eligible_voter(community_id, proposal_id, origin),
+			Error::<T>::NotEligible
+		);
+
+		let proposal = Proposals::<T>::get(community_id, proposal_id)
+			.ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+		ensure!(Choices::<T>::contains_key(proposal_id), Error::<T>::NoChoiceAvailable);
+
+		ensure!(proposal.status, Error::<T>::ProposalNotActive);
+
+		ensure!(!(proposal.voter_accounts).contains(origin), Error::<T>::DuplicateVote);
+
+		let choice_id = Self::choice_id_of(proposal_id, &choice)?;
+
+		// Adding the vote to the storage.
+		Self::do_add_vote(choice_id, origin, weight)?;
+
+		// Add this account in voter_accounts list.
+		Proposals::<T>::mutate(community_id, proposal_id, |proposal_details| -> DispatchResult {
+			let proposal_info = proposal_details
+				.as_mut()
+				.ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+			proposal_info
+				.voter_accounts
+				.try_push(origin.clone())
+				.ok()
+				.ok_or(Error::<T>::AccountLimitReached)?;
+
+			Ok(())
+		})?;
+
+		// The choice and weight are kept to take the vote back when it gets changed.
+		if RevisableProposals::<T>::contains_key(proposal_id) {
+			CastVotes::<T>::insert(proposal_id, origin, (choice_id, weight));
+		}
+
+		Self::deposit_event(Event::VoteCasted(proposal_id));
+		Ok(())
+	}
+
+	/// Get the id of the choice with the given label from a proposal.
+	fn choice_id_of(
+		proposal_id: T::ProposalId,
+		choice: &BoundedVec<u8, T::LabelLimit>,
+	) -> Result<T::ChoiceId, DispatchError> {
+		Choices::<T>::get(proposal_id)
+			.ok_or(Error::<T>::NoChoiceAvailable)?
+			.into_iter()
+			.find(|choices| choices.label == *choice)
+			.map(|choices| choices.id)
+			.ok_or_else(|| Error::<T>::ChoiceDoesNotExist.into())
+	}
+
+	/// Count the vote of `who` with the given weight for a choice.
+	fn do_add_vote(choice_id: T::ChoiceId, who: &T::AccountId, weight: u128) -> DispatchResult {
+		Votes::<T>::mutate(choice_id, |optional_vote| -> DispatchResult {
+			let vote = optional_vote.as_mut().ok_or(Error::<T>::VotesNotFound)?;
+			vote.who
+				.try_push(who.clone())
+				.ok()
+				.ok_or(Error::<T>::AccountLimitReached)?;
+			*optional_vote = Some(Vote {
+				who: vote.who.clone(),
+				vote_count: vote.vote_count + 1,
+				last_voted: <frame_system::Pallet<T>>::block_number(),
+				vote_weight: vote.vote_weight.saturating_add(weight),
+			});
+			Ok(())
+		})
+	}
+
+	/// Take the vote of `who` with the given weight back from a choice.
+	fn do_remove_vote(choice_id: T::ChoiceId, who: &T::AccountId, weight: u128) -> DispatchResult {
+		Votes::<T>::try_mutate(choice_id, |optional_vote| -> DispatchResult {
+			let vote = optional_vote.as_mut().ok_or(Error::<T>::VotesNotFound)?;
+			vote.who.retain(|voter| voter != who);
+			vote.vote_count = vote.vote_count.saturating_sub(1);
+			vote.vote_weight = vote.vote_weight.saturating_sub(weight);
+			Ok(())
+		})
+	}
+
+	/// Get the choice and the weight of the vote of `who` on an active revisable proposal.
+	fn revisable_vote(
+		community_id: T::CommunityId,
+		proposal_id: T::ProposalId,
+		who: &T::AccountId,
+	) -> Result<(T::ChoiceId, u128), DispatchError> {
+		let proposal = Proposals::<T>::get(community_id, proposal_id)
+			.ok_or(Error::<T>::ProposalDoesNotExist)?;
+		ensure!(proposal.status, Error::<T>::ProposalNotActive);
+		ensure!(
+			RevisableProposals::<T>::contains_key(proposal_id),
+			Error::<T>::VoteChangesNotAllowed
+		);
+
+		CastVotes::<T>::get(proposal_id, who).ok_or_else(|| Error::<T>::NotVoted.into())
+	}
+
+	/// Lock the stake of a weighted vote, holding assets in the stake account.
+	fn do_lock_stake(
+		who: &T::AccountId,
+		asset: Option<AssetIdOf<T>>,
+		amount: T::Balance,
+	) -> DispatchResult {
+		match asset {
+			Some(asset_id) => {
+				<T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset_id,
+					who,
+					&Self::stake_account(),
+					Self::asset_amount(amount),
+					Preservation::Expendable,
+				)?;
+			},
+			None => {
+				// A single lock covers the JUR staked on all the proposals.
+				let locked = LockedVoteBalance::<T>::get(who).saturating_add(amount);
+				ensure!(
+					pallet_balances::Pallet::<T>::free_balance(who) >= locked,
+					Error::<T>::InsufficientBalance
+				);
+				<pallet_balances::Pallet<T> as LockableCurrency<T::AccountId>>::set_lock(
+					VOTE_LOCK_ID,
+					who,
+					locked,
+					WithdrawReasons::all(),
+				);
+				LockedVoteBalance::<T>::insert(who, locked);
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Release the stake of a weighted vote.
+	fn do_unlock_stake(
+		who: &T::AccountId,
+		asset: Option<AssetIdOf<T>>,
+		amount: T::Balance,
+	) -> DispatchResult {
+		match asset {
+			Some(asset_id) => {
+				<T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset_id,
+					&Self::stake_account(),
+					who,
+					Self::asset_amount(amount),
+					Preservation::Expendable,
+				)?;
+			},
+			None => {
+				let locked = LockedVoteBalance::<T>::get(who).saturating_sub(amount);
+				if locked.is_zero() {
+					<pallet_balances::Pallet<T> as LockableCurrency<T::AccountId>>::remove_lock(
+						VOTE_LOCK_ID,
+						who,
+					);
+					LockedVoteBalance::<T>::remove(who);
+				} else {
+					<pallet_balances::Pallet<T> as LockableCurrency<T::AccountId>>::set_lock(
+						VOTE_LOCK_ID,
+						who,
+						locked,
+						WithdrawReasons::all(),
+					);
+					LockedVoteBalance::<T>::insert(who, locked);
+				}
+			},
+		}
+
+		Ok(())
+	}
+
+	/// The account holding the asset stakes of the weighted votes.
+	pub fn stake_account() -> T::AccountId {
+		T::StakePalletId::get().into_account_truncating()
+	}
+
+	/// Convert a JUR amount into an amount of an asset.
+	fn asset_amount(amount: T::Balance) -> AssetBalanceOf<T> {
+		amount.saturated_into::<u128>().saturated_into()
+	}
+
+	/// Whether the eligibility rule of the proposal allows the account to vote.
+	///
+	/// Only the passport rules are checked, not the membership of the account. A suspended
+	/// passport does not count.
+	pub fn is_eligible_voter(
+		community_id: T::CommunityId,
+		proposal_id: T::ProposalId,
+		who: &T::AccountId,
+	) -> bool {
+		let eligibility = match ProposalEligibility::<T>::get(proposal_id) {
+			Some(eligibility) => eligibility,
+			None => return true,
+		};
+		let passport = match pallet_passport::Pallet::<T>::active_passport_of(community_id, who) {
+			Some(passport) => passport,
+			None => return false,
+		};
+
+		match eligibility {
+			VoterEligibility::AnyMember | VoterEligibility::PassportHolders => true,
+			VoterEligibility::AnyBadge(badges) => {
+				badges.iter().any(|badge| passport.badges.contains(badge))
+			},
+			VoterEligibility::AllBadges(badges) => {
+				badges.iter().all(|badge| passport.badges.contains(badge))
+			},
+		}
+	}
+
+	/// The number of the `members` the eligibility rule allows to vote.
+	///
+	/// The passports are counted in the community and the community whose passports it
+	/// inherits. The holders of each badge count separately towards `AnyBadge`, and the least
+	/// held badge bounds `AllBadges`.
+	fn eligible_electorate(
+		community_id: T::CommunityId,
+		eligibility: &VoterEligibilityOf<T>,
+		members: u32,
+	) -> u32 {
+		let mut communities = vec![community_id];
+		let passport_community = pallet_community::Pallet::<T>::passport_community(community_id);
+		if passport_community != community_id {
+			communities.push(passport_community);
+		}
+
+		let holders = |badge: Option<
+			&BoundedVec<u8, <T as pallet_passport::Config>::BadgeNameLimit>,
+		>| {
+			communities.iter().fold(0_u32, |total, community_id| {
+				let count = match badge {
+					Some(badge) => pallet_passport::BadgeHolderCount::<T>::get(community_id, badge),
+					None => pallet_passport::PassportCount::<T>::get(community_id),
+				};
+				total.saturating_add(count)
+			})
+		};
+
+		let electorate = match eligibility {
+			VoterEligibility::AnyMember => return members,
+			VoterEligibility::PassportHolders => holders(None),
+			VoterEligibility::AnyBadge(badges) => badges
+				.iter()
+				.fold(0_u32, |total, badge| total.saturating_add(holders(Some(badge)))),
+			VoterEligibility::AllBadges(badges) => badges
+				.iter()
+				.map(|badge| holders(Some(badge)))
+				.min()
+				.unwrap_or_default(),
+		};
+
+		electorate.min(members)
+	}
+
+	/// Ensure a badge rule names existing badges of the community.
+	fn ensure_valid_eligibility(
+		community_id: T::CommunityId,
+		eligibility: &VoterEligibilityOf<T>,
+	) -> DispatchResult {
+		if let VoterEligibility::AnyBadge(badges) | VoterEligibility::AllBadges(badges) =
+			eligibility
+		{
+			ensure!(!badges.is_empty(), Error::<T>::InvalidEligibility);
+			ensure!(
+				badges
+					.iter()
+					.all(|badge| pallet_passport::Badges::<T>::contains_key(community_id, badge)),
+				Error::<T>::BadgeDoesNotExist
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Ensure a supermajority requires more than half of the votes.
+	fn ensure_valid_threshold(threshold: &ProposalThreshold) -> DispatchResult {
+		if let ApprovalThreshold::SuperMajority(share) = threshold.approval {
+			ensure!(share > Perbill::from_percent(50), Error::<T>::InvalidThreshold);
+		}
+
+		Ok(())
+	}
+
+	#[allow(clippy::too_many_arguments)]
 	pub fn do_create_proposal(
 		proposer_account: T::AccountId,
 		community_id: T::CommunityId,
@@ -423,6 +1766,10 @@ impl<T: Config> Pallet<T> {
 		choices: Vec<Vec<u8>>,
 		is_historical: bool,
 		proposal_duration: u32,
+		threshold: ProposalThreshold,
+		voting_mode: VotingModeOf<T>,
+		eligibility: VoterEligibilityOf<T>,
+		allow_vote_changes: bool,
 	) -> DispatchResultWithPostInfo {
 		let bounded_account: BoundedVec<T::AccountId, <T as Config>::AccountLimit> = Vec::new()
 			.clone()
@@ -443,9 +1790,11 @@ impl<T: Config> Pallet<T> {
 		let new_choices: Vec<Choice<T::ChoiceId, <T as Config>::LabelLimit>> = choices
 			.clone()
 			.into_iter()
-			.map(|choice| {
+			.map(|choice| -> Result<_, DispatchError> {
 				let bounded_choice: BoundedVec<u8, <T as Config>::LabelLimit> =
-					choice.try_into().expect("Choice Label Limit Exceeded.");
+					choice
+						.try_into()
+						.map_err(|_| Error::<T>::InvalidChoicesGiven)?;
 
 				let choice_id: T::ChoiceId =
 					NextChoiceId::<T>::get().unwrap_or(T::ChoiceId::initial_value());
@@ -453,14 +1802,15 @@ impl<T: Config> Pallet<T> {
 					who: bounded_account.clone(),
 					vote_count: 0,
 					last_voted: <frame_system::Pallet<T>>::block_number(),
+					vote_weight: 0,
 				};
 				<Votes<T>>::insert(choice_id, vote);
 
 				let next_choice_id = choice_id.increment();
 				NextChoiceId::<T>::set(Some(next_choice_id));
-				Choice { id: choice_id, label: bounded_choice }
+				Ok(Choice { id: choice_id, label: bounded_choice })
 			})
-			.collect::<Vec<_>>();
+			.collect::<Result<Vec<_>, _>>()?;
 
 		// Storing the proposal
 		<Proposals<T>>::insert(community_id, proposal_id, &new_proposal);
@@ -469,7 +1819,21 @@ impl<T: Config> Pallet<T> {
 		let total_block: u32 = BLOCKS_PER_DAY * proposal_duration;
 
 		let expire_block = frame_system::Pallet::<T>::block_number() + total_block.into();
-		ProposalExpireTime::<T>::insert(expire_block, (proposal_id, community_id));
+		ProposalExpiryQueue::<T>::schedule(expire_block, (proposal_id, community_id))
+			.map_err(|_| Error::<T>::TooManyExpiries)?;
+
+		// The stakes of weighted votes are locked until the scheduled end of the voting.
+		if voting_mode != VotingMode::OneMemberOneVote {
+			WeightedProposals::<T>::insert(proposal_id, (voting_mode, expire_block));
+		}
+
+		if eligibility != VoterEligibility::AnyMember {
+			ProposalEligibility::<T>::insert(proposal_id, &eligibility);
+		}
+
+		if allow_vote_changes {
+			RevisableProposals::<T>::insert(proposal_id, ());
+		}
 
 		let next_proposal_id = proposal_id.increment();
 		NextProposalId::<T>::set(Some(next_proposal_id));
@@ -478,8 +1842,128 @@ impl<T: Config> Pallet<T> {
 		if !choices.is_empty() {
 			<Choices<T>>::insert(proposal_id, new_choices);
 		}
+
+		// The quorum is measured against the voters allowed at the creation of the proposal.
+		let members = pallet_community::MemberCount::<T>::get(community_id);
+		let electorate = Self::eligible_electorate(community_id, &eligibility, members);
+		ProposalThresholds::<T>::insert(proposal_id, (threshold, electorate));
 		Self::deposit_event(Event::CreatedProposal(proposal_id));
 
 		Ok(().into())
 	}
+
+	/// Count the votes of every choice and pick the winner.
+	///
+	/// No choice wins when fewer `voters` than the quorum of the `electorate` voted. Otherwise
+	/// a single choice with the most weight wins by majority when it reached the approval
+	/// threshold of the total weight, and by plurality when it did not. Several choices with
+	/// the most weight are a tie and no choice wins.
+	pub fn do_tally(
+		choices: &[Choice<T::ChoiceId, <T as Config>::LabelLimit>],
+		voters: u64,
+		threshold: &ProposalThreshold,
+		electorate: u32,
+	) -> Result<ProposalTally<T::ChoiceId>, DispatchError> {
+		let breakdown = choices
+			.iter()
+			.map(|choice| {
+				Votes::<T>::get(choice.id)
+					.map(|vote| (choice.id, vote.vote_weight))
+					.ok_or(Error::<T>::VotesNotFound)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let top = breakdown
+			.iter()
+			.map(|(_, weight)| *weight)
+			.max()
+			.unwrap_or_default();
+		let total = breakdown
+			.iter()
+			.fold(0_u128, |total, (_, weight)| total.saturating_add(*weight));
+		let mut leaders = breakdown.iter().filter(|(_, weight)| *weight == top);
+
+		let quorum_reached = threshold
+			.quorum
+			.map_or(true, |quorum| voters >= quorum.mul_ceil(electorate as u64));
+		let approved = match threshold.approval {
+			ApprovalThreshold::SimpleMajority => top.saturating_mul(2) > total,
+			ApprovalThreshold::SuperMajority(share) => top >= share.mul_ceil(total),
+		};
+
+		let (status, winner) = match (leaders.next(), leaders.next()) {
+			_ if !quorum_reached => (ProposalResultStatus::QuorumNotReached, None),
+			(Some((choice_id, _)), None) if approved => {
+				(ProposalResultStatus::Majority, Some(*choice_id))
+			},
+			(Some((choice_id, _)), None) => (ProposalResultStatus::Plurality, Some(*choice_id)),
+			_ => (ProposalResultStatus::Tie, None),
+		};
+
+		Ok(ProposalTally { status, winner, breakdown })
+	}
+}
+
+impl<T: Config> OnCommunityDissolved<T::CommunityId> for Pallet<T> {
+	fn on_community_dissolved(community_id: T::CommunityId, limit: u32) -> u32 {
+		let mut removed = 0_u32;
+
+		// A proposal is only removed once its votes are all removed, the next call resumes
+		// with the same proposal.
+		while let Some(proposal_id) = Proposals::<T>::iter_key_prefix(community_id).next() {
+			if removed >= limit {
+				return limit;
+			}
+
+			let cursor = DissolveCursor::<T>::take()
+				.filter(|(cursor_proposal_id, _)| *cursor_proposal_id == proposal_id)
+				.map(|(_, cursor)| cursor);
+			let budget = limit.saturating_sub(removed);
+			let cleared = CastVotes::<T>::clear_prefix(proposal_id, budget, cursor.as_deref());
+			// The votes cast in the current block are removed beyond the budget.
+			removed = removed.saturating_add(cleared.unique.min(budget));
+
+			if let Some(cursor) = cleared.maybe_cursor {
+				DissolveCursor::<T>::put((proposal_id, cursor));
+				return limit;
+			}
+
+			let mut choices = Choices::<T>::get(proposal_id).unwrap_or_default();
+			while removed < limit {
+				match choices.pop() {
+					Some(choice) => Votes::<T>::remove(choice.id),
+					None => break,
+				}
+				removed = removed.saturating_add(1);
+			}
+
+			// The proposal together with its choices, result, amendment, scope, threshold,
+			// voting mode, eligibility, revisability and call. The stakes stay until their
+			// voters unlock them.
+			if !choices.is_empty() || removed.saturating_add(PROPOSAL_CLEANUP_ITEMS) > limit {
+				Choices::<T>::insert(proposal_id, choices);
+				return limit;
+			}
+
+			Choices::<T>::remove(proposal_id);
+			ProposalResult::<T>::remove(proposal_id);
+			ConstitutionAmendments::<T>::remove(proposal_id);
+			NationWideProposals::<T>::remove(proposal_id);
+			ProposalThresholds::<T>::remove(proposal_id);
+			WeightedProposals::<T>::remove(proposal_id);
+			ProposalEligibility::<T>::remove(proposal_id);
+			RevisableProposals::<T>::remove(proposal_id);
+			ProposalCalls::<T>::remove(proposal_id);
+			Proposals::<T>::remove(community_id, proposal_id);
+			removed = removed.saturating_add(PROPOSAL_CLEANUP_ITEMS);
+		}
+
+		if removed.saturating_add(2) > limit {
+			return limit;
+		}
+
+		CommunityThresholds::<T>::remove(community_id);
+		CommunityVotingAssets::<T>::remove(community_id);
+		removed.saturating_add(2)
+	}
 }
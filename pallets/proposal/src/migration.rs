@@ -0,0 +1,382 @@
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use log;
+use sp_runtime::Saturating;
+use sp_std::vec;
+
+pub mod v1 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 0 {
+				let now = frame_system::Pallet::<T>::block_number();
+				let mut translated = 0u64;
+
+				ProposalExpireTime::<T>::translate::<(T::ProposalId, T::CommunityId), _>(
+					|expire_block, old_value| {
+						translated.saturating_inc();
+
+						// The proposals expired before the upgrade are already closed.
+						(expire_block >= now).then(|| BoundedVec::truncate_from(vec![old_value]))
+					},
+				);
+				ProposalExpiryCursor::<T>::put(now);
+
+				StorageVersion::new(1).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Proposal Upgraded {} expiries, storage to version 1",
+					translated
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 2)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Proposal Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"must upgrade linearly"
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			let pending_count = ProposalExpireTime::<T>::iter_keys()
+				.filter(|expire_block| *expire_block >= now)
+				.count();
+			Ok((pending_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pending_count: Vec<u8>) -> Result<(), &'static str> {
+			let pending_count: u32 = Decode::decode(&mut pending_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = ProposalExpireTime::<T>::iter_values()
+				.map(|expiries| expiries.len() as u32)
+				.sum::<u32>();
+			assert_eq!(
+				pending_count, post_count,
+				"the pending expiries before and after the migration should be the same"
+			);
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, storage_alias, weights::Weight};
+
+	use super::*;
+
+	#[derive(Decode)]
+	pub(super) struct OldVote<BlockNumber, AccountId, AccountLimit: Get<u32>> {
+		pub who: BoundedVec<AccountId, AccountLimit>,
+		pub vote_count: u64,
+		pub last_voted: BlockNumber,
+	}
+
+	#[derive(Decode)]
+	enum OldProposalResultStatus {
+		Accepted,
+		Rejected,
+	}
+
+	#[derive(Encode, Decode)]
+	pub(super) struct OldProposalTally<ChoiceId> {
+		pub status: ProposalResultStatus,
+		pub winner: Option<ChoiceId>,
+		pub breakdown: Vec<(ChoiceId, u64)>,
+	}
+
+	/// The results, written with the vote counts of the time.
+	#[storage_alias]
+	type ProposalResult<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::ProposalId,
+		OldProposalTally<<T as Config>::ChoiceId>,
+		OptionQuery,
+	>;
+
+	/// Replace the accepted or rejected results by the tally of the proposal.
+	///
+	/// An accepted result stored the votes of the first choice and becomes a `Majority` of
+	/// it, a rejected one stored the votes of the second choice and becomes a `Plurality`.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 1 {
+				let mut translated = 0u64;
+
+				ProposalResult::<T>::translate::<
+					(
+						OldProposalResultStatus,
+						OldVote<BlockNumberFor<T>, T::AccountId, <T as Config>::AccountLimit>,
+					),
+					_,
+				>(|proposal_id, (old_status, old_vote)| {
+					translated.saturating_inc();
+
+					let (status, index) = match old_status {
+						OldProposalResultStatus::Accepted => (ProposalResultStatus::Majority, 0),
+						OldProposalResultStatus::Rejected => (ProposalResultStatus::Plurality, 1),
+					};
+
+					// The stored votes belong to the first or the second choice of the proposal.
+					match Choices::<T>::get(proposal_id)
+						.and_then(|choices| choices.get(index).cloned())
+					{
+						Some(choice) => Some(OldProposalTally {
+							status,
+							winner: Some(choice.id),
+							breakdown: vec![(choice.id, old_vote.vote_count)],
+						}),
+						None => {
+							log::error!(
+								target: LOG_TARGET,
+								"Choices not found for the result of proposal {:?}",
+								proposal_id
+							);
+							Some(OldProposalTally { status, winner: None, breakdown: Vec::new() })
+						},
+					}
+				});
+
+				StorageVersion::new(2).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Proposal Upgraded {} results, storage to version 2",
+					translated
+				);
+				T::DbWeight::get().reads_writes(translated * 2 + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Proposal Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"must upgrade linearly"
+			);
+			let count = ProposalResult::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(count: Vec<u8>) -> Result<(), &'static str> {
+			let count: u32 = Decode::decode(&mut count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = ProposalResult::<T>::iter_values().count() as u32;
+			assert_eq!(count, post_count, "every result should be translated");
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 2, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::v2::{OldProposalTally, OldVote};
+	use super::*;
+	use crate::pallet::ProposalResult;
+
+	/// Give every vote and result a weight equal to its number of votes.
+	pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version == 2 {
+				let mut translated = 0u64;
+
+				Votes::<T>::translate::<
+					OldVote<BlockNumberFor<T>, T::AccountId, <T as Config>::AccountLimit>,
+					_,
+				>(|_, old| {
+					translated.saturating_inc();
+					Some(Vote {
+						who: old.who,
+						vote_count: old.vote_count,
+						last_voted: old.last_voted,
+						vote_weight: old.vote_count as u128,
+					})
+				});
+
+				ProposalResult::<T>::translate::<OldProposalTally<T::ChoiceId>, _>(|_, old| {
+					translated.saturating_inc();
+					Some(ProposalTally {
+						status: old.status,
+						winner: old.winner,
+						breakdown: old
+							.breakdown
+							.into_iter()
+							.map(|(choice_id, count)| (choice_id, count as u128))
+							.collect(),
+					})
+				});
+
+				StorageVersion::new(3).put::<Pallet<T>>();
+				log::info!(
+					target: LOG_TARGET,
+					"Proposal Upgraded {} votes and results, storage to version 3",
+					translated
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Proposal Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"must upgrade linearly"
+			);
+			let count =
+				(Votes::<T>::iter_keys().count() + ProposalResult::<T>::iter_keys().count()) as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(count: Vec<u8>) -> Result<(), &'static str> {
+			let count: u32 = Decode::decode(&mut count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = (Votes::<T>::iter_values().count()
+				+ ProposalResult::<T>::iter_values().count()) as u32;
+			assert_eq!(count, post_count, "every vote and result should be translated");
+
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == 3, "must_upgrade");
+
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "try-runtime")]
+mod test {
+	use super::*;
+	use crate::{
+		mock::{Test as T, *},
+		pallet::ProposalResult,
+	};
+	use frame_support::{pallet_prelude::StorageVersion, storage::unhashed};
+
+	#[test]
+	fn migration_works() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0);
+			run_to_block(10);
+
+			unhashed::put(&ProposalExpireTime::<T>::hashed_key_for(5), &(1u32, 1u32));
+			unhashed::put(&ProposalExpireTime::<T>::hashed_key_for(20), &(2u32, 1u32));
+
+			let state = v1::MigrateToV1::<T>::pre_upgrade().unwrap();
+			let _w = v1::MigrateToV1::<T>::on_runtime_upgrade();
+			v1::MigrateToV1::<T>::post_upgrade(state).unwrap();
+
+			assert!(!ProposalExpireTime::<T>::contains_key(5));
+			assert_eq!(ProposalExpireTime::<T>::get(20).into_inner(), vec![(2, 1)]);
+			assert_eq!(ProposalExpiryCursor::<T>::get(), Some(10));
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
+		});
+	}
+
+	#[test]
+	fn migration_to_v2_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			let choices: Vec<Choice<u32, <T as Config>::LabelLimit>> = vec![
+				Choice { id: 1, label: BoundedVec::truncate_from(b"Yes".to_vec()) },
+				Choice { id: 2, label: BoundedVec::truncate_from(b"No".to_vec()) },
+			];
+			Choices::<T>::insert(1, choices.clone());
+			Choices::<T>::insert(2, choices);
+
+			let voters: BoundedVec<u64, <T as Config>::AccountLimit> =
+				BoundedVec::truncate_from(vec![1, 2]);
+			// The accepted result stored the votes of the first choice, the rejected one of the
+			// second choice.
+			unhashed::put(
+				&ProposalResult::<T>::hashed_key_for(1),
+				&(0u8, voters.clone(), 2u64, 5u64),
+			);
+			unhashed::put(&ProposalResult::<T>::hashed_key_for(2), &(1u8, voters, 2u64, 5u64));
+
+			let state = v2::MigrateToV2::<T>::pre_upgrade().unwrap();
+			let _w = v2::MigrateToV2::<T>::on_runtime_upgrade();
+			v2::MigrateToV2::<T>::post_upgrade(state).unwrap();
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+
+			let state = v3::MigrateToV3::<T>::pre_upgrade().unwrap();
+			let _w = v3::MigrateToV3::<T>::on_runtime_upgrade();
+			v3::MigrateToV3::<T>::post_upgrade(state).unwrap();
+
+			let accepted = ProposalResult::<T>::get(1).unwrap();
+			assert_eq!(accepted.status, ProposalResultStatus::Majority);
+			assert_eq!((accepted.winner, accepted.breakdown), (Some(1), vec![(1, 2)]));
+
+			let rejected = ProposalResult::<T>::get(2).unwrap();
+			assert_eq!(rejected.status, ProposalResultStatus::Plurality);
+			assert_eq!((rejected.winner, rejected.breakdown), (Some(2), vec![(2, 2)]));
+		});
+	}
+
+	#[test]
+	fn migration_to_v3_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			let voters: BoundedVec<u64, <T as Config>::AccountLimit> =
+				BoundedVec::truncate_from(vec![1, 2]);
+			unhashed::put(&Votes::<T>::hashed_key_for(1), &(voters.clone(), 2u64, 5u64));
+			unhashed::put(
+				&ProposalResult::<T>::hashed_key_for(1),
+				&(ProposalResultStatus::Majority, Some(1u32), vec![(1u32, 2u64), (2u32, 0u64)]),
+			);
+
+			let state = v3::MigrateToV3::<T>::pre_upgrade().unwrap();
+			let _w = v3::MigrateToV3::<T>::on_runtime_upgrade();
+			v3::MigrateToV3::<T>::post_upgrade(state).unwrap();
+
+			let vote = Votes::<T>::get(1).unwrap();
+			assert_eq!(vote.who, voters);
+			assert_eq!((vote.vote_count, vote.vote_weight), (2, 2));
+			assert_eq!(ProposalResult::<T>::get(1).unwrap().breakdown, vec![(1, 2), (2, 0)]);
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 3);
+		});
+	}
+}
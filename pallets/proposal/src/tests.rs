@@ -1,13 +1,28 @@
 use crate::pallet::ProposalResult;
-use crate::types::ProposalResultStatus;
-use crate::{mock::*, Choices, Error, Votes};
+use crate::types::{
+	ApprovalThreshold, Conviction, ProposalResultStatus, ProposalThreshold, VoterEligibility,
+	VotingMode,
+};
+use crate::{
+	mock::*, CastVotes, Choices, CommunityThresholds, ConstitutionAmendments, Error, Event,
+	LockedVoteBalance, NationWideProposals, ProposalCalls, ProposalEnactments, ProposalThresholds,
+	Proposals, StakedVotes, Votes,
+};
 use frame_support::pallet_prelude::ConstU32;
 use frame_support::BoundedVec;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
+	traits::{fungible::Mutate, Hooks},
+	weights::Weight,
+};
 use pallet_community::types::{
-	Category, CommunityMetaData, CommunityType, Customs, Languages, Religions, Territories,
-	Traditions, Values,
+	Category, CommunityMetaData, CommunityType, ConstitutionAmendment, Customs, DuesAmount,
+	Languages, Permissions, RecurringDue, Religions, State, Territories, Traditions, Values,
 };
+use pallet_community::OnCommunityDissolved;
+use pallet_passport::types::BadgesType;
+use sp_runtime::Perbill;
 
 fn get_community_metadata() -> CommunityMetaData<ConstU32<250>> {
 	let custom_one: Vec<u8> =
@@ -57,6 +72,7 @@ fn get_community_metadata() -> CommunityMetaData<ConstU32<250>> {
 	community_metadata
 }
 fn create_community() {
+	let community_id = pallet_community::NextCommunityId::<Test>::get().unwrap_or(1);
 	Community::create_community(
 		RuntimeOrigin::signed(1),
 		// hash of IPFS path of dummy logo
@@ -75,6 +91,9 @@ fn create_community() {
 		Some(CommunityType::Nation),
 	)
 	.unwrap();
+	for member in [2, 7, 8] {
+		Community::accept_invitation(RuntimeOrigin::signed(member), community_id).unwrap();
+	}
 }
 
 pub fn add_founder() {
@@ -99,6 +118,10 @@ fn create_proposal() {
 		vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
 		false,
 		5,
+		None,
+		VotingMode::OneMemberOneVote,
+		VoterEligibility::AnyMember,
+		false,
 	)
 	.unwrap();
 }
@@ -126,7 +149,67 @@ fn create_proposal_works() {
 				"England".as_bytes().to_vec()
 			],
 			false,
-			5
+			5,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
+		));
+
+		assert!(Choices::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn create_proposal_works_for_delegated_role() {
+	new_test_ext().execute_with(|| {
+		let proposal_name: Vec<u8> = "Jur community Language proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur community Language proposal".into();
+		let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+			proposal_description.try_into().unwrap();
+
+		add_founder();
+		create_community();
+
+		assert_noop!(
+			Proposal::create_proposal(
+				RuntimeOrigin::signed(2),
+				1,
+				bounded_proposal_name.clone(),
+				bounded_proposal_description.clone(),
+				vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+				false,
+				5,
+				None,
+				VotingMode::OneMemberOneVote,
+				VoterEligibility::AnyMember,
+				false,
+			),
+			Error::<Test>::NotAllowed
+		);
+
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Organizer".into(),
+			Permissions::CREATE_PROPOSALS
+		));
+
+		assert_ok!(Proposal::create_proposal(
+			RuntimeOrigin::signed(2),
+			1,
+			bounded_proposal_name,
+			bounded_proposal_description,
+			vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+			false,
+			5,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
 		));
 
 		assert!(Choices::<Test>::contains_key(1));
@@ -155,7 +238,11 @@ fn create_proposal_does_not_work_when_no_community_id() {
 					"England".as_bytes().to_vec()
 				],
 				false,
-				5
+				5,
+				None,
+				VotingMode::OneMemberOneVote,
+				VoterEligibility::AnyMember,
+				false,
 			),
 			Error::<Test>::CommunityDoesNotExist
 		);
@@ -206,7 +293,11 @@ fn cast_votes_not_work_for_invalid_input() {
 			bounded_proposal_description.clone(),
 			vec!["Yes".into(), "No".into()],
 			false,
-			5
+			5,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
 		));
 
 		let choice: Vec<u8> = "no".into();
@@ -224,7 +315,11 @@ fn cast_votes_not_work_for_invalid_input() {
 			bounded_proposal_description,
 			vec!["English".into(), "German".into()],
 			false,
-			5
+			5,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
 		));
 
 		let choice: Vec<u8> = "No".into();
@@ -262,6 +357,10 @@ fn cast_vote_not_work_for_after_proposal_deadline() {
 			],
 			false,
 			1,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
 		));
 
 		run_to_block(15_000);
@@ -332,7 +431,42 @@ fn create_proposal_not_working_invalid_choice() {
 				bounded_proposal_description,
 				vec![],
 				false,
-				5
+				5,
+				None,
+				VotingMode::OneMemberOneVote,
+				VoterEligibility::AnyMember,
+				false,
+			),
+			Error::<Test>::InvalidChoicesGiven
+		);
+	});
+}
+
+#[test]
+fn create_proposal_not_working_for_too_long_choice_label() {
+	new_test_ext().execute_with(|| {
+		let proposal_name: Vec<u8> = "Jur community Language proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur community Language proposal".into();
+		let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+			proposal_description.try_into().unwrap();
+
+		add_founder();
+		create_community();
+		assert_noop!(
+			Proposal::create_proposal(
+				RuntimeOrigin::signed(1),
+				1,
+				bounded_proposal_name,
+				bounded_proposal_description,
+				vec!["English".into(), "Portuguese!".into()],
+				false,
+				5,
+				None,
+				VotingMode::OneMemberOneVote,
+				VoterEligibility::AnyMember,
+				false,
 			),
 			Error::<Test>::InvalidChoicesGiven
 		);
@@ -359,6 +493,10 @@ fn cast_vote_works_with_proposal_result_accepted() {
 			vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec(),],
 			false,
 			1,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
 		));
 
 		let choice: Vec<u8> = "Yes".into();
@@ -370,7 +508,9 @@ fn cast_vote_works_with_proposal_result_accepted() {
 		run_to_block(15_000);
 
 		assert_eq!(Votes::<Test>::get(1).unwrap().vote_count, 2);
-		assert_eq!(ProposalResult::<Test>::get(1).unwrap().0, ProposalResultStatus::Accepted);
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Majority);
+		assert_eq!(result.winner, Some(1));
 	});
 }
 
@@ -394,6 +534,10 @@ fn cast_vote_works_with_proposal_result_rejected() {
 			vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec(),],
 			false,
 			1,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
 		));
 
 		let choice: Vec<u8> = "Yes".into();
@@ -409,6 +553,1435 @@ fn cast_vote_works_with_proposal_result_rejected() {
 		run_to_block(15_000);
 
 		assert_eq!(Votes::<Test>::get(2).unwrap().vote_count, 2);
-		assert_eq!(ProposalResult::<Test>::get(1).unwrap().0, ProposalResultStatus::Rejected);
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Majority);
+		assert_eq!(result.winner, Some(2));
+	});
+}
+
+#[test]
+fn cast_vote_works_with_proposal_result_plurality() {
+	new_test_ext().execute_with(|| {
+		let proposal_name: Vec<u8> = "Jur community Language proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur community Language proposal".into();
+		let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+			proposal_description.try_into().unwrap();
+
+		add_founder();
+		create_community();
+		assert_ok!(Proposal::create_proposal(
+			RuntimeOrigin::signed(1),
+			1,
+			bounded_proposal_name,
+			bounded_proposal_description,
+			vec![
+				"English".as_bytes().to_vec(),
+				"German".as_bytes().to_vec(),
+				"French".as_bytes().to_vec(),
+			],
+			false,
+			1,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
+		));
+
+		let choice: Vec<u8> = "French".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "German".into();
+		let bounded_choice2: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "English".into();
+		let bounded_choice3: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(1), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(7), 1, 1, bounded_choice2));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(8), 1, 1, bounded_choice3));
+
+		assert_eq!(Votes::<Test>::get(3).unwrap().vote_count, 2);
+
+		run_to_block(15_000);
+
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Plurality);
+		assert_eq!(result.winner, Some(3));
+		assert_eq!(result.breakdown, vec![(1, 1), (2, 1), (3, 2)]);
+		System::assert_has_event(
+			Event::<Test>::ProposalResultDecided(1, ProposalResultStatus::Plurality, Some(3))
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn cast_vote_works_with_proposal_result_tie() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice2: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(1), 1, 1, bounded_choice));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice2));
+
+		run_to_block(75_000);
+
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Tie);
+		assert_eq!(result.winner, None);
+		assert_eq!(result.breakdown, vec![(1, 1), (2, 1)]);
+	});
+}
+
+fn create_proposal_with_threshold(threshold: ProposalThreshold) {
+	let proposal_name: Vec<u8> = "Jur community Language proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of Jur community Language proposal".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+		proposal_description.try_into().unwrap();
+
+	add_founder();
+	create_community();
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+		false,
+		5,
+		Some(threshold),
+		VotingMode::OneMemberOneVote,
+		VoterEligibility::AnyMember,
+		false,
+	)
+	.unwrap();
+}
+
+#[test]
+fn cast_vote_works_with_proposal_result_quorum_not_reached() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_threshold(ProposalThreshold {
+			approval: ApprovalThreshold::SimpleMajority,
+			quorum: Some(Perbill::from_percent(75)),
+		});
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		// Two of the four members vote, while three are needed.
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(1), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+
+		run_to_block(75_000);
+
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::QuorumNotReached);
+		assert_eq!(result.winner, None);
+		assert_eq!(result.breakdown, vec![(1, 2), (2, 0)]);
+		System::assert_has_event(
+			Event::<Test>::ProposalResultDecided(1, ProposalResultStatus::QuorumNotReached, None)
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn cast_vote_works_with_proposal_result_supermajority() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_threshold(ProposalThreshold {
+			approval: ApprovalThreshold::SuperMajority(Perbill::from_percent(80)),
+			quorum: None,
+		});
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice2: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(1), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(7), 1, 1, bounded_choice));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(8), 1, 1, bounded_choice2));
+
+		run_to_block(75_000);
+
+		// Three votes out of four are a majority, but not a supermajority of 80%.
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Plurality);
+		assert_eq!(result.winner, Some(1));
+		assert!(!ProposalThresholds::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn set_proposal_threshold_works() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+
+		let threshold = ProposalThreshold {
+			approval: ApprovalThreshold::SuperMajority(Perbill::from_percent(66)),
+			quorum: Some(Perbill::from_percent(50)),
+		};
+
+		assert_noop!(
+			Proposal::set_proposal_threshold(RuntimeOrigin::signed(2), 1, Some(threshold)),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Proposal::set_proposal_threshold(
+				RuntimeOrigin::signed(1),
+				1,
+				Some(ProposalThreshold {
+					approval: ApprovalThreshold::SuperMajority(Perbill::from_percent(50)),
+					quorum: None,
+				})
+			),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			Proposal::set_proposal_threshold(RuntimeOrigin::signed(1), 2, Some(threshold)),
+			Error::<Test>::CommunityDoesNotExist
+		);
+
+		assert_ok!(Proposal::set_proposal_threshold(RuntimeOrigin::signed(1), 1, Some(threshold)));
+		assert_eq!(CommunityThresholds::<Test>::get(1), Some(threshold));
+		System::assert_last_event(Event::<Test>::UpdatedProposalThreshold(1).into());
+
+		// Proposals created without a threshold follow the default of the community.
+		assert_ok!(create_another_proposal());
+		assert_eq!(ProposalThresholds::<Test>::get(1), Some((threshold, 4)));
+
+		assert_ok!(Proposal::set_proposal_threshold(RuntimeOrigin::signed(1), 1, None));
+		assert!(!CommunityThresholds::<Test>::contains_key(1));
+
+		// A member holding the governance permission can set it as well.
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Governor".into(),
+			Permissions::MANAGE_GOVERNANCE
+		));
+		assert_ok!(Proposal::set_proposal_threshold(RuntimeOrigin::signed(2), 1, Some(threshold)));
+		assert_eq!(CommunityThresholds::<Test>::get(1), Some(threshold));
+	});
+}
+
+fn create_weighted_proposal(voting_mode: VotingMode<u32>) -> DispatchResultWithPostInfo {
+	let proposal_name: Vec<u8> = "Jur community Treasury proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of Jur community Treasury proposal".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+		false,
+		5,
+		None,
+		voting_mode,
+		VoterEligibility::AnyMember,
+		false,
+	)
+}
+
+#[test]
+fn cast_weighted_vote_works_with_locked_jur() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		Balances::set_balance(&1, 1_000);
+		Balances::set_balance(&2, 1_000);
+		assert_ok!(create_weighted_proposal(VotingMode::Native { conviction: true }));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice2: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::cast_vote(RuntimeOrigin::signed(7), 1, 1, bounded_choice.clone()),
+			Error::<Test>::WeightedVoteRequired
+		);
+		assert_noop!(
+			Proposal::cast_weighted_vote(
+				RuntimeOrigin::signed(7),
+				1,
+				1,
+				bounded_choice.clone(),
+				100,
+				Conviction::None
+			),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Proposal::cast_weighted_vote(
+			RuntimeOrigin::signed(1),
+			1,
+			1,
+			bounded_choice2,
+			100,
+			Conviction::None
+		));
+		assert_ok!(Proposal::cast_weighted_vote(
+			RuntimeOrigin::signed(2),
+			1,
+			1,
+			bounded_choice,
+			30,
+			Conviction::Locked4x
+		));
+		System::assert_last_event(Event::<Test>::VoteStakeLocked(1, 2, 30).into());
+
+		assert_eq!(Votes::<Test>::get(1).unwrap().vote_weight, 120);
+		assert_eq!(Proposal::locked_vote_balance(1), 100);
+		assert_eq!(Balances::locks(1)[0].amount, 100);
+
+		assert_noop!(
+			Proposal::unlock_vote(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::StakeStillLocked
+		);
+
+		run_to_block(72_001);
+
+		// 30 JUR with a 4x conviction outweigh 100 JUR without conviction.
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Majority);
+		assert_eq!(result.winner, Some(1));
+		assert_eq!(result.breakdown, vec![(1, 120), (2, 100)]);
+
+		assert_ok!(Proposal::unlock_vote(RuntimeOrigin::signed(1), 1));
+		assert!(Balances::locks(1).is_empty());
+		assert!(!LockedVoteBalance::<Test>::contains_key(1));
+		System::assert_last_event(Event::<Test>::VoteStakeUnlocked(1, 1, 100).into());
+
+		// The conviction keeps the stake locked for four more periods.
+		assert_noop!(
+			Proposal::unlock_vote(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::StakeStillLocked
+		);
+		run_to_block(72_401);
+		assert_ok!(Proposal::unlock_vote(RuntimeOrigin::signed(2), 1));
+		assert!(Balances::locks(2).is_empty());
+		assert_noop!(
+			Proposal::unlock_vote(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::StakeNotFound
+		);
+	});
+}
+
+#[test]
+fn cast_weighted_vote_works_with_community_asset() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 2, 100));
+
+		assert_noop!(
+			create_weighted_proposal(VotingMode::Asset { asset_id: 1, conviction: false }),
+			Error::<Test>::AssetNotDesignated
+		);
+		assert_noop!(
+			Proposal::set_voting_asset(RuntimeOrigin::signed(2), 1, Some(1)),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(Proposal::set_voting_asset(RuntimeOrigin::signed(1), 1, Some(1)));
+		System::assert_last_event(Event::<Test>::UpdatedVotingAsset(1).into());
+		assert_ok!(create_weighted_proposal(VotingMode::Asset { asset_id: 1, conviction: false }));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::cast_weighted_vote(
+				RuntimeOrigin::signed(2),
+				1,
+				1,
+				bounded_choice.clone(),
+				40,
+				Conviction::Locked2x
+			),
+			Error::<Test>::ConvictionNotAllowed
+		);
+		assert_ok!(Proposal::cast_weighted_vote(
+			RuntimeOrigin::signed(2),
+			1,
+			1,
+			bounded_choice,
+			40,
+			Conviction::None
+		));
+		assert_eq!(Assets::balance(1, 2), 60);
+		assert_eq!(Assets::balance(1, Proposal::stake_account()), 40);
+
+		run_to_block(72_001);
+
+		assert_eq!(ProposalResult::<Test>::get(1).unwrap().breakdown, vec![(1, 40), (2, 0)]);
+		assert_ok!(Proposal::unlock_vote(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Assets::balance(1, 2), 100);
+	});
+}
+
+#[test]
+fn cast_weighted_vote_not_works_for_unweighted_proposal() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::cast_weighted_vote(
+				RuntimeOrigin::signed(1),
+				1,
+				1,
+				bounded_choice,
+				100,
+				Conviction::None
+			),
+			Error::<Test>::NotWeightedProposal
+		);
+	});
+}
+
+fn add_badge(name: &str) {
+	let badge_description: Vec<u8> = "Badge of the Jur community members".into();
+	let badge_address: Vec<u8> =
+		"abcdreifec54rzopwm6mvqm3fknmdlsw2yefpdr7xrgtsron62on2nynegq".into();
+
+	Passport::add_badge(
+		RuntimeOrigin::signed(1),
+		1,
+		name.as_bytes().to_vec().try_into().unwrap(),
+		BadgesType::Participation,
+		badge_description.try_into().unwrap(),
+		badge_address.try_into().unwrap(),
+	)
+	.unwrap();
+}
+
+fn badges(names: &[&str]) -> BoundedVec<BoundedVec<u8, ConstU32<20>>, ConstU32<3>> {
+	names
+		.iter()
+		.map(|name| name.as_bytes().to_vec().try_into().unwrap())
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn create_restricted_proposal(
+	eligibility: VoterEligibility<ConstU32<20>, ConstU32<3>>,
+) -> DispatchResultWithPostInfo {
+	let proposal_name: Vec<u8> = "Jur community Citizens proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of Jur community Citizens proposal".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+		false,
+		5,
+		None,
+		VotingMode::OneMemberOneVote,
+		eligibility,
+		false,
+	)
+}
+
+#[test]
+fn cast_vote_works_for_passport_holders() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		assert_ok!(create_restricted_proposal(VoterEligibility::PassportHolders));
+		assert_eq!(Proposal::proposal_eligibility(1), Some(VoterEligibility::PassportHolders));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()),
+			Error::<Test>::NotEligible
+		);
+
+		assert_ok!(Passport::mint(RuntimeOrigin::signed(2), 1));
+
+		// A suspended passport does not allow to vote.
+		pallet_passport::SuspendedPassports::<Test>::insert(1, 2, 1);
+		assert_noop!(
+			Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()),
+			Error::<Test>::NotEligible
+		);
+
+		pallet_passport::SuspendedPassports::<Test>::remove(1, 2);
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+	});
+}
+
+#[test]
+fn cast_vote_works_for_badge_holders() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		add_badge("Citizens");
+		add_badge("Core");
+
+		assert_noop!(
+			create_restricted_proposal(VoterEligibility::AnyBadge(badges(&["Citizens", "Mars"]))),
+			Error::<Test>::BadgeDoesNotExist
+		);
+		assert_noop!(
+			create_restricted_proposal(VoterEligibility::AllBadges(badges(&[]))),
+			Error::<Test>::InvalidEligibility
+		);
+
+		assert_ok!(create_restricted_proposal(VoterEligibility::AllBadges(badges(&[
+			"Citizens", "Core"
+		]))));
+		assert_ok!(create_restricted_proposal(VoterEligibility::AnyBadge(badges(&[
+			"Citizens", "Core"
+		]))));
+		assert!(ProposalEligibility::<Test>::contains_key(2));
+
+		assert_ok!(Passport::mint(RuntimeOrigin::signed(2), 1));
+		assert_ok!(Passport::mint(RuntimeOrigin::signed(7), 1));
+		assert_ok!(Passport::issue_badge(
+			RuntimeOrigin::signed(1),
+			1,
+			"Citizens".as_bytes().to_vec().try_into().unwrap(),
+			vec![2, 7]
+		));
+		assert_ok!(Passport::issue_badge(
+			RuntimeOrigin::signed(1),
+			1,
+			"Core".as_bytes().to_vec().try_into().unwrap(),
+			vec![7]
+		));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		// Only the holders of both badges can vote on the first proposal.
+		assert_noop!(
+			Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()),
+			Error::<Test>::NotEligible
+		);
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(7), 1, 1, bounded_choice.clone()));
+
+		// Any of the badges is enough for the second one.
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 2, bounded_choice.clone()));
+		assert_noop!(
+			Proposal::cast_vote(RuntimeOrigin::signed(8), 1, 2, bounded_choice),
+			Error::<Test>::NotEligible
+		);
+	});
+}
+
+#[test]
+fn restricted_proposal_quorum_counts_eligible_voters() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		add_badge("Citizens");
+		add_badge("Core");
+
+		assert_ok!(Passport::mint(RuntimeOrigin::signed(2), 1));
+		assert_ok!(Passport::mint(RuntimeOrigin::signed(7), 1));
+		assert_ok!(Passport::issue_badge(
+			RuntimeOrigin::signed(1),
+			1,
+			"Citizens".as_bytes().to_vec().try_into().unwrap(),
+			vec![2, 7]
+		));
+		assert_ok!(Passport::issue_badge(
+			RuntimeOrigin::signed(1),
+			1,
+			"Core".as_bytes().to_vec().try_into().unwrap(),
+			vec![7]
+		));
+
+		assert_ok!(create_restricted_proposal(VoterEligibility::AnyMember));
+		assert_ok!(create_restricted_proposal(VoterEligibility::PassportHolders));
+		assert_ok!(create_restricted_proposal(VoterEligibility::AnyBadge(badges(&[
+			"Citizens", "Core"
+		]))));
+		assert_ok!(create_restricted_proposal(VoterEligibility::AllBadges(badges(&[
+			"Citizens", "Core"
+		]))));
+
+		let electorate = |proposal_id| ProposalThresholds::<Test>::get(proposal_id).map(|t| t.1);
+		assert_eq!(electorate(1), Some(4));
+		assert_eq!(electorate(2), Some(2));
+		// The holders of each badge are counted, capped by the members.
+		assert_eq!(electorate(3), Some(3));
+		assert_eq!(electorate(4), Some(1));
+	});
+}
+
+fn create_revisable_proposal(voting_mode: VotingMode<u32>) -> DispatchResultWithPostInfo {
+	let proposal_name: Vec<u8> = "Jur community Festival proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of Jur community Festival proposal".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+		false,
+		5,
+		None,
+		voting_mode,
+		VoterEligibility::AnyMember,
+		true,
+	)
+}
+
+#[test]
+fn change_vote_works() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		assert_ok!(create_revisable_proposal(VotingMode::OneMemberOneVote));
+		assert!(Proposal::revisable_proposals(1).is_some());
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice2: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::change_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice2.clone()),
+			Error::<Test>::NotVoted
+		);
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(7), 1, 1, bounded_choice.clone()));
+
+		assert_noop!(
+			Proposal::change_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice),
+			Error::<Test>::DuplicateVote
+		);
+		assert_noop!(
+			Proposal::change_vote(
+				RuntimeOrigin::signed(2),
+				1,
+				1,
+				"Maybe".as_bytes().to_vec().try_into().unwrap()
+			),
+			Error::<Test>::ChoiceDoesNotExist
+		);
+
+		assert_ok!(Proposal::change_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice2.clone()));
+		System::assert_last_event(Event::<Test>::VoteChanged(1).into());
+
+		let yes = Votes::<Test>::get(1).unwrap();
+		let no = Votes::<Test>::get(2).unwrap();
+		assert_eq!((yes.who.into_inner(), yes.vote_count, yes.vote_weight), (vec![7], 1, 1));
+		assert_eq!((no.who.into_inner(), no.vote_count, no.vote_weight), (vec![2], 1, 1));
+		assert_eq!(CastVotes::<Test>::get(1, 2), Some((2, 1)));
+		assert_eq!(
+			Proposals::<Test>::get(1, 1)
+				.unwrap()
+				.voter_accounts
+				.into_inner(),
+			vec![2, 7]
+		);
+
+		run_to_block(72_001);
+
+		assert_noop!(
+			Proposal::change_vote(RuntimeOrigin::signed(7), 1, 1, bounded_choice2),
+			Error::<Test>::ProposalNotActive
+		);
+		assert_eq!(ProposalResult::<Test>::get(1).unwrap().status, ProposalResultStatus::Tie);
+	});
+}
+
+#[test]
+fn retract_vote_works() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		assert_ok!(create_revisable_proposal(VotingMode::OneMemberOneVote));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::retract_vote(RuntimeOrigin::signed(2), 1, 1));
+		System::assert_last_event(Event::<Test>::VoteRetracted(1).into());
+
+		let yes = Votes::<Test>::get(1).unwrap();
+		assert_eq!((yes.who.len(), yes.vote_count, yes.vote_weight), (0, 0, 0));
+		assert!(Proposals::<Test>::get(1, 1)
+			.unwrap()
+			.voter_accounts
+			.is_empty());
+		assert!(!CastVotes::<Test>::contains_key(1, 2));
+
+		assert_noop!(
+			Proposal::retract_vote(RuntimeOrigin::signed(2), 1, 1),
+			Error::<Test>::NotVoted
+		);
+
+		// A retracted vote can be cast again.
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+		assert_eq!(Votes::<Test>::get(1).unwrap().vote_count, 1);
+	});
+}
+
+#[test]
+fn retract_vote_releases_the_stake_of_weighted_vote() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		Balances::set_balance(&2, 1_000);
+		assert_ok!(create_revisable_proposal(VotingMode::Native { conviction: true }));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice2: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_weighted_vote(
+			RuntimeOrigin::signed(2),
+			1,
+			1,
+			bounded_choice,
+			30,
+			Conviction::Locked4x
+		));
+
+		// The weight of the vote moves with it.
+		assert_ok!(Proposal::change_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice2));
+		assert_eq!(Votes::<Test>::get(1).unwrap().vote_weight, 0);
+		assert_eq!(Votes::<Test>::get(2).unwrap().vote_weight, 120);
+		assert_eq!(Proposal::locked_vote_balance(2), 30);
+
+		assert_ok!(Proposal::retract_vote(RuntimeOrigin::signed(2), 1, 1));
+		assert_eq!(Votes::<Test>::get(2).unwrap().vote_weight, 0);
+		assert!(!StakedVotes::<Test>::contains_key(2, 1));
+		assert!(!LockedVoteBalance::<Test>::contains_key(2));
+		assert!(Balances::locks(2).is_empty());
+		System::assert_has_event(Event::<Test>::VoteStakeUnlocked(1, 2, 30).into());
+	});
+}
+
+#[test]
+fn change_vote_not_works_for_binding_proposal() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice2: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+		assert!(!CastVotes::<Test>::contains_key(1, 2));
+
+		assert_noop!(
+			Proposal::change_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice2),
+			Error::<Test>::VoteChangesNotAllowed
+		);
+		assert_noop!(
+			Proposal::retract_vote(RuntimeOrigin::signed(2), 1, 1),
+			Error::<Test>::VoteChangesNotAllowed
+		);
+	});
+}
+
+fn create_another_proposal() -> DispatchResultWithPostInfo {
+	let proposal_name: Vec<u8> = "Jur community Religion proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of Jur community Religion proposal".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+		false,
+		5,
+		None,
+		VotingMode::OneMemberOneVote,
+		VoterEligibility::AnyMember,
+		false,
+	)
+}
+
+#[test]
+fn proposals_expiring_in_the_same_block_are_all_closed() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_another_proposal());
+		assert_ok!(create_another_proposal());
+		assert_noop!(create_another_proposal(), Error::<Test>::TooManyExpiries);
+		assert_ok!(Proposal::do_try_state());
+
+		// Only two proposals are closed in the block of the deadline.
+		run_to_block(72_001);
+		assert!(!Proposal::proposals(1, 1).unwrap().status);
+		assert!(!Proposal::proposals(1, 2).unwrap().status);
+		assert!(Proposal::proposals(1, 3).unwrap().status);
+
+		// The remaining one is carried over and closed with the spare weight of the block.
+		Proposal::on_idle(72_001, Weight::MAX);
+		assert!(!Proposal::proposals(1, 3).unwrap().status);
+		assert!(ProposalResult::<Test>::contains_key(3));
+		assert_eq!(Proposal::proposal_expiry_cursor(), Some(72_002));
+	});
+}
+
+#[test]
+fn expired_proposal_close_failure_is_reported() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		// An inconsistent proposal whose choices are missing.
+		Choices::<Test>::remove(1);
+
+		run_to_block(72_001);
+
+		System::assert_has_event(
+			Event::<Test>::ProposalCloseFailed(1, Error::<Test>::ChoiceDoesNotExist.into()).into(),
+		);
+		assert!(Proposal::proposals(1, 1).unwrap().status);
+		assert!(!ProposalResult::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn try_state_detects_expiry_of_missing_proposal() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::do_try_state());
+
+		Proposals::<Test>::remove(1, 1);
+		assert!(Proposal::do_try_state().is_err());
+
+		run_to_block(72_001);
+
+		System::assert_has_event(
+			Event::<Test>::ProposalCloseFailed(1, Error::<Test>::ProposalDoesNotExist.into())
+				.into(),
+		);
+		assert_ok!(Proposal::do_try_state());
+	});
+}
+
+fn create_state() {
+	let community_id = pallet_community::NextCommunityId::<Test>::get().unwrap_or(1);
+	Community::create_community(
+		RuntimeOrigin::signed(1),
+		None,
+		"Jur State".into(),
+		None,
+		Some(vec![1, 2, 9]),
+		None,
+		Category::Public,
+		None,
+		None,
+		None,
+		Some(CommunityType::State(State {
+			constitution: vec![b"Article one".to_vec().try_into().unwrap()]
+				.try_into()
+				.unwrap(),
+			government: vec![],
+			citizens: vec![],
+		})),
+	)
+	.unwrap();
+	for member in [2, 9] {
+		Community::accept_invitation(RuntimeOrigin::signed(member), community_id).unwrap();
+	}
+}
+
+fn propose_amendment(amendment: ConstitutionAmendment) {
+	let proposal_name: Vec<u8> = "Jur constitution amendment".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of Jur constitution amendment".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+		proposal_description.try_into().unwrap();
+
+	assert_ok!(Proposal::propose_constitution_amendment(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		amendment,
+		1,
+	));
+}
+
+#[test]
+fn constitution_amendment_applied_when_accepted() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_state();
+		propose_amendment(ConstitutionAmendment::AddArticle(
+			b"Article two".to_vec().try_into().unwrap(),
+		));
+		assert!(ConstitutionAmendments::<Test>::contains_key(1));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(1), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+
+		run_to_block(15_000);
+
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Majority);
+		assert_eq!(result.winner, Some(1));
+		System::assert_has_event(Event::<Test>::ConstitutionAmendmentApplied(1, Ok(())).into());
+		assert_eq!(
+			Community::state_of(1).unwrap().constitution,
+			vec![
+				b"Article one".to_vec().try_into().unwrap(),
+				b"Article two".to_vec().try_into().unwrap()
+			]
+		);
+		assert!(!ConstitutionAmendments::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn constitution_amendment_not_applied_when_rejected() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_state();
+		propose_amendment(ConstitutionAmendment::RemoveArticle(0));
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(1), 1, 1, bounded_choice));
+
+		run_to_block(15_000);
+
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.status, ProposalResultStatus::Majority);
+		assert_eq!(result.winner, Some(2));
+		assert_eq!(
+			Community::state_of(1).unwrap().constitution,
+			vec![b"Article one".to_vec().try_into().unwrap()]
+		);
+		assert!(!ConstitutionAmendments::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn propose_constitution_amendment_not_works_for_other_community_types() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+
+		let proposal_name: Vec<u8> = "Jur constitution amendment".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::propose_constitution_amendment(
+				RuntimeOrigin::signed(1),
+				1,
+				bounded_proposal_name,
+				Default::default(),
+				ConstitutionAmendment::RemoveArticle(0),
+				1,
+			),
+			pallet_community::Error::<Test>::NotStateCommunity
+		);
+	});
+}
+
+#[test]
+fn nation_wide_proposal_open_to_sub_community_members() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		create_state();
+
+		assert_ok!(Community::request_parent(RuntimeOrigin::signed(1), 2, 1, false, false));
+		assert_ok!(Community::approve_sub_community(RuntimeOrigin::signed(1), 1, 2));
+
+		let proposal_name: Vec<u8> = "Jur nation-wide proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+		assert_ok!(Proposal::create_proposal(
+			RuntimeOrigin::signed(1),
+			1,
+			bounded_proposal_name.clone(),
+			Default::default(),
+			vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+			false,
+			1,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
+		));
+		assert_ok!(Proposal::create_nation_wide_proposal(
+			RuntimeOrigin::signed(1),
+			1,
+			bounded_proposal_name,
+			Default::default(),
+			vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+			false,
+			1,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
+		));
+		assert!(!NationWideProposals::<Test>::contains_key(1));
+		assert!(NationWideProposals::<Test>::contains_key(2));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+
+		// Account 9 is only a member of the sub-community.
+		assert!(!Community::is_member(1, &9));
+		assert_noop!(
+			Proposal::cast_vote(RuntimeOrigin::signed(9), 1, 1, bounded_choice.clone()),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(9), 1, 2, bounded_choice));
+	});
+}
+
+#[test]
+fn nation_wide_proposal_not_works_with_quorum() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		let threshold = ProposalThreshold {
+			approval: ApprovalThreshold::SimpleMajority,
+			quorum: Some(Perbill::from_percent(50)),
+		};
+
+		let proposal_name: Vec<u8> = "Jur nation-wide proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::create_nation_wide_proposal(
+				RuntimeOrigin::signed(1),
+				1,
+				bounded_proposal_name.clone(),
+				Default::default(),
+				vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+				false,
+				1,
+				Some(threshold),
+				VotingMode::OneMemberOneVote,
+				VoterEligibility::AnyMember,
+				false,
+			),
+			Error::<Test>::QuorumNotAllowed
+		);
+
+		// The quorum of the default threshold is left out.
+		assert_ok!(Proposal::set_proposal_threshold(RuntimeOrigin::signed(1), 1, Some(threshold)));
+		assert_ok!(Proposal::create_nation_wide_proposal(
+			RuntimeOrigin::signed(1),
+			1,
+			bounded_proposal_name,
+			Default::default(),
+			vec!["Yes".as_bytes().to_vec(), "No".as_bytes().to_vec()],
+			false,
+			1,
+			None,
+			VotingMode::OneMemberOneVote,
+			VoterEligibility::AnyMember,
+			false,
+		));
+		let (threshold, _) = ProposalThresholds::<Test>::get(1).unwrap();
+		assert_eq!(threshold.quorum, None);
+	});
+}
+
+#[test]
+fn cast_vote_not_works_for_lapsed_member() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Community::set_membership_dues(
+			RuntimeOrigin::signed(1),
+			1,
+			None,
+			Some(RecurringDue { amount: DuesAmount::Native(10), period: 1 })
+		));
+		run_to_block(3);
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		assert_noop!(
+			Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()),
+			Error::<Test>::DuesLapsed
+		);
+
+		Balances::set_balance(&2, 100);
+		assert_ok!(Community::pay_dues(RuntimeOrigin::signed(2), 1, 1));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+	});
+}
+
+fn propose_call(call: RuntimeCall, enactment_delay: Option<u64>) -> DispatchResultWithPostInfo {
+	let proposal_name: Vec<u8> = "Jur community spending".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of Jur community spending".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::propose_call(
+		RuntimeOrigin::signed(1),
+		1,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		Box::new(call),
+		enactment_delay,
+		5,
+	)
+}
+
+fn set_quorum(quorum: Perbill) {
+	let threshold =
+		ProposalThreshold { approval: ApprovalThreshold::SimpleMajority, quorum: Some(quorum) };
+	assert_ok!(Proposal::set_proposal_threshold(RuntimeOrigin::signed(1), 1, Some(threshold)));
+}
+
+#[test]
+fn propose_call_works() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		set_quorum(Perbill::from_percent(25));
+		Balances::set_balance(&Community::treasury_account(1), 1_000);
+
+		let call = RuntimeCall::Community(pallet_community::Call::spend {
+			community_id: 1,
+			beneficiary: 3,
+			amount: 100,
+		});
+		assert_ok!(propose_call(call, Some(10)));
+		assert!(ProposalCalls::<Test>::contains_key(1));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+
+		run_to_block(72_001);
+		System::assert_has_event(Event::<Test>::ProposalEnactmentScheduled(1, 72_011).into());
+		assert_eq!(Balances::free_balance(3), 0);
+
+		run_to_block(72_011);
+		System::assert_has_event(Event::<Test>::ProposalEnacted(1, Ok(())).into());
+		assert_eq!(Balances::free_balance(3), 100);
+		assert!(!ProposalCalls::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn propose_call_without_delay_is_enacted_in_the_next_block() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		set_quorum(Perbill::from_percent(25));
+
+		let description = "Jur is governed by its members";
+		let call = RuntimeCall::Community(pallet_community::Call::update_community {
+			community_id: 1,
+			logo: None,
+			description: Some(description.into()),
+		});
+		assert_ok!(propose_call(call, None));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+
+		run_to_block(72_002);
+		System::assert_has_event(Event::<Test>::ProposalEnactmentScheduled(1, 72_002).into());
+		System::assert_has_event(Event::<Test>::ProposalEnacted(1, Ok(())).into());
+		assert_eq!(
+			pallet_community::Communities::<Test>::get(1)
+				.unwrap()
+				.description
+				.to_vec(),
+			description.as_bytes().to_vec()
+		);
+	});
+}
+
+#[test]
+fn propose_call_enactments_fit_in_the_block_budget() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		set_quorum(Perbill::from_percent(25));
+		Balances::set_balance(&Community::treasury_account(1), 1_000);
+
+		let call = RuntimeCall::Community(pallet_community::Call::spend {
+			community_id: 1,
+			beneficiary: 3,
+			amount: 100,
+		});
+		let call_weight = call.get_dispatch_info().weight;
+		MaxEnactmentWeight::set(call_weight.saturating_mul(3) / 2);
+
+		assert_ok!(propose_call(call.clone(), None));
+		assert_ok!(propose_call(call, None));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice.clone()));
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 2, bounded_choice));
+
+		// Only one of the calls fits in the budget of a block.
+		run_to_block(72_002);
+		System::assert_has_event(Event::<Test>::ProposalEnacted(1, Ok(())).into());
+		assert_eq!(Balances::free_balance(3), 100);
+		assert!(ProposalCalls::<Test>::contains_key(2));
+
+		run_to_block(72_003);
+		System::assert_has_event(Event::<Test>::ProposalEnacted(2, Ok(())).into());
+		assert_eq!(Balances::free_balance(3), 200);
+		assert!(!ProposalCalls::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn propose_call_not_enacted_when_rejected() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		set_quorum(Perbill::from_percent(25));
+		Balances::set_balance(&Community::treasury_account(1), 1_000);
+
+		let call = RuntimeCall::Community(pallet_community::Call::spend {
+			community_id: 1,
+			beneficiary: 3,
+			amount: 100,
+		});
+		assert_ok!(propose_call(call, None));
+
+		let choice: Vec<u8> = "No".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+
+		run_to_block(72_010);
+		let result = ProposalResult::<Test>::get(1).unwrap();
+		assert_eq!(result.winner, Some(2));
+		assert!(!ProposalCalls::<Test>::contains_key(1));
+		assert_eq!(Balances::free_balance(3), 0);
+	});
+}
+
+#[test]
+fn propose_call_not_works_for_too_long_call() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		set_quorum(Perbill::from_percent(25));
+
+		let call = RuntimeCall::Community(pallet_community::Call::update_community {
+			community_id: 1,
+			logo: None,
+			description: Some(vec![b'a'; 200]),
+		});
+		assert_noop!(propose_call(call, None), Error::<Test>::CallTooLong);
+	});
+}
+
+#[test]
+fn propose_call_not_works_without_quorum() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+
+		let call = RuntimeCall::Community(pallet_community::Call::spend {
+			community_id: 1,
+			beneficiary: 3,
+			amount: 100,
+		});
+		assert_noop!(propose_call(call, None), Error::<Test>::QuorumRequired);
+	});
+}
+
+#[test]
+fn propose_call_not_works_for_lone_delegate() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		set_quorum(Perbill::from_percent(50));
+		Balances::set_balance(&Community::treasury_account(1), 1_000);
+
+		let proposal_name: Vec<u8> = "Jur community spending".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<60>> = proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of Jur community spending".into();
+		let bounded_proposal_description: BoundedVec<u8, ConstU32<250>> =
+			proposal_description.try_into().unwrap();
+
+		let call = RuntimeCall::Community(pallet_community::Call::spend {
+			community_id: 1,
+			beneficiary: 2,
+			amount: 900,
+		});
+
+		// Creating proposals does not allow to propose calls.
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Delegate".into(),
+			Permissions::CREATE_PROPOSALS
+		));
+		assert_noop!(
+			Proposal::propose_call(
+				RuntimeOrigin::signed(2),
+				1,
+				bounded_proposal_name.clone(),
+				bounded_proposal_description.clone(),
+				Box::new(call.clone()),
+				None,
+				5,
+			),
+			Error::<Test>::NotAllowed
+		);
+
+		// A call proposed by a delegate is not accepted by its own vote alone.
+		assert_ok!(Community::grant_role(
+			RuntimeOrigin::signed(1),
+			1,
+			2,
+			"Delegate".into(),
+			Permissions::EXECUTE_CALLS
+		));
+		assert_ok!(Proposal::propose_call(
+			RuntimeOrigin::signed(2),
+			1,
+			bounded_proposal_name,
+			bounded_proposal_description,
+			Box::new(call),
+			None,
+			5,
+		));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+
+		run_to_block(72_010);
+		assert_eq!(
+			ProposalResult::<Test>::get(1).unwrap().status,
+			ProposalResultStatus::QuorumNotReached
+		);
+		assert!(!ProposalCalls::<Test>::contains_key(1));
+		assert_eq!(Balances::free_balance(Community::treasury_account(1)), 1_000);
+	});
+}
+
+#[test]
+fn propose_call_deferred_when_enactment_block_is_full() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		set_quorum(Perbill::from_percent(25));
+		Balances::set_balance(&Community::treasury_account(1), 1_000);
+
+		let call = RuntimeCall::Community(pallet_community::Call::spend {
+			community_id: 1,
+			beneficiary: 3,
+			amount: 100,
+		});
+		assert_ok!(propose_call(call, None));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		assert_ok!(Proposal::cast_vote(RuntimeOrigin::signed(2), 1, 1, bounded_choice));
+
+		ProposalEnactments::<Test>::insert(
+			72_002,
+			BoundedVec::truncate_from(vec![(90, 1), (91, 1), (92, 1)]),
+		);
+
+		run_to_block(72_002);
+		System::assert_has_event(Event::<Test>::ProposalEnactmentScheduled(1, 72_003).into());
+		assert_eq!(Balances::free_balance(3), 0);
+
+		run_to_block(72_003);
+		System::assert_has_event(Event::<Test>::ProposalEnacted(1, Ok(())).into());
+		assert_eq!(Balances::free_balance(3), 100);
+	});
+}
+
+#[test]
+fn dissolved_community_proposals_are_removed_within_the_limit() {
+	new_test_ext().execute_with(|| {
+		add_founder();
+		create_community();
+		assert_ok!(create_revisable_proposal(VotingMode::OneMemberOneVote));
+
+		let choice: Vec<u8> = "Yes".into();
+		let bounded_choice: BoundedVec<u8, ConstU32<10>> = choice.try_into().unwrap();
+		for voter in [2, 7, 8] {
+			assert_ok!(Proposal::cast_vote(
+				RuntimeOrigin::signed(voter),
+				1,
+				1,
+				bounded_choice.clone()
+			));
+		}
+
+		// The votes fit in the limit but not the proposal itself.
+		assert_eq!(Proposal::on_community_dissolved(1, 10), 10);
+		assert_eq!(CastVotes::<Test>::iter_prefix(1).count(), 0);
+		assert!(Votes::<Test>::get(1).is_none());
+		assert!(Proposals::<Test>::contains_key(1, 1));
+
+		assert_eq!(Proposal::on_community_dissolved(1, 10), 10);
+		assert!(!Proposals::<Test>::contains_key(1, 1));
+		assert!(Choices::<Test>::get(1).is_none());
+
+		assert_eq!(Proposal::on_community_dissolved(1, 10), 2);
 	});
 }
@@ -1,7 +1,10 @@
-use codec::{Decode, Encode};
-use frame_support::{pallet_prelude::Get, BoundedVec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{Perbill, RuntimeDebug};
+use sp_std::vec::Vec;
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
 #[scale_info(skip_type_params(DescriptionLimit, NameLimit, AccountLimit))]
@@ -32,13 +35,135 @@ pub struct Vote<BlockNumber, AccountId, AccountLimit: Get<u32>> {
 	pub who: BoundedVec<AccountId, AccountLimit>,
 	pub vote_count: u64,
 	pub last_voted: BlockNumber,
+	/// The total weight of the votes, equal to `vote_count` unless the votes are weighted.
+	pub vote_weight: u128,
 }
 
 /// Result of proposal.
 #[derive(Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, Encode, Decode)]
 pub enum ProposalResultStatus {
-	/// Proposal is passed.
-	Accepted,
-	/// Proposal is rejected.
-	Rejected,
+	/// The winning choice reached the approval threshold of the proposal.
+	Majority,
+	/// The winning choice got the most votes, but not enough to reach the approval threshold.
+	Plurality,
+	/// Several choices got the most votes, so no choice has won.
+	Tie,
+	/// Not enough members voted, so no choice has won.
+	QuorumNotReached,
+}
+
+/// Outcome of the voting on a proposal.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProposalTally<ChoiceId> {
+	pub status: ProposalResultStatus,
+	pub winner: Option<ChoiceId>,
+	/// The weight of the votes of every choice, in the order of the choices.
+	pub breakdown: Vec<(ChoiceId, u128)>,
+}
+
+/// Share of the votes the winning choice of a proposal needs.
+#[derive(
+	Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, Encode, Decode, MaxEncodedLen, Default,
+)]
+pub enum ApprovalThreshold {
+	/// More than half of the votes.
+	#[default]
+	SimpleMajority,
+	/// At least the given share of the votes, which must be more than half of them.
+	SuperMajority(Perbill),
+}
+
+/// Rules deciding the result of a proposal.
+#[derive(
+	Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, Encode, Decode, MaxEncodedLen, Default,
+)]
+pub struct ProposalThreshold {
+	pub approval: ApprovalThreshold,
+	/// The share of the members at the creation of the proposal which has to vote.
+	pub quorum: Option<Perbill>,
+}
+
+/// How the votes on a proposal are weighted.
+#[derive(Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+pub enum VotingMode<AssetId> {
+	/// Every voter counts as one vote.
+	OneMemberOneVote,
+	/// Votes are weighted by the JUR locked until the end of the proposal.
+	Native { conviction: bool },
+	/// Votes are weighted by the asset designated by the community, held until the end of
+	/// the proposal.
+	Asset { asset_id: AssetId, conviction: bool },
+}
+
+/// Multiplier of a weighted vote in exchange for keeping its stake locked after the end of
+/// the proposal.
+#[derive(
+	Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, Encode, Decode, MaxEncodedLen, Default,
+)]
+pub enum Conviction {
+	/// 1x the stake, unlocked at the end of the proposal.
+	#[default]
+	None,
+	/// 2x the stake, locked for 1 period after the end of the proposal.
+	Locked2x,
+	/// 3x the stake, locked for 2 periods after the end of the proposal.
+	Locked3x,
+	/// 4x the stake, locked for 4 periods after the end of the proposal.
+	Locked4x,
+	/// 5x the stake, locked for 8 periods after the end of the proposal.
+	Locked5x,
+	/// 6x the stake, locked for 16 periods after the end of the proposal.
+	Locked6x,
+}
+
+impl Conviction {
+	/// The multiplier applied to the stake of a vote.
+	pub fn multiplier(self) -> u128 {
+		match self {
+			Conviction::None => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 3,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 5,
+			Conviction::Locked6x => 6,
+		}
+	}
+
+	/// The number of periods the stake stays locked after the end of the proposal.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked2x => 1,
+			Conviction::Locked3x => 2,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 8,
+			Conviction::Locked6x => 16,
+		}
+	}
+}
+
+/// Stake locked by a weighted vote.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StakedVote<CommunityId, AssetId, Balance, BlockNumber> {
+	pub community_id: CommunityId,
+	/// The asset held, or `None` for locked JUR.
+	pub asset: Option<AssetId>,
+	pub amount: Balance,
+	pub unlock_at: BlockNumber,
+}
+
+/// Members allowed to vote on a proposal.
+#[derive(
+	CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, Encode, Decode,
+)]
+#[scale_info(skip_type_params(BadgeNameLimit, MaxBadges))]
+pub enum VoterEligibility<BadgeNameLimit: Get<u32>, MaxBadges: Get<u32>> {
+	/// Every member of the community.
+	AnyMember,
+	/// The members holding a passport of the community.
+	PassportHolders,
+	/// The members whose passport holds at least one of the badges.
+	AnyBadge(BoundedVec<BoundedVec<u8, BadgeNameLimit>, MaxBadges>),
+	/// The members whose passport holds all of the badges.
+	AllBadges(BoundedVec<BoundedVec<u8, BadgeNameLimit>, MaxBadges>),
 }
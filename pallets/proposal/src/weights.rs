@@ -31,6 +31,18 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create_proposal() -> Weight;
 	fn cast_vote() -> Weight;
+	fn propose_constitution_amendment() -> Weight;
+	fn create_nation_wide_proposal() -> Weight;
+	fn service_expiry_block() -> Weight;
+	fn close_proposal() -> Weight;
+	fn set_proposal_threshold() -> Weight;
+	fn cast_weighted_vote() -> Weight;
+	fn unlock_vote() -> Weight;
+	fn set_voting_asset() -> Weight;
+	fn change_vote() -> Weight;
+	fn retract_vote() -> Weight;
+	fn propose_call() -> Weight;
+	fn enact_proposal() -> Weight;
 }
 
 /// Weights for pallet_proposal using the Substrate node and recommended hardware.
@@ -42,7 +54,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
 	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Proposal::ProposalExpireTime` (r:0 w:1)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
 	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Proposals` (r:0 w:1)
 	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -50,14 +62,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Votes` (r:0 w:4)
 	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:0 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityVotingAssets` (r:1 w:0)
+	/// Proof: `Proposal::CommunityVotingAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::WeightedProposals` (r:0 w:1)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Badges` (r:1 w:0)
+	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:0 w:1)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:0 w:1)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:2 w:0)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:6 w:0)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `806`
 		//  Estimated: `4271`
 		// Minimum execution time: 39_000_000 picoseconds.
 		Weight::from_parts(40_000_000, 4271)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -67,15 +99,327 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Votes` (r:1 w:1)
 	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::WeightedProposals` (r:1 w:0)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:1 w:0)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:2 w:0)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:0 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:2 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn cast_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1272`
 		//  Estimated: `4737`
 		// Minimum execution time: 37_000_000 picoseconds.
 		Weight::from_parts(38_000_000, 4737)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextProposalId` (r:1 w:1)
+	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
+	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:0 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:0 w:1)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:0 w:2)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ConstitutionAmendments` (r:0 w:1)
+	/// Proof: `Proposal::ConstitutionAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:0 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_constitution_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `842`
+		//  Estimated: `4307`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4307)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextProposalId` (r:2 w:1)
+	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
+	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:0 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:0 w:1)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:0 w:4)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NationWideProposals` (r:0 w:1)
+	/// Proof: `Proposal::NationWideProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:1 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:0)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityVotingAssets` (r:1 w:0)
+	/// Proof: `Proposal::CommunityVotingAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::WeightedProposals` (r:0 w:1)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Badges` (r:1 w:0)
+	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:0 w:1)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:0 w:1)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:2 w:0)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:6 w:0)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_nation_wide_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806`
+		//  Estimated: `4271`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 4271)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Proposal::ProposalExpiryCursor` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpiryCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_expiry_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3604`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 3604)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Community::Communities` (r:1 w:1)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:1 w:0)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:2 w:0)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ConstitutionAmendments` (r:1 w:1)
+	/// Proof: `Proposal::ConstitutionAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalResult` (r:0 w:1)
+	/// Proof: `Proposal::ProposalResult` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:1 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalCalls` (r:1 w:1)
+	/// Proof: `Proposal::ProposalCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEnactments` (r:10 w:1)
+	/// Proof: `Proposal::ProposalEnactments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn close_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `6983`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 6983)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:0 w:1)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_proposal_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::WeightedProposals` (r:1 w:0)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:1 w:0)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:1 w:1)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::StakedVotes` (r:0 w:1)
+	/// Proof: `Proposal::StakedVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:1 w:0)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:2 w:0)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:0 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:2 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cast_weighted_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1684`
+		//  Estimated: `6208`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Proposal::StakedVotes` (r:1 w:1)
+	/// Proof: `Proposal::StakedVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unlock_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `6208`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityVotingAssets` (r:0 w:1)
+	/// Proof: `Proposal::CommunityVotingAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_voting_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:1 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:1 w:0)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:2 w:2)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn change_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1420`
+		//  Estimated: `6360`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 6360)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:1 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:1 w:1)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::StakedVotes` (r:1 w:1)
+	/// Proof: `Proposal::StakedVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn retract_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1634`
+		//  Estimated: `6208`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(53_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextProposalId` (r:1 w:1)
+	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
+	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:0 w:2)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:0 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:0 w:1)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:0 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalCalls` (r:0 w:1)
+	/// Proof: `Proposal::ProposalCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806`
+		//  Estimated: `4271`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4271)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Proposal::ProposalCalls` (r:1 w:1)
+	/// Proof: `Proposal::ProposalCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn enact_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320`
+		//  Estimated: `3785`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3785)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -86,7 +430,7 @@ impl WeightInfo for () {
 	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
 	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Proposal::ProposalExpireTime` (r:0 w:1)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
 	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Proposals` (r:0 w:1)
 	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -94,14 +438,34 @@ impl WeightInfo for () {
 	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Votes` (r:0 w:4)
 	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:0 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityVotingAssets` (r:1 w:0)
+	/// Proof: `Proposal::CommunityVotingAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::WeightedProposals` (r:0 w:1)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Badges` (r:1 w:0)
+	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:0 w:1)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:0 w:1)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:2 w:0)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:6 w:0)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `806`
 		//  Estimated: `4271`
 		// Minimum execution time: 39_000_000 picoseconds.
 		Weight::from_parts(40_000_000, 4271)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
 	}
 	/// Storage: `Community::Communities` (r:1 w:0)
 	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -111,13 +475,325 @@ impl WeightInfo for () {
 	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Votes` (r:1 w:1)
 	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::WeightedProposals` (r:1 w:0)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:1 w:0)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:2 w:0)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:0 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:2 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn cast_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1272`
 		//  Estimated: `4737`
 		// Minimum execution time: 37_000_000 picoseconds.
 		Weight::from_parts(38_000_000, 4737)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextProposalId` (r:1 w:1)
+	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
+	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:0 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:0 w:1)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:0 w:2)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ConstitutionAmendments` (r:0 w:1)
+	/// Proof: `Proposal::ConstitutionAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:0 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_constitution_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `842`
+		//  Estimated: `4307`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4307)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextProposalId` (r:2 w:1)
+	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
+	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:0 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:0 w:1)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:0 w:4)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NationWideProposals` (r:0 w:1)
+	/// Proof: `Proposal::NationWideProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:1 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::SubCommunities` (r:1 w:0)
+	/// Proof: `Community::SubCommunities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityVotingAssets` (r:1 w:0)
+	/// Proof: `Proposal::CommunityVotingAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::WeightedProposals` (r:0 w:1)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Badges` (r:1 w:0)
+	/// Proof: `Passport::Badges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:0 w:1)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:0 w:1)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::PassportCount` (r:2 w:0)
+	/// Proof: `Passport::PassportCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::BadgeHolderCount` (r:6 w:0)
+	/// Proof: `Passport::BadgeHolderCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_nation_wide_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806`
+		//  Estimated: `4271`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 4271)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Proposal::ProposalExpiryCursor` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpiryCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_expiry_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3604`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 3604)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Community::Communities` (r:1 w:1)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:1 w:0)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:2 w:0)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ConstitutionAmendments` (r:1 w:1)
+	/// Proof: `Proposal::ConstitutionAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalResult` (r:0 w:1)
+	/// Proof: `Proposal::ProposalResult` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:1 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalCalls` (r:1 w:1)
+	/// Proof: `Proposal::ProposalCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEnactments` (r:10 w:1)
+	/// Proof: `Proposal::ProposalEnactments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn close_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `6983`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 6983)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:0 w:1)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_proposal_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::WeightedProposals` (r:1 w:0)
+	/// Proof: `Proposal::WeightedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityDues` (r:1 w:0)
+	/// Proof: `Community::CommunityDues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:1 w:0)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:1 w:1)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::StakedVotes` (r:0 w:1)
+	/// Proof: `Proposal::StakedVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalEligibility` (r:1 w:0)
+	/// Proof: `Proposal::ProposalEligibility` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::Passports` (r:2 w:0)
+	/// Proof: `Passport::Passports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:0 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Passport::SuspendedPassports` (r:2 w:0)
+	/// Proof: `Passport::SuspendedPassports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cast_weighted_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1684`
+		//  Estimated: `6208`
+		// Minimum execution time: 62_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Proposal::StakedVotes` (r:1 w:1)
+	/// Proof: `Proposal::StakedVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unlock_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `6208`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityVotingAssets` (r:0 w:1)
+	/// Proof: `Proposal::CommunityVotingAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityMembers` (r:1 w:0)
+	/// Proof: `Community::CommunityMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_voting_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:1 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:1 w:0)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:2 w:2)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn change_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1420`
+		//  Estimated: `6360`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 6360)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::RevisableProposals` (r:1 w:0)
+	/// Proof: `Proposal::RevisableProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CastVotes` (r:1 w:1)
+	/// Proof: `Proposal::CastVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:1 w:1)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::StakedVotes` (r:1 w:1)
+	/// Proof: `Proposal::StakedVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn retract_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1634`
+		//  Estimated: `6208`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(53_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Community::Communities` (r:1 w:0)
+	/// Proof: `Community::Communities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::CommunityRoles` (r:1 w:0)
+	/// Proof: `Community::CommunityRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextProposalId` (r:1 w:1)
+	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::NextChoiceId` (r:1 w:1)
+	/// Proof: `Proposal::NextChoiceId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CommunityThresholds` (r:1 w:0)
+	/// Proof: `Proposal::CommunityThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Community::MemberCount` (r:1 w:0)
+	/// Proof: `Community::MemberCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Votes` (r:0 w:2)
+	/// Proof: `Proposal::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:0 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Choices` (r:0 w:1)
+	/// Proof: `Proposal::Choices` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalThresholds` (r:0 w:1)
+	/// Proof: `Proposal::ProposalThresholds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalCalls` (r:0 w:1)
+	/// Proof: `Proposal::ProposalCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806`
+		//  Estimated: `4271`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4271)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Proposal::ProposalCalls` (r:1 w:1)
+	/// Proof: `Proposal::ProposalCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn enact_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320`
+		//  Estimated: `3785`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3785)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
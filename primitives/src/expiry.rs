@@ -0,0 +1,126 @@
+//! A bounded queue of the items expiring at a given block.
+//!
+//! The items are kept per block in a `StorageMap` and a `StorageValue` tracks the first block
+//! whose items have not all been processed yet. The items which don't fit in the budget of a
+//! block stay queued and are processed first in the following blocks.
+
+use frame_support::{
+	storage::{StorageMap, StorageValue},
+	traits::Get,
+	BoundedVec,
+};
+use parity_scale_codec::FullCodec;
+use sp_runtime::traits::{One, Saturating};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Expiry queue stored in the `Queue` map of the items expiring at every block, with the
+/// `Cursor` value holding the first block which has not been fully processed.
+pub struct ExpiryQueue<BlockNumber, Item, Limit, Queue, Cursor>(
+	PhantomData<(BlockNumber, Item, Limit, Queue, Cursor)>,
+);
+
+impl<BlockNumber, Item, Limit, Queue, Cursor> ExpiryQueue<BlockNumber, Item, Limit, Queue, Cursor>
+where
+	BlockNumber: FullCodec + Copy + PartialOrd + One + Saturating,
+	Item: FullCodec + PartialEq,
+	Limit: Get<u32>,
+	Queue: StorageMap<BlockNumber, BoundedVec<Item, Limit>, Query = BoundedVec<Item, Limit>>,
+	Cursor: StorageValue<BlockNumber, Query = Option<BlockNumber>>,
+{
+	/// Schedule `item` to expire at the block `at`.
+	///
+	/// The item is given back when `Limit` items already expire at that block.
+	pub fn schedule(at: BlockNumber, item: Item) -> Result<(), Item> {
+		Queue::try_mutate(at, |items| items.try_push(item))
+	}
+
+	/// Schedule `item` to expire at the first block from `at` with room left, looking at most
+	/// `blocks` blocks ahead.
+	///
+	/// Returns the block the item expires at. The item is given back when all those blocks
+	/// are full.
+	pub fn schedule_within(at: BlockNumber, blocks: u32, item: Item) -> Result<BlockNumber, Item> {
+		let mut block = at;
+		let mut item = item;
+
+		for _ in 0..blocks {
+			match Self::schedule(block, item) {
+				Ok(()) => return Ok(block),
+				Err(returned) => item = returned,
+			}
+			block.saturating_inc();
+		}
+
+		Err(item)
+	}
+
+	/// Remove `item` from the items expiring at the block `at`.
+	pub fn cancel(at: BlockNumber, item: &Item) {
+		let mut items = Queue::get(at);
+		items.retain(|queued| queued != item);
+
+		if items.is_empty() {
+			Queue::remove(at);
+		} else {
+			Queue::insert(at, items);
+		}
+	}
+
+	/// Hand the items expired up to the block `now` to `expire`, oldest first.
+	///
+	/// At most `limit` blocks are visited and `limit` items processed. The remaining items are
+	/// carried over and processed first by the next call.
+	///
+	/// Returns the number of visited blocks and of processed items.
+	pub fn service(now: BlockNumber, limit: u32, mut expire: impl FnMut(Item)) -> (u32, u32) {
+		Self::try_service(now, limit, |item| {
+			expire(item);
+			Ok(())
+		})
+	}
+
+	/// Hand the items expired up to the block `now` to `expire`, oldest first, until it gives
+	/// one back.
+	///
+	/// The item given back stays queued with the remaining ones, and is handed first to the
+	/// next call. The limits of [`Self::service`] apply as well.
+	///
+	/// Returns the number of visited blocks and of processed items.
+	pub fn try_service(
+		now: BlockNumber,
+		limit: u32,
+		mut expire: impl FnMut(Item) -> Result<(), Item>,
+	) -> (u32, u32) {
+		let mut block = Cursor::get().unwrap_or(now);
+		let mut visited = 0_u32;
+		let mut processed = 0_u32;
+
+		while block <= now && visited < limit && processed < limit {
+			let items = Queue::take(block).into_inner();
+			visited.saturating_inc();
+
+			let mut rest = Vec::new();
+			for item in items {
+				if !rest.is_empty() || processed >= limit {
+					rest.push(item);
+					continue;
+				}
+
+				match expire(item) {
+					Ok(()) => processed.saturating_inc(),
+					Err(item) => rest.push(item),
+				}
+			}
+
+			if !rest.is_empty() {
+				Queue::insert(block, BoundedVec::<Item, Limit>::truncate_from(rest));
+				break;
+			}
+
+			block.saturating_inc();
+		}
+
+		Cursor::put(block);
+		(visited, processed)
+	}
+}
@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod expiry;
 pub mod proof;
 
 pub mod macros;
@@ -2,8 +2,12 @@ use crate::proof::{
 	compute_storage_key_for_depositor, convert, decode_rlp, extract_storage_root, verify_proof,
 	ErrorMessage,
 };
-use crate::EthereumAddress;
-use frame_support::assert_ok;
+use crate::{expiry::ExpiryQueue, EthereumAddress};
+use frame_support::{
+	assert_ok,
+	pallet_prelude::{ConstU32, Twox64Concat, ValueQuery},
+	BoundedVec,
+};
 use hex_literal::hex;
 
 fn get_account_rlp() -> Vec<u8> {
@@ -147,3 +151,64 @@ fn compute_key_works() {
 fn decode_rlp_works() {
 	assert_eq!(decode_rlp(vec![129, 200]).unwrap(), 200);
 }
+
+#[frame_support::storage_alias]
+type Queue = StorageMap<Expiry, Twox64Concat, u32, BoundedVec<u32, ConstU32<2>>, ValueQuery>;
+
+#[frame_support::storage_alias]
+type Cursor = StorageValue<Expiry, u32>;
+
+type TestQueue = ExpiryQueue<u32, u32, ConstU32<2>, Queue, Cursor>;
+
+#[test]
+fn expiry_queue_carries_over_items() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert_ok!(TestQueue::schedule(5, 1));
+		assert_ok!(TestQueue::schedule(5, 2));
+		assert_eq!(TestQueue::schedule(5, 3), Err(3));
+		assert_ok!(TestQueue::schedule(6, 3));
+		assert_ok!(TestQueue::schedule(8, 4));
+
+		let mut expired = vec![];
+		assert_eq!(TestQueue::service(5, 1, |item| expired.push(item)), (1, 1));
+		assert_eq!(expired, vec![1]);
+		assert_eq!(Cursor::get(), Some(5));
+
+		assert_eq!(TestQueue::service(6, 2, |item| expired.push(item)), (2, 2));
+		assert_eq!(expired, vec![1, 2, 3]);
+		assert_eq!(Cursor::get(), Some(7));
+		assert!(!Queue::contains_key(5));
+
+		TestQueue::cancel(8, &4);
+		assert!(!Queue::contains_key(8));
+	});
+}
+
+#[test]
+fn expiry_queue_keeps_items_given_back() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert_ok!(TestQueue::schedule(5, 1));
+		assert_ok!(TestQueue::schedule(5, 2));
+		assert_ok!(TestQueue::schedule(6, 3));
+
+		let mut expired = vec![];
+		let given_back = TestQueue::try_service(6, 3, |item| {
+			if item == 2 {
+				return Err(item);
+			}
+			expired.push(item);
+			Ok(())
+		});
+		assert_eq!(given_back, (1, 1));
+		assert_eq!(Queue::get(5).into_inner(), vec![2]);
+		assert_eq!(Cursor::get(), Some(5));
+
+		let serviced = TestQueue::try_service(6, 3, |item| {
+			expired.push(item);
+			Ok(())
+		});
+		assert_eq!(serviced, (2, 2));
+		assert_eq!(expired, vec![1, 2, 3]);
+		assert_eq!(Cursor::get(), Some(7));
+	});
+}
@@ -122,10 +122,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 129,
+	spec_version: 130,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -403,6 +403,11 @@ parameter_types! {
 	pub const MaxVotesPerVoter: u32 = 16;
 }
 
+parameter_types! {
+	pub const CommunityPalletId: PalletId = PalletId(*b"jur/cmty");
+	pub const CommunityMetadataDepositPerByte: Balance = deposit(0, 1);
+}
+
 impl pallet_community::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CommunityId = CommunityId;
@@ -412,12 +417,37 @@ impl pallet_community::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type WeightInfo = pallet_community::weights::SubstrateWeight<Runtime>;
-	type MyRandomness = RandomnessCollectiveFlip;
 	type TagLimit = ConstU32<40>;
 	type ColorLimit = ConstU32<7>;
 	type CommunityLimit = ConstU32<3>;
 	type StringLimit = ConstU32<2560>;
 	type LogoLimit = ConstU32<60>;
+	type MaxArticles = ConstU32<256>;
+	type ArticleLimit = ConstU32<8192>;
+	type JoinRequestTtl = ConstU32<{ 7 * DAYS }>;
+	type MaxJoinRequestsPerBlock = ConstU32<100>;
+	type InvitationTtl = ConstU32<{ 7 * DAYS }>;
+	type MaxInvitationsPerBlock = ConstU32<100>;
+	type MembershipGate = pallet_community::traits::AssetsGate<Assets>;
+	type OnCommunityDissolved = (Proposal, Passport, Bounties, EventPallet);
+	type DissolveCleanupLimit = ConstU32<100>;
+	type SlashOrigin = EnsureRoot<AccountId>;
+	type OnSlash = Treasury;
+	type OnCitizenshipRevoked = Passport;
+	type PalletId = CommunityPalletId;
+	type Assets = Assets;
+	type MaxHierarchyDepth = ConstU32<8>;
+	type MaxDescendants = ConstU32<100>;
+	type OnMemberJoined = Passport;
+	type OnMemberLeft = (Passport, Bounties, EventPallet);
+	type MetadataDepositPerByte = CommunityMetadataDepositPerByte;
+	type CommunityOrigin = pallet_community::EnsureCommunity<Runtime>;
+}
+
+parameter_types! {
+	pub const ProposalStakePalletId: PalletId = PalletId(*b"jur/prsk");
+	pub ProposalMaxCallWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+	pub ProposalMaxEnactmentWeight: Weight = Perbill::from_percent(25) * RuntimeBlockWeights::get().max_block;
 }
 
 impl pallet_proposal::Config for Runtime {
@@ -428,17 +458,34 @@ impl pallet_proposal::Config for Runtime {
 	type DescriptionLimit = ConstU32<8192>;
 	type LabelLimit = ConstU32<10>;
 	type AccountLimit = ConstU32<500>;
+	type MaxExpiriesPerBlock = ConstU32<100>;
+	type MaxClosedPerBlock = ConstU32<20>;
+	type ConvictionPeriod = ConstU32<{ 7 * DAYS }>;
+	type StakePalletId = ProposalStakePalletId;
+	type MaxEligibilityBadges = ConstU32<10>;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type MaxCallLength = ConstU32<{ 16 * 1024 }>;
+	type MaxCallWeight = ProposalMaxCallWeight;
+	type MaxEnactmentWeight = ProposalMaxEnactmentWeight;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type WeightInfo = pallet_proposal::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const PassportLeavePolicy: pallet_passport::types::PassportLeavePolicy =
+		pallet_passport::types::PassportLeavePolicy::Suspend;
+}
+
 impl pallet_passport::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PassportId = PassportId;
 	type BadgeNameLimit = ConstU32<20>;
 	type DescriptionLimit = ConstU32<250>;
 	type AddressLimit = ConstU32<60>;
+	type MintPassportOnJoin = ConstBool<false>;
+	type LeavePolicy = PassportLeavePolicy;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type WeightInfo = pallet_passport::weights::SubstrateWeight<Runtime>;
@@ -463,6 +510,9 @@ impl pallet_bounties::Config for Runtime {
 	type DescriptionLimit = ConstU32<8192>;
 	type CategoryLimit = ConstU32<20>;
 	type AccountLimit = ConstU32<500>;
+	type MaxBountiesPerParticipant = ConstU32<20>;
+	type MaxExpiriesPerBlock = ConstU32<100>;
+	type MaxClosedPerBlock = ConstU32<20>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
@@ -641,7 +691,19 @@ pub type Executive = frame_executive::Executive<
 
 type Migrations = (
 	pallet_passport::migration::v1::MigrateToV1<Runtime>,
+	pallet_passport::migration::v2::MigrateToV2<Runtime>,
 	pallet_community::migration::v8::MigrateToV8<Runtime>,
+	pallet_community::migration::v9::MigrateToV9<Runtime>,
+	pallet_community::migration::v10::MigrateToV10<Runtime>,
+	pallet_community::migration::v11::MigrateToV11<Runtime>,
+	pallet_community::migration::v12::MigrateToV12<Runtime>,
+	pallet_community::migration::v13::MigrateToV13<Runtime>,
+	pallet_proposal::migration::v1::MigrateToV1<Runtime>,
+	pallet_proposal::migration::v2::MigrateToV2<Runtime>,
+	pallet_proposal::migration::v3::MigrateToV3<Runtime>,
+	pallet_bounties::migration::v1::MigrateToV1<Runtime>,
+	pallet_bounties::migration::v2::MigrateToV2<Runtime>,
+	pallet_events::migration::v1::MigrateToV1<Runtime>,
 );
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -790,6 +852,33 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_community_runtime_api::CommunityApi<Block, AccountId, CommunityId, Balance, CurrencyId, BlockNumber> for Runtime {
+		fn treasury_account(community_id: CommunityId) -> AccountId {
+			Community::treasury_account(community_id)
+		}
+		fn treasury_balance(community_id: CommunityId) -> Balance {
+			Community::treasury_balance(community_id)
+		}
+		fn treasury_asset_balance(community_id: CommunityId, asset_id: CurrencyId) -> Balance {
+			Community::treasury_asset_balance(community_id, asset_id)
+		}
+		fn descendants(community_id: CommunityId) -> Vec<CommunityId> {
+			Community::descendants_of(community_id)
+		}
+		fn community_by_reference_id(reference_id: [u8; 16]) -> Option<CommunityId> {
+			Community::community_by_reference_id(reference_id)
+		}
+		fn community_by_name(name: Vec<u8>) -> Option<CommunityId> {
+			Community::community_id_by_name(&name)
+		}
+		fn dues_paid_until(community_id: CommunityId, who: AccountId) -> Option<BlockNumber> {
+			Community::dues_paid_until_of(community_id, &who)
+		}
+		fn is_lapsed(community_id: CommunityId, who: AccountId) -> bool {
+			Community::is_lapsed(community_id, &who)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,